@@ -21,6 +21,9 @@ try:
     from vedyut._core import (
         py_segment as _rust_segment,
     )
+    from vedyut._core import (
+        py_segment_analyze as _rust_segment_analyze,
+    )
     from vedyut._core import (
         py_transliterate as _rust_transliterate,
     )
@@ -131,6 +134,29 @@ def segment(
     return [text.split()]
 
 
+def segment_analyze(
+    text: str,
+    script: Script = Script.DEVANAGARI,
+    max_results: int = 10,
+) -> list[list[tuple[str, list[dict[str, Any]]]]]:
+    """
+    Segment Sanskrit text and analyze every word of each segmentation.
+
+    Args:
+        text: Sanskrit text to segment
+        script: Input script (first-class parameter with sensible default)
+        max_results: Maximum number of segmentations to return
+
+    Returns:
+        List of segmentations, each a list of (word, analyses) pairs
+    """
+    if RUST_AVAILABLE:
+        return _rust_segment_analyze(text, script.value, max_results)
+
+    # Fallback if Rust not available
+    return [[(word, analyze(word, script)) for word in text.split()]]
+
+
 def analyze(
     word: str,
     script: Script = Script.DEVANAGARI,
@@ -265,6 +291,7 @@ __all__ = [
     "Script",
     "transliterate",
     "segment",
+    "segment_analyze",
     "analyze",
     "generate_verb",
     "sanskritify",
@@ -2,6 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 use vedyut_kosha::{Entry, Lexicon};
+use vedyut_lipi::{transliterate, Scheme};
+use vedyut_sandhi::split_sandhi;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AnalysisResult {
@@ -21,68 +23,266 @@ pub struct AnalysisResult {
     pub linga: Option<String>,
     /// Additional tags
     pub tags: Vec<String>,
+    /// Reliability of the analysis, from 0.0 to 1.0
+    pub confidence: f64,
 }
 
+/// Confidence of an analysis backed by the lexicon
+pub const LEXICON_CONFIDENCE: f64 = 1.0;
+/// Confidence of an ending-based guess
+pub const GUESS_CONFIDENCE: f64 = 0.3;
+
+/// Ending heuristics for [`Analyzer::guess`], longest ending first (SLP1)
+///
+/// Each row is (ending, kind, lakara, purusha, vibhakti, vacana).
+const GUESS_ENDINGS: &[(&str, &str, &str, &str, &str, &str)] = &[
+    ("anti", "tinanta", "lat", "prathama", "", "bahu"),
+    ("asya", "subanta", "", "", "shashthi", "eka"),
+    ("eRa", "subanta", "", "", "trtiya", "eka"),
+    ("ena", "subanta", "", "", "trtiya", "eka"),
+    ("Aya", "subanta", "", "", "caturthi", "eka"),
+    ("taH", "tinanta", "lat", "prathama", "", "dvi"),
+    ("ti", "tinanta", "lat", "prathama", "", "eka"),
+    ("si", "tinanta", "lat", "madhyama", "", "eka"),
+    ("mi", "tinanta", "lat", "uttama", "", "eka"),
+    ("aH", "subanta", "", "", "prathama", "eka"),
+    ("AH", "subanta", "", "", "prathama", "bahu"),
+];
+
+/// Endings stripped by [`Analyzer::lemmatize`] with the stem-final sound they
+/// leave behind (SLP1), longest ending first
+///
+/// Nominal endings are those of a-stems (rāma); verbal endings are those of
+/// the thematic present, so the result is the present stem (gacCati → gacC).
+const LEMMA_ENDINGS: &[(&str, &str)] = &[
+    ("eByaH", "a"),
+    ("AByAm", "a"),
+    ("asya", "a"),
+    ("AnAm", "a"),
+    ("ARAm", "a"),
+    ("anti", ""),
+    ("ataH", ""),
+    ("aTaH", ""),
+    ("AvaH", ""),
+    ("AmaH", ""),
+    ("ayoH", "a"),
+    ("ena", "a"),
+    ("eRa", "a"),
+    ("Aya", "a"),
+    ("ezu", "a"),
+    ("ati", ""),
+    ("asi", ""),
+    ("Ami", ""),
+    ("aTa", ""),
+    ("aH", "a"),
+    ("AH", "a"),
+    ("EH", "a"),
+    ("At", "a"),
+    ("am", "a"),
+    ("An", "a"),
+];
+
 pub struct Analyzer {
     lexicon: Lexicon,
+    split_sandhi: bool,
 }
 
 impl Analyzer {
     pub fn new(lexicon: Lexicon) -> Self {
-        Self { lexicon }
+        Self {
+            lexicon,
+            split_sandhi: false,
+        }
+    }
+
+    /// Let [`Analyzer::analyze_split`] try sandhi splits of words missing from
+    /// the lexicon (off by default, as every split point is tried)
+    pub fn with_sandhi_splitting(mut self, split_sandhi: bool) -> Self {
+        self.split_sandhi = split_sandhi;
+        self
+    }
+
+    /// Analyze a word, or the two words it may be a sandhi of
+    ///
+    /// A word in the lexicon gives one result holding just its analyses. On a
+    /// miss, with sandhi splitting on, each split (SLP1) whose two parts are
+    /// both in the lexicon gives a result pairing each part with its analyses,
+    /// e.g. `tavEva` → `tava` + `eva`. Otherwise the word is analyzed as a
+    /// whole, from its ending.
+    pub fn analyze_split(&self, word: &str) -> Vec<Vec<(String, Vec<AnalysisResult>)>> {
+        let whole = || vec![vec![(word.to_string(), self.analyze(word))]];
+        if !self.split_sandhi || self.lexicon.contains(word) {
+            return whole();
+        }
+
+        let mut compounds: Vec<Vec<(String, Vec<AnalysisResult>)>> = Vec::new();
+        for (left, right) in split_sandhi(word) {
+            let seen = compounds
+                .iter()
+                .any(|parts| parts[0].0 == left && parts[1].0 == right);
+            if seen || !self.lexicon.contains(&left) || !self.lexicon.contains(&right) {
+                continue;
+            }
+            let left_analyses = self.analyze(&left);
+            let right_analyses = self.analyze(&right);
+            compounds.push(vec![(left, left_analyses), (right, right_analyses)]);
+        }
+
+        if compounds.is_empty() {
+            whole()
+        } else {
+            compounds
+        }
     }
 
     /// Analyze morphological features of a word
+    ///
+    /// Lexicon analyses and ending-based guesses are both returned, sorted by
+    /// confidence (most reliable first).
     pub fn analyze(&self, word: &str) -> Vec<AnalysisResult> {
-        let mut results = Vec::new();
-
-        // 1. Direct lookup in lexicon
-        if let Some(entries) = self.lexicon.lookup(word) {
-            for entry in entries {
-                match entry {
-                    Entry::Tinanta(tinanta) => {
-                        results.push(AnalysisResult {
-                            word: word.to_string(),
-                            root: Some(tinanta.root.clone()),
-                            lakara: Some(tinanta.lakara.clone()),
-                            purusha: Some(tinanta.purusha.clone()),
-                            vacana: Some(tinanta.vacana.clone()),
-                            vibhakti: None,
-                            linga: None,
-                            tags: vec!["tinanta".to_string()],
-                        });
-                    }
-                    Entry::Subanta(subanta) => {
-                        results.push(AnalysisResult {
-                            word: word.to_string(),
-                            root: Some(subanta.stem.clone()),
-                            lakara: None,
-                            purusha: None,
-                            vacana: subanta.vacana.clone(),
-                            vibhakti: subanta.vibhakti.clone(),
-                            linga: subanta.linga.clone(),
-                            tags: vec!["subanta".to_string()],
-                        });
-                    }
-                    Entry::Avyaya(avyaya) => {
-                        results.push(AnalysisResult {
-                            word: word.to_string(),
-                            root: Some(avyaya.word.clone()),
-                            lakara: None,
-                            purusha: None,
-                            vacana: None,
-                            vibhakti: None,
-                            linga: None,
-                            tags: vec!["avyaya".to_string()],
-                        });
-                    }
-                    _ => {} // Handle others
+        analyze_with(&self.lexicon, word)
+    }
+
+    /// Guess the analysis of an unknown word from its ending
+    ///
+    /// This is a best-effort fallback: results carry a `"guessed"` tag, no root
+    /// and [`GUESS_CONFIDENCE`].
+    /// Devanagari input is matched via SLP1.
+    pub fn guess(&self, word: &str) -> Vec<AnalysisResult> {
+        guess_ending(word)
+    }
+
+    /// Candidate stems of a word, without full analysis
+    ///
+    /// Roots of lexicon entries for the word come first, followed by stems
+    /// obtained by stripping known endings. Results are in the script of the
+    /// input (Devanagari or SLP1) and free of duplicates.
+    pub fn lemmatize(&self, word: &str) -> Vec<String> {
+        let mut lemmas: Vec<String> = Vec::new();
+        let mut push = |lemma: String| {
+            if !lemma.is_empty() && !lemmas.contains(&lemma) {
+                lemmas.push(lemma);
+            }
+        };
+
+        for analysis in analyze_with(&self.lexicon, word) {
+            if let Some(root) = analysis.root {
+                push(root);
+            }
+        }
+
+        let is_devanagari = is_devanagari(word);
+        let slp1 = if is_devanagari {
+            transliterate(word, Scheme::Devanagari, Scheme::Slp1)
+        } else {
+            word.to_string()
+        };
+
+        for (ending, stem_final) in LEMMA_ENDINGS {
+            if let Some(base) = slp1.strip_suffix(ending).filter(|base| !base.is_empty()) {
+                let stem = format!("{}{}", base, stem_final);
+                if is_devanagari {
+                    push(transliterate(&stem, Scheme::Slp1, Scheme::Devanagari));
+                } else {
+                    push(stem);
                 }
             }
         }
 
-        results
+        lemmas
+    }
+}
+
+/// Analyze a word against a borrowed lexicon
+pub(crate) fn analyze_with(lexicon: &Lexicon, word: &str) -> Vec<AnalysisResult> {
+    let mut results = Vec::new();
+
+    // 1. Direct lookup in lexicon
+    if let Some(entries) = lexicon.lookup(word) {
+        for entry in entries {
+            match entry {
+                Entry::Tinanta(tinanta) => {
+                    results.push(AnalysisResult {
+                        word: word.to_string(),
+                        root: Some(tinanta.root.clone()),
+                        lakara: Some(tinanta.lakara.clone()),
+                        purusha: Some(tinanta.purusha.clone()),
+                        vacana: Some(tinanta.vacana.clone()),
+                        vibhakti: None,
+                        linga: None,
+                        tags: vec!["tinanta".to_string()],
+                        confidence: LEXICON_CONFIDENCE,
+                    });
+                }
+                Entry::Subanta(subanta) => {
+                    results.push(AnalysisResult {
+                        word: word.to_string(),
+                        root: Some(subanta.stem.clone()),
+                        lakara: None,
+                        purusha: None,
+                        vacana: subanta.vacana.clone(),
+                        vibhakti: subanta.vibhakti.clone(),
+                        linga: subanta.linga.clone(),
+                        tags: vec!["subanta".to_string()],
+                        confidence: LEXICON_CONFIDENCE,
+                    });
+                }
+                Entry::Avyaya(avyaya) => {
+                    results.push(AnalysisResult {
+                        word: word.to_string(),
+                        root: Some(avyaya.word.clone()),
+                        lakara: None,
+                        purusha: None,
+                        vacana: None,
+                        vibhakti: None,
+                        linga: None,
+                        tags: vec!["avyaya".to_string()],
+                        confidence: LEXICON_CONFIDENCE,
+                    });
+                }
+                _ => {} // Handle others
+            }
+        }
     }
+
+    // 2. Ending heuristics
+    results.extend(guess_ending(word));
+
+    results.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+    results
+}
+
+fn is_devanagari(word: &str) -> bool {
+    word.chars().any(|c| ('\u{0900}'..='\u{097F}').contains(&c))
+}
+
+fn guess_ending(word: &str) -> Vec<AnalysisResult> {
+    let slp1 = if is_devanagari(word) {
+        transliterate(word, Scheme::Devanagari, Scheme::Slp1)
+    } else {
+        word.to_string()
+    };
+
+    let some = |s: &str| (!s.is_empty()).then(|| s.to_string());
+
+    GUESS_ENDINGS
+        .iter()
+        .find(|(ending, ..)| slp1.len() > ending.len() && slp1.ends_with(ending))
+        .map(
+            |&(_, kind, lakara, purusha, vibhakti, vacana)| AnalysisResult {
+                word: word.to_string(),
+                root: None,
+                lakara: some(lakara),
+                purusha: some(purusha),
+                vacana: some(vacana),
+                vibhakti: some(vibhakti),
+                linga: None,
+                tags: vec![kind.to_string(), "guessed".to_string()],
+                confidence: GUESS_CONFIDENCE,
+            },
+        )
+        .into_iter()
+        .collect()
 }
 
 // For backward compatibility or simpler usage without lexicon initialization
@@ -97,13 +297,14 @@ pub fn analyze_placeholder(word: &str) -> Option<AnalysisResult> {
         vibhakti: None,
         linga: None,
         tags: vec![],
+        confidence: 0.0,
     })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use vedyut_kosha::entries::{Entry, TinantaEntry};
+    use vedyut_kosha::entries::{AvyayaEntry, Entry, TinantaEntry};
 
     #[test]
     fn test_analyze_tinanta() {
@@ -120,11 +321,128 @@ mod tests {
 
         let analyzer = Analyzer::new(lexicon);
         let results = analyzer.analyze("भवति");
+        // The lexicon hit, then the guess from the -ti ending
+        assert_eq!(results.len(), 2);
 
-        assert_eq!(results.len(), 1);
         let res = &results[0];
         assert_eq!(res.word, "भवति");
         assert_eq!(res.root.as_deref(), Some("भू"));
         assert_eq!(res.lakara.as_deref(), Some("lat"));
     }
+
+    #[test]
+    fn test_guess_unknown_words() {
+        let analyzer = Analyzer::new(Lexicon::new());
+
+        let verb = analyzer.guess("xyzti");
+        assert_eq!(verb.len(), 1);
+        assert_eq!(verb[0].tags, vec!["tinanta", "guessed"]);
+        assert_eq!(verb[0].purusha.as_deref(), Some("prathama"));
+        assert_eq!(verb[0].vacana.as_deref(), Some("eka"));
+
+        let noun = analyzer.guess("xyzaH");
+        assert_eq!(noun.len(), 1);
+        assert_eq!(noun[0].tags, vec!["subanta", "guessed"]);
+        assert_eq!(noun[0].vibhakti.as_deref(), Some("prathama"));
+
+        assert!(analyzer.guess("xyz").is_empty());
+    }
+
+    #[test]
+    fn test_lexicon_outranks_guess() {
+        let mut lexicon = Lexicon::new();
+        lexicon.add(
+            "भवति".to_string(),
+            Entry::Tinanta(TinantaEntry {
+                root: "भू".to_string(),
+                lakara: "lat".to_string(),
+                purusha: "prathama".to_string(),
+                vacana: "eka".to_string(),
+            }),
+        );
+
+        let results = Analyzer::new(lexicon).analyze("भवति");
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].confidence, LEXICON_CONFIDENCE);
+        assert_eq!(results[0].root.as_deref(), Some("भू"));
+        assert_eq!(results[1].confidence, GUESS_CONFIDENCE);
+        assert!(results[1].tags.contains(&"guessed".to_string()));
+    }
+
+    #[test]
+    fn test_analyze_falls_back_to_guess() {
+        let analyzer = Analyzer::new(Lexicon::new());
+        let results = analyzer.analyze("पठति");
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].word, "पठति");
+        assert!(results[0].tags.contains(&"guessed".to_string()));
+    }
+
+    #[test]
+    fn test_lemmatize() {
+        let analyzer = Analyzer::new(Lexicon::new());
+
+        assert_eq!(analyzer.lemmatize("rAmasya"), vec!["rAma"]);
+        assert_eq!(analyzer.lemmatize("gacCati"), vec!["gacC"]);
+        assert_eq!(analyzer.lemmatize("रामस्य"), vec!["राम"]);
+        assert!(analyzer.lemmatize("ca").is_empty());
+    }
+
+    #[test]
+    fn test_lemmatize_prefers_lexicon_root() {
+        let mut lexicon = Lexicon::new();
+        lexicon.add(
+            "gacCati".to_string(),
+            Entry::Tinanta(TinantaEntry {
+                root: "gam".to_string(),
+                lakara: "lat".to_string(),
+                purusha: "prathama".to_string(),
+                vacana: "eka".to_string(),
+            }),
+        );
+
+        let analyzer = Analyzer::new(lexicon);
+        assert_eq!(analyzer.lemmatize("gacCati"), vec!["gam", "gacC"]);
+    }
+
+    /// A lexicon with `words` as avyayas
+    fn avyaya_lexicon(words: &[&str]) -> Lexicon {
+        let mut lexicon = Lexicon::new();
+        for word in words {
+            lexicon.add(
+                word.to_string(),
+                Entry::Avyaya(AvyayaEntry {
+                    word: word.to_string(),
+                }),
+            );
+        }
+        lexicon
+    }
+
+    #[test]
+    fn test_analyze_split() {
+        let analyzer = Analyzer::new(avyaya_lexicon(&["tava", "eva"])).with_sandhi_splitting(true);
+        let results = analyzer.analyze_split("tavEva");
+        assert_eq!(results.len(), 1);
+
+        let parts: Vec<&str> = results[0].iter().map(|(part, _)| part.as_str()).collect();
+        assert_eq!(parts, vec!["tava", "eva"]);
+        for (part, analyses) in &results[0] {
+            assert_eq!(analyses[0].root.as_deref(), Some(part.as_str()));
+            assert_eq!(analyses[0].tags, vec!["avyaya"]);
+        }
+
+        // A known word is not split
+        assert_eq!(analyzer.analyze_split("eva")[0].len(), 1);
+    }
+
+    #[test]
+    fn test_analyze_split_disabled() {
+        let results = Analyzer::new(avyaya_lexicon(&["tava", "eva"])).analyze_split("tavEva");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0][0].0, "tavEva");
+        assert!(results[0][0].1.is_empty());
+    }
 }
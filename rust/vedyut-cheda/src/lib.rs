@@ -6,13 +6,23 @@
 pub mod analyzer;
 pub mod segmenter;
 
-pub use analyzer::{AnalysisResult, Analyzer};
-pub use segmenter::{SegmentResult, Segmenter};
+pub use analyzer::{AnalysisResult, Analyzer, GUESS_CONFIDENCE, LEXICON_CONFIDENCE};
+pub use segmenter::{segment_and_analyze, to_padapatha, SegmentResult, Segmenter, SegmenterConfig};
 
 // Compatibility helpers for vedyut-core
 use vedyut_kosha::Lexicon;
 
 pub fn segment_text(text: &str) -> Vec<SegmentResult> {
+    let segmenter = Segmenter::new(temporary_lexicon(text));
+    segmenter.segment(text)
+}
+
+/// Segment text and analyze each word (see [`segment_and_analyze`])
+pub fn segment_and_analyze_text(text: &str) -> Vec<Vec<(String, Vec<AnalysisResult>)>> {
+    segment_and_analyze(text, &temporary_lexicon(text))
+}
+
+fn temporary_lexicon(text: &str) -> Lexicon {
     // Ideally this should use a global lexicon instance
     // For now, create a temporary empty lexicon (will fail to validate words properly)
     // Or just return empty results
@@ -25,9 +35,7 @@ pub fn segment_text(text: &str) -> Vec<SegmentResult> {
             word: text.to_string(),
         }),
     );
-
-    let segmenter = Segmenter::new(lexicon);
-    segmenter.segment(text)
+    lexicon
 }
 
 /// Analyze morphological features of a word (legacy placeholder)
@@ -1,49 +1,122 @@
 //! Text segmentation logic
+use crate::analyzer::{analyze_with, AnalysisResult};
 use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant};
 use vedyut_kosha::Lexicon;
-use vedyut_sandhi::split_sandhi;
+use vedyut_sandhi::{sandhi_consistent, split_sandhi};
 
+/// One segmentation of a text
+///
+/// The JSON field names are pinned with `serde(rename)` and do not follow
+/// renames of the Rust fields.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct SegmentResult {
     /// The segmented words
+    #[serde(rename = "words")]
     pub words: Vec<String>,
     /// Confidence score (0.0 to 1.0)
+    #[serde(rename = "score")]
     pub score: f64,
 }
 
+/// Words joined by `+`, then the score, e.g. `deva+indra (0.50)`
+impl std::fmt::Display for SegmentResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({:.2})", self.words.join("+"), self.score)
+    }
+}
+
+/// Limits on the search done by [`Segmenter::segment`]
+///
+/// When a limit is hit the search stops, and the segmentations found so far
+/// are returned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SegmenterConfig {
+    /// Stop once this many segmentations have been found
+    pub max_candidates: usize,
+    /// Most words in one segmentation
+    pub max_depth: usize,
+    /// Wall-clock limit for one call
+    pub time_budget: Option<Duration>,
+}
+
+impl Default for SegmenterConfig {
+    fn default() -> Self {
+        Self {
+            max_candidates: 1000,
+            max_depth: 6,
+            time_budget: None,
+        }
+    }
+}
+
 pub struct Segmenter {
     lexicon: Lexicon,
+    config: SegmenterConfig,
 }
 
 impl Segmenter {
     pub fn new(lexicon: Lexicon) -> Self {
-        Self { lexicon }
+        Self::with_config(lexicon, SegmenterConfig::default())
+    }
+
+    pub fn with_config(lexicon: Lexicon, config: SegmenterConfig) -> Self {
+        Self { lexicon, config }
     }
 
     /// Segment text into words using sandhi splitting
     pub fn segment(&self, text: &str) -> Vec<SegmentResult> {
-        let mut results = Vec::new();
+        segment_with(&self.lexicon, text, &self.config)
+    }
+}
 
-        let paths = self.find_valid_paths(text, 0);
+/// Segment text against a borrowed lexicon
+fn segment_with(lexicon: &Lexicon, text: &str, config: &SegmenterConfig) -> Vec<SegmentResult> {
+    let mut results = Vec::new();
 
-        for path in paths {
-            // Calculate a score
-            // Heuristic: Prefer fewer words (Longer matches)
-            let score = 1.0 / (path.len() as f64);
-            results.push(SegmentResult { words: path, score });
-        }
+    let mut search = Search {
+        lexicon,
+        config,
+        deadline: config.time_budget.map(|budget| Instant::now() + budget),
+        found: 0,
+    };
+    let paths = search.find_valid_paths(text, 0);
 
-        // Sort by score descending
-        results.sort_by(|a, b| {
-            b.score
-                .partial_cmp(&a.score)
-                .unwrap_or(std::cmp::Ordering::Equal)
-        });
-        results
+    for path in paths {
+        // Calculate a score
+        // Heuristic: Prefer fewer words (Longer matches)
+        let score = 1.0 / (path.len() as f64);
+        results.push(SegmentResult { words: path, score });
     }
 
-    fn find_valid_paths(&self, text: &str, depth: usize) -> Vec<Vec<String>> {
-        if depth > 5 {
+    // Sort by score descending
+    results.sort_by(|a, b| {
+        b.score
+            .partial_cmp(&a.score)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    results
+}
+
+/// Depth-first search for segmentations, within the limits of a config
+struct Search<'a> {
+    lexicon: &'a Lexicon,
+    config: &'a SegmenterConfig,
+    deadline: Option<Instant>,
+    /// Complete segmentations found so far
+    found: usize,
+}
+
+impl Search<'_> {
+    fn out_of_budget(&self) -> bool {
+        self.found >= self.config.max_candidates
+            || self
+                .deadline
+                .is_some_and(|deadline| Instant::now() >= deadline)
+    }
+
+    fn find_valid_paths(&mut self, text: &str, depth: usize) -> Vec<Vec<String>> {
+        if depth >= self.config.max_depth || self.out_of_budget() {
             return Vec::new();
         }
         let mut paths = Vec::new();
@@ -51,13 +124,17 @@ impl Segmenter {
         // 1. Whole word check
         if self.lexicon.contains(text) {
             paths.push(vec![text.to_string()]);
+            self.found += 1;
         }
 
         // 2. Split check
         let splits = split_sandhi(text);
         for (left, right) in splits {
-            // Check if left is valid word
-            if self.lexicon.contains(&left) {
+            if self.out_of_budget() {
+                break;
+            }
+            // Check if left is valid word, and the split undoes a real sandhi
+            if self.lexicon.contains(&left) && sandhi_consistent(&left, &right, text) {
                 // Recurse on right
                 let right_paths = self.find_valid_paths(&right, depth + 1);
                 for path in right_paths {
@@ -72,10 +149,75 @@ impl Segmenter {
     }
 }
 
+/// Split a sandhi-joined (saṃhitā) sentence into its padapāṭha
+///
+/// Each whitespace-separated chunk is segmented and its best-scoring path
+/// kept. Chunks with no valid segmentation are returned unchanged.
+pub fn to_padapatha(text: &str, lexicon: &Lexicon) -> Vec<String> {
+    let mut padas = Vec::new();
+
+    for chunk in text.split_whitespace() {
+        match segment_with(lexicon, chunk, &SegmenterConfig::default())
+            .into_iter()
+            .next()
+        {
+            Some(best) => padas.extend(best.words),
+            None => padas.push(chunk.to_string()),
+        }
+    }
+
+    padas
+}
+
+/// Segment text and pair every word of each path with its analyses
+///
+/// Paths are in the same order as [`Segmenter::segment`] returns them.
+pub fn segment_and_analyze(
+    text: &str,
+    lexicon: &Lexicon,
+) -> Vec<Vec<(String, Vec<AnalysisResult>)>> {
+    segment_with(lexicon, text, &SegmenterConfig::default())
+        .into_iter()
+        .map(|result| {
+            result
+                .words
+                .into_iter()
+                .map(|word| {
+                    let analyses = analyze_with(lexicon, &word);
+                    (word, analyses)
+                })
+                .collect()
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use vedyut_kosha::entries::{DhatuEntry, Entry};
+    use vedyut_kosha::entries::{AvyayaEntry, DhatuEntry, Entry};
+
+    #[test]
+    fn test_segment_result_display() {
+        let result = SegmentResult {
+            words: vec!["deva".to_string(), "indra".to_string()],
+            score: 0.5,
+        };
+        assert_eq!(result.to_string(), "deva+indra (0.50)");
+    }
+
+    #[test]
+    fn test_segment_result_serde() {
+        let result = SegmentResult {
+            words: vec!["deva".to_string(), "Alaya".to_string()],
+            score: 0.25,
+        };
+        let json = serde_json::to_string(&result).unwrap();
+        assert_eq!(json, r#"{"words":["deva","Alaya"],"score":0.25}"#);
+        assert_eq!(
+            serde_json::from_str::<SegmentResult>(&json).unwrap(),
+            result
+        );
+    }
 
     fn create_mock_lexicon() -> Lexicon {
         let mut lex = Lexicon::new();
@@ -133,4 +275,96 @@ mod tests {
         let results = segmenter.segment("ityAdi");
         assert!(results.iter().any(|r| r.words == vec!["iti", "Adi"]));
     }
+
+    #[test]
+    fn test_segment_drops_inconsistent_splits() {
+        let mut lex = Lexicon::new();
+        for word in ["ca", "iti"] {
+            lex.add(
+                word.to_string(),
+                Entry::Avyaya(AvyayaEntry {
+                    word: word.to_string(),
+                }),
+            );
+        }
+        let segmenter = Segmenter::new(lex);
+
+        let results = segmenter.segment("ceti");
+        assert!(results.iter().any(|r| r.words == vec!["ca", "iti"]));
+        // ca + iti gives ceti, so the plain cut of "caiti" is dropped
+        assert!(segmenter.segment("caiti").is_empty());
+    }
+
+    #[test]
+    fn test_to_padapatha() {
+        let lex = create_mock_lexicon();
+
+        assert_eq!(to_padapatha("devendra", &lex), vec!["deva", "indra"]);
+        assert_eq!(
+            to_padapatha("ityAdi devendra", &lex),
+            vec!["iti", "Adi", "deva", "indra"]
+        );
+        // Whole-word matches beat splits
+        assert_eq!(to_padapatha("devAlaya", &lex), vec!["devAlaya"]);
+    }
+
+    #[test]
+    fn test_segment_and_analyze() {
+        let mut lex = Lexicon::new();
+        for word in ["iti", "Adi"] {
+            lex.add(
+                word.to_string(),
+                Entry::Avyaya(AvyayaEntry {
+                    word: word.to_string(),
+                }),
+            );
+        }
+
+        let paths = segment_and_analyze("ityAdi", &lex);
+        let path = paths
+            .iter()
+            .find(|path| path.iter().map(|(w, _)| w.as_str()).eq(["iti", "Adi"]))
+            .unwrap();
+
+        for (word, analyses) in path {
+            assert_eq!(analyses[0].root.as_deref(), Some(word.as_str()));
+            assert_eq!(analyses[0].tags, vec!["avyaya"]);
+        }
+    }
+
+    #[test]
+    fn test_segment_budget() {
+        // Every run of ka's is a word, so "kakaka..." has exponentially many
+        // segmentations
+        let dummy = Entry::Avyaya(AvyayaEntry {
+            word: "ka".to_string(),
+        });
+        let mut lex = Lexicon::new();
+        for len in 1..=8 {
+            lex.add("ka".repeat(len), dummy.clone());
+        }
+        let text = "ka".repeat(60);
+
+        let config = SegmenterConfig {
+            max_candidates: 50,
+            max_depth: 60,
+            time_budget: Some(Duration::from_secs(2)),
+        };
+        let results = Segmenter::with_config(lex.clone(), config).segment(&text);
+        assert!(!results.is_empty());
+        assert!(results.len() <= 50);
+        for result in &results {
+            assert_eq!(result.words.concat(), text);
+        }
+
+        // The time budget alone also stops the search: a spent budget finds
+        // nothing
+        let config = SegmenterConfig {
+            max_candidates: usize::MAX,
+            max_depth: 60,
+            time_budget: Some(Duration::ZERO),
+        };
+        let results = Segmenter::with_config(lex, config).segment(&text);
+        assert!(results.is_empty());
+    }
 }
@@ -15,6 +15,7 @@ fn _core(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(py_sanskritify, m)?)?;
     m.add_function(wrap_pyfunction!(py_segment, m)?)?;
     m.add_function(wrap_pyfunction!(py_analyze, m)?)?;
+    m.add_function(wrap_pyfunction!(py_segment_analyze, m)?)?;
 
     Ok(())
 }
@@ -133,22 +134,57 @@ fn py_analyze(word: &str, script: &str, py: Python) -> PyResult<Vec<PyObject>> {
     })?;
 
     if let Some(analysis) = vedyut_cheda::analyze_word(word) {
-        let dict = PyDict::new(py);
-        dict.set_item("word", analysis.word)?;
-        dict.set_item("root", analysis.root)?;
-        dict.set_item("lakara", analysis.lakara)?;
-        dict.set_item("purusha", analysis.purusha)?;
-        dict.set_item("vacana", analysis.vacana)?;
-        dict.set_item("vibhakti", analysis.vibhakti)?;
-        dict.set_item("linga", analysis.linga)?;
-        dict.set_item("tags", analysis.tags)?;
-
-        Ok(vec![dict.unbind().into()])
+        Ok(vec![analysis_to_dict(py, analysis)?])
     } else {
         Ok(vec![])
     }
 }
 
+/// Segment text and analyze each word of every segmentation
+#[pyfunction]
+#[pyo3(signature = (text, script="devanagari", max_results=10))]
+fn py_segment_analyze(
+    text: &str,
+    script: &str,
+    max_results: usize,
+    py: Python,
+) -> PyResult<Vec<Vec<(String, Vec<PyObject>)>>> {
+    let _scheme = Scheme::from_str(script).ok_or_else(|| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Unsupported script: {}", script))
+    })?;
+
+    vedyut_cheda::segment_and_analyze_text(text)
+        .into_iter()
+        .take(max_results)
+        .map(|path| {
+            path.into_iter()
+                .map(|(word, analyses)| {
+                    let dicts = analyses
+                        .into_iter()
+                        .map(|analysis| analysis_to_dict(py, analysis))
+                        .collect::<PyResult<Vec<_>>>()?;
+                    Ok::<_, PyErr>((word, dicts))
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn analysis_to_dict(py: Python, analysis: vedyut_cheda::AnalysisResult) -> PyResult<PyObject> {
+    let dict = PyDict::new(py);
+    dict.set_item("word", analysis.word)?;
+    dict.set_item("root", analysis.root)?;
+    dict.set_item("lakara", analysis.lakara)?;
+    dict.set_item("purusha", analysis.purusha)?;
+    dict.set_item("vacana", analysis.vacana)?;
+    dict.set_item("vibhakti", analysis.vibhakti)?;
+    dict.set_item("linga", analysis.linga)?;
+    dict.set_item("tags", analysis.tags)?;
+    dict.set_item("confidence", analysis.confidence)?;
+
+    Ok(dict.unbind().into())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
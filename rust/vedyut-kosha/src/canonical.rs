@@ -0,0 +1,66 @@
+//! Canonical spelling of words used as lexicon keys
+
+/// Vargas of the stops in SLP1, each ending in its nasal
+const VARGAS: [&str; 5] = ["kKgGN", "cCjJY", "wWqQR", "tTdDn", "pPbBm"];
+
+/// Canonical form of an SLP1 word for use as a lexicon key
+///
+/// Orthographic variants of a word get the same key:
+/// - Vedic accents (`/`, `\`, `^`, U+0951, U+0952, the Vedic extension
+///   signs) and the pluta mark `3` are dropped
+/// - a nasal before a stop of its own varga becomes anusvāra, so `saNkalpa`
+///   and `saMkalpa` match (8.4.58 anusvārasya yayi parasavarṇaḥ, read back)
+/// - candrabindu `~` is read as anusvāra, and a final anusvāra as `m`
+///   (8.3.23 mo 'nusvāraḥ, read back), so `rAmaM` and `rAmam` match
+pub fn canonical_key(slp1: &str) -> String {
+    let chars: Vec<char> = slp1.chars().filter(|&c| !is_accent_or_pluta(c)).collect();
+    let mut key = String::with_capacity(slp1.len());
+
+    for (i, &c) in chars.iter().enumerate() {
+        let next = chars.get(i + 1).copied();
+        let c = match c {
+            '~' => 'M',
+            _ if next.is_some_and(|next| is_nasal_of(c, next)) => 'M',
+            _ => c,
+        };
+        let final_anusvara = c == 'M' && !next.is_some_and(|next| next.is_alphabetic());
+        key.push(if final_anusvara { 'm' } else { c });
+    }
+
+    key
+}
+
+fn is_accent_or_pluta(c: char) -> bool {
+    matches!(
+        c,
+        '/' | '\\' | '^' | '3' | '\u{0951}' | '\u{0952}' | '\u{1CD0}'..='\u{1CFF}'
+    )
+}
+
+/// Check that `nasal` is the nasal of the varga of the stop `stop`
+fn is_nasal_of(nasal: char, stop: char) -> bool {
+    VARGAS
+        .iter()
+        .any(|varga| varga.ends_with(nasal) && varga[..varga.len() - 1].contains(stop))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_canonical_key_variants() {
+        assert_eq!(canonical_key("saNkalpa"), canonical_key("saMkalpa"));
+        assert_eq!(canonical_key("Santa"), canonical_key("SaMta"));
+        assert_eq!(canonical_key("sampUrRa"), "saMpUrRa");
+        assert_eq!(canonical_key("rAmaM"), canonical_key("rAmam"));
+        assert_eq!(canonical_key("ag/nim"), "agnim");
+        assert_eq!(canonical_key("devA3"), "devA");
+        assert_eq!(canonical_key("ha~sa"), "haMsa");
+
+        // Nasals not before a stop of their varga stay
+        assert_eq!(canonical_key("anya"), "anya");
+        assert_eq!(canonical_key("Tanka"), "Tanka");
+        assert_eq!(canonical_key("janma"), "janma");
+    }
+}
@@ -1,7 +1,7 @@
 /// Lexicon entry types
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum Entry {
     Dhatu(DhatuEntry),
     Subanta(SubantaEntry),
@@ -10,7 +10,30 @@ pub enum Entry {
     Avyaya(AvyayaEntry),
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Kind of a lexicon entry, without its payload
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum EntryKind {
+    Dhatu,
+    Subanta,
+    Tinanta,
+    Krdanta,
+    Avyaya,
+}
+
+impl Entry {
+    /// Get the kind of this entry
+    pub fn kind(&self) -> EntryKind {
+        match self {
+            Entry::Dhatu(_) => EntryKind::Dhatu,
+            Entry::Subanta(_) => EntryKind::Subanta,
+            Entry::Tinanta(_) => EntryKind::Tinanta,
+            Entry::Krdanta(_) => EntryKind::Krdanta,
+            Entry::Avyaya(_) => EntryKind::Avyaya,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DhatuEntry {
     /// Verb root
     pub root: String,
@@ -22,7 +45,7 @@ pub struct DhatuEntry {
     pub code: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SubantaEntry {
     /// Nominal stem
     pub stem: String,
@@ -34,7 +57,7 @@ pub struct SubantaEntry {
     pub vacana: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TinantaEntry {
     /// Verb root
     pub root: String,
@@ -46,7 +69,7 @@ pub struct TinantaEntry {
     pub vacana: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct KrdantaEntry {
     /// Kṛdanta form
     pub form: String,
@@ -56,7 +79,7 @@ pub struct KrdantaEntry {
     pub pratyaya: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AvyayaEntry {
     /// Indeclinable word
     pub word: String,
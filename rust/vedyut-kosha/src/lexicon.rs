@@ -1,14 +1,53 @@
 //! High-performance lexicon for fast lookups
 
-use crate::entries::Entry;
+use crate::canonical::canonical_key;
+use crate::entries::{Entry, EntryKind};
 use rustc_hash::FxHashMap;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io::BufRead;
+use std::sync::OnceLock;
+
+/// Summary counts describing a lexicon's contents
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LexiconStats {
+    /// Number of unique words
+    pub total_words: usize,
+    /// Number of entries across all words
+    pub total_entries: usize,
+    /// Number of entries of each kind
+    pub by_kind: FxHashMap<EntryKind, usize>,
+    /// Largest number of entries stored for a single word (homographs)
+    pub max_entries_per_word: usize,
+}
+
+impl LexiconStats {
+    /// Number of entries of the given kind
+    pub fn count(&self, kind: EntryKind) -> usize {
+        self.by_kind.get(&kind).copied().unwrap_or(0)
+    }
+}
+
+/// One line of a JSON-lines lexicon
+#[derive(Deserialize)]
+struct JsonlRecord {
+    word: String,
+    entry: Entry,
+}
 
 /// High-performance lexicon with sub-microsecond lookup times
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Lexicon {
     /// Internal storage: word -> entries
     entries: FxHashMap<String, Vec<Entry>>,
+    /// Sources of each entry, parallel to `entries`; words and trailing
+    /// entries without a recorded source may be missing
+    #[serde(default)]
+    sources: FxHashMap<String, Vec<Vec<String>>>,
+    /// Words by [`canonical_key`], built on the first canonical lookup and
+    /// dropped when a word is added
+    #[serde(skip)]
+    canonical: OnceLock<FxHashMap<String, Vec<String>>>,
 }
 
 impl Lexicon {
@@ -16,15 +55,86 @@ impl Lexicon {
     pub fn new() -> Self {
         Self {
             entries: FxHashMap::default(),
+            sources: FxHashMap::default(),
+            canonical: OnceLock::new(),
         }
     }
 
     /// Add an entry to the lexicon
+    ///
+    /// Entries identical to one already stored for the word are skipped, so
+    /// loading overlapping sources does not inflate lookups.
     pub fn add(&mut self, word: String, entry: Entry) {
-        self.entries
-            .entry(word)
-            .or_insert_with(Vec::new)
-            .push(entry);
+        self.insert(word, entry, None, true);
+    }
+
+    /// Add an entry and record the dictionary it came from (e.g. "MW")
+    ///
+    /// An identical entry from another source is stored once, with both sources.
+    pub fn add_from(&mut self, word: String, entry: Entry, source: &str) {
+        self.insert(word, entry, Some(source), true);
+    }
+
+    /// Add an entry to the lexicon even if an identical entry already exists
+    pub fn add_allow_dup(&mut self, word: String, entry: Entry) {
+        self.insert(word, entry, None, false);
+    }
+
+    fn insert(&mut self, word: String, entry: Entry, source: Option<&str>, dedup: bool) {
+        if !self.entries.contains_key(&word) {
+            self.canonical.take();
+        }
+
+        let Some(source) = source else {
+            let entries = self.entries.entry(word).or_default();
+            if !dedup || !entries.contains(&entry) {
+                entries.push(entry);
+            }
+            return;
+        };
+
+        let entries = self.entries.entry(word.clone()).or_default();
+        let index = match entries.iter().position(|e| *e == entry) {
+            Some(index) if dedup => index,
+            _ => {
+                entries.push(entry);
+                entries.len() - 1
+            }
+        };
+
+        let sources = self.sources.entry(word).or_default();
+        if sources.len() <= index {
+            sources.resize(index + 1, Vec::new());
+        }
+        if !sources[index].iter().any(|s| s == source) {
+            sources[index].push(source.to_string());
+        }
+    }
+
+    /// Merge another lexicon into this one, skipping identical entries
+    ///
+    /// Sources recorded in `other` are kept.
+    pub fn merge(&mut self, mut other: Lexicon) {
+        for (word, entries) in other.entries {
+            let sources = other.sources.remove(&word).unwrap_or_default();
+            for (i, entry) in entries.into_iter().enumerate() {
+                match sources.get(i).filter(|s| !s.is_empty()) {
+                    Some(entry_sources) => {
+                        for source in entry_sources {
+                            self.add_from(word.clone(), entry.clone(), source);
+                        }
+                    }
+                    None => self.add(word.clone(), entry),
+                }
+            }
+        }
+    }
+
+    /// Add every (word, entry) pair, skipping identical entries
+    pub fn extend(&mut self, entries: impl IntoIterator<Item = (String, Entry)>) {
+        for (word, entry) in entries {
+            self.add(word, entry);
+        }
     }
 
     /// Look up a word in the lexicon
@@ -35,6 +145,75 @@ impl Lexicon {
         self.entries.get(word)
     }
 
+    /// Look up a word by its [`canonical_key`], so that orthographic variants
+    /// match: `saNkalpa` finds the entries stored under `saMkalpa`
+    ///
+    /// # Returns
+    /// The entries of every stored word with the same key, by word
+    pub fn lookup_canonical(&self, word: &str) -> Vec<&Entry> {
+        let index = self.canonical.get_or_init(|| {
+            let mut index: FxHashMap<String, Vec<String>> = FxHashMap::default();
+            for word in self.entries.keys() {
+                index
+                    .entry(canonical_key(word))
+                    .or_default()
+                    .push(word.clone());
+            }
+            for words in index.values_mut() {
+                words.sort();
+            }
+            index
+        });
+
+        index
+            .get(&canonical_key(word))
+            .into_iter()
+            .flatten()
+            .filter_map(|variant| self.entries.get(variant))
+            .flatten()
+            .collect()
+    }
+
+    /// Look up the entries of a word that were loaded from `source`
+    pub fn lookup_from(&self, word: &str, source: &str) -> Vec<&Entry> {
+        let (Some(entries), Some(sources)) = (self.entries.get(word), self.sources.get(word))
+        else {
+            return Vec::new();
+        };
+
+        entries
+            .iter()
+            .zip(sources)
+            .filter(|(_, entry_sources)| entry_sources.iter().any(|s| s == source))
+            .map(|(entry, _)| entry)
+            .collect()
+    }
+
+    /// Entries of a word that has more than one distinct analysis
+    ///
+    /// Identical entries (e.g. from `add_allow_dup`) count once and are
+    /// returned once. A word with a single analysis, or not in the lexicon,
+    /// gives an empty list.
+    pub fn homographs(&self, word: &str) -> Vec<&Entry> {
+        let mut distinct: Vec<&Entry> = Vec::new();
+        for entry in self.entries.get(word).into_iter().flatten() {
+            if !distinct.contains(&entry) {
+                distinct.push(entry);
+            }
+        }
+
+        if distinct.len() > 1 {
+            distinct
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// Check whether a word has more than one distinct analysis
+    pub fn has_homograph(&self, word: &str) -> bool {
+        !self.homographs(word).is_empty()
+    }
+
     /// Check if a word exists in the lexicon
     pub fn contains(&self, word: &str) -> bool {
         self.entries.contains_key(word)
@@ -50,11 +229,86 @@ impl Lexicon {
         self.entries.is_empty()
     }
 
+    /// Iterate over every (word, entry) pair, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Entry)> {
+        self.entries
+            .iter()
+            .flat_map(|(word, entries)| entries.iter().map(move |entry| (word.as_str(), entry)))
+    }
+
+    /// Iterate over the distinct words, in no particular order
+    pub fn words(&self) -> impl Iterator<Item = &str> {
+        self.entries.keys().map(String::as_str)
+    }
+
+    /// Collect the stored tiṅanta forms of a root by (lakara, purusha, vacana)
+    ///
+    /// Forms within a cell are sorted, so the result is deterministic.
+    pub fn paradigm(&self, root: &str) -> BTreeMap<(String, String, String), Vec<&str>> {
+        let mut paradigm: BTreeMap<(String, String, String), Vec<&str>> = BTreeMap::new();
+
+        for (word, entry) in self.iter() {
+            if let Entry::Tinanta(tinanta) = entry {
+                if tinanta.root == root {
+                    let key = (
+                        tinanta.lakara.clone(),
+                        tinanta.purusha.clone(),
+                        tinanta.vacana.clone(),
+                    );
+                    paradigm.entry(key).or_default().push(word);
+                }
+            }
+        }
+
+        for forms in paradigm.values_mut() {
+            forms.sort_unstable();
+        }
+        paradigm
+    }
+
+    /// Compute entry counts for diagnostics
+    pub fn stats(&self) -> LexiconStats {
+        let mut stats = LexiconStats {
+            total_words: self.entries.len(),
+            ..Default::default()
+        };
+
+        for entries in self.entries.values() {
+            stats.total_entries += entries.len();
+            stats.max_entries_per_word = stats.max_entries_per_word.max(entries.len());
+            for entry in entries {
+                *stats.by_kind.entry(entry.kind()).or_default() += 1;
+            }
+        }
+
+        stats
+    }
+
     /// Load lexicon from JSON file
     pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
         serde_json::from_str(json)
     }
 
+    /// Load a lexicon from JSON lines, one `{"word": ..., "entry": ...}`
+    /// record per line
+    ///
+    /// Records are read and added one at a time, so only the lexicon itself is
+    /// held in memory. Blank lines are skipped.
+    pub fn from_jsonl<R: BufRead>(reader: R) -> Result<Self, serde_json::Error> {
+        let mut lexicon = Self::new();
+
+        for line in reader.lines() {
+            let line = line.map_err(serde_json::Error::io)?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let record: JsonlRecord = serde_json::from_str(&line)?;
+            lexicon.add(record.word, record.entry);
+        }
+
+        Ok(lexicon)
+    }
+
     /// Save lexicon to JSON string
     pub fn to_json(&self) -> Result<String, serde_json::Error> {
         serde_json::to_string(self)
@@ -70,7 +324,17 @@ impl Default for Lexicon {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::entries::{DhatuEntry, Entry};
+    use crate::entries::{AvyayaEntry, DhatuEntry, Entry, TinantaEntry};
+
+    /// A bhvādi गम् entry with the given meaning
+    fn gam(artha: &str) -> Entry {
+        Entry::Dhatu(DhatuEntry {
+            root: "गम्".to_string(),
+            gana: "भ्वादि".to_string(),
+            artha: Some(artha.to_string()),
+            code: None,
+        })
+    }
 
     #[test]
     fn test_lexicon_new() {
@@ -104,25 +368,295 @@ mod tests {
     fn test_lexicon_multiple_entries() {
         let mut lex = Lexicon::new();
 
-        let entry1 = Entry::Dhatu(DhatuEntry {
-            root: "गम्".to_string(),
+        lex.add("गम्".to_string(), gam("to go"));
+        lex.add("गम्".to_string(), gam("to understand"));
+
+        assert_eq!(lex.len(), 1); // Same word
+        let entries = lex.lookup("गम्").unwrap();
+        assert_eq!(entries.len(), 2); // But two entries
+    }
+
+    #[test]
+    fn test_lexicon_homographs() {
+        let mut lex = Lexicon::new();
+
+        let go = gam("to go");
+        let understand = gam("to understand");
+
+        lex.add("गम्".to_string(), go.clone());
+        assert!(lex.homographs("गम्").is_empty());
+        assert!(!lex.has_homograph("गम्"));
+
+        // A duplicate of the same analysis is not a homograph
+        lex.add_allow_dup("गम्".to_string(), go.clone());
+        assert!(!lex.has_homograph("गम्"));
+
+        lex.add("गम्".to_string(), understand.clone());
+        assert_eq!(lex.homographs("गम्"), vec![&go, &understand]);
+        assert!(lex.has_homograph("गम्"));
+
+        assert!(lex.homographs("missing").is_empty());
+    }
+
+    #[test]
+    fn test_lexicon_iter() {
+        let mut lex = Lexicon::new();
+        let iti = Entry::Avyaya(AvyayaEntry {
+            word: "iti".to_string(),
+        });
+        let be = Entry::Dhatu(DhatuEntry {
+            root: "भू".to_string(),
             gana: "भ्वादि".to_string(),
-            artha: Some("to go".to_string()),
-            code: Some("01.1137".to_string()),
+            artha: None,
+            code: None,
+        });
+        let be_again = Entry::Dhatu(DhatuEntry {
+            root: "भू".to_string(),
+            gana: "भ्वादि".to_string(),
+            artha: Some("to be".to_string()),
+            code: None,
         });
 
-        let entry2 = Entry::Dhatu(DhatuEntry {
-            root: "गम्".to_string(),
+        lex.add("iti".to_string(), iti.clone());
+        lex.add("भू".to_string(), be.clone());
+        lex.add("भू".to_string(), be_again.clone());
+
+        let mut pairs: Vec<(&str, &Entry)> = lex.iter().collect();
+        pairs.sort_by_key(|(word, entry)| (*word, format!("{:?}", entry)));
+        let mut expected = vec![("iti", &iti), ("भू", &be), ("भू", &be_again)];
+        expected.sort_by_key(|(word, entry)| (*word, format!("{:?}", entry)));
+        assert_eq!(pairs, expected);
+
+        let mut words: Vec<&str> = lex.words().collect();
+        words.sort_unstable();
+        assert_eq!(words, vec!["iti", "भू"]);
+    }
+
+    #[test]
+    fn test_lexicon_add_deduplicates() {
+        let mut lex = Lexicon::new();
+
+        let entry = Entry::Dhatu(DhatuEntry {
+            root: "भू".to_string(),
             gana: "भ्वादि".to_string(),
-            artha: Some("to understand".to_string()),
-            code: Some("01.1138".to_string()),
+            artha: Some("to be".to_string()),
+            code: Some("01.0001".to_string()),
         });
 
-        lex.add("गम्".to_string(), entry1);
-        lex.add("गम्".to_string(), entry2);
+        lex.add("भू".to_string(), entry.clone());
+        lex.add("भू".to_string(), entry.clone());
+        assert_eq!(lex.lookup("भू").unwrap().len(), 1);
 
-        assert_eq!(lex.len(), 1); // Same word
-        let entries = lex.lookup("गम्").unwrap();
-        assert_eq!(entries.len(), 2); // But two entries
+        lex.add_allow_dup("भू".to_string(), entry);
+        assert_eq!(lex.lookup("भू").unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_lexicon_merge() {
+        let go = gam("to go");
+        let understand = gam("to understand");
+        let be = Entry::Dhatu(DhatuEntry {
+            root: "भू".to_string(),
+            gana: "भ्वादि".to_string(),
+            artha: Some("to be".to_string()),
+            code: Some("01.0001".to_string()),
+        });
+
+        let mut base = Lexicon::new();
+        base.add("गम्".to_string(), go.clone());
+
+        let mut user = Lexicon::new();
+        user.add("गम्".to_string(), go.clone());
+        user.add("गम्".to_string(), understand.clone());
+        user.add("भू".to_string(), be);
+
+        base.merge(user);
+
+        assert_eq!(base.len(), 2);
+        assert_eq!(base.lookup("गम्").unwrap(), &vec![go, understand]);
+        assert_eq!(base.lookup("भू").unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_lexicon_stats() {
+        let mut lex = Lexicon::new();
+        for artha in ["to go", "to understand"] {
+            lex.add("गम्".to_string(), gam(artha));
+        }
+        lex.add(
+            "भवति".to_string(),
+            Entry::Tinanta(TinantaEntry {
+                root: "भू".to_string(),
+                lakara: "lat".to_string(),
+                purusha: "prathama".to_string(),
+                vacana: "eka".to_string(),
+            }),
+        );
+        lex.add(
+            "च".to_string(),
+            Entry::Avyaya(AvyayaEntry {
+                word: "च".to_string(),
+            }),
+        );
+
+        let stats = lex.stats();
+        assert_eq!(stats.total_words, 3);
+        assert_eq!(stats.total_entries, 4);
+        assert_eq!(stats.count(EntryKind::Dhatu), 2);
+        assert_eq!(stats.count(EntryKind::Tinanta), 1);
+        assert_eq!(stats.count(EntryKind::Avyaya), 1);
+        assert_eq!(stats.count(EntryKind::Subanta), 0);
+        assert_eq!(stats.max_entries_per_word, 2);
+    }
+
+    #[test]
+    fn test_lexicon_paradigm() {
+        let tinanta = |root: &str, purusha: &str, vacana: &str| {
+            Entry::Tinanta(TinantaEntry {
+                root: root.to_string(),
+                lakara: "lat".to_string(),
+                purusha: purusha.to_string(),
+                vacana: vacana.to_string(),
+            })
+        };
+
+        let mut lex = Lexicon::new();
+        lex.add("Bavati".to_string(), tinanta("BU", "prathama", "eka"));
+        lex.add("BavataH".to_string(), tinanta("BU", "prathama", "dvi"));
+        lex.add("Bavanti".to_string(), tinanta("BU", "prathama", "bahu"));
+        lex.add("gacCati".to_string(), tinanta("gam", "prathama", "eka"));
+
+        let paradigm = lex.paradigm("BU");
+        assert_eq!(paradigm.len(), 3);
+        assert_eq!(
+            paradigm[&("lat".to_string(), "prathama".to_string(), "eka".to_string())],
+            vec!["Bavati"]
+        );
+        assert_eq!(
+            paradigm[&(
+                "lat".to_string(),
+                "prathama".to_string(),
+                "bahu".to_string()
+            )],
+            vec!["Bavanti"]
+        );
+        assert!(lex.paradigm("kf").is_empty());
+    }
+
+    #[test]
+    fn test_lexicon_sources() {
+        let go = gam("to go");
+        let understand = gam("to understand");
+
+        let mut lex = Lexicon::new();
+        lex.add_from("गम्".to_string(), go.clone(), "MW");
+        lex.add_from("गम्".to_string(), understand.clone(), "Apte");
+        lex.add_from("गम्".to_string(), go.clone(), "Apte");
+
+        assert_eq!(lex.lookup("गम्").unwrap().len(), 2);
+        assert_eq!(lex.lookup_from("गम्", "MW"), vec![&go]);
+        assert_eq!(lex.lookup_from("गम्", "Apte"), vec![&go, &understand]);
+        assert!(lex.lookup_from("गम्", "generated").is_empty());
+        assert!(lex.lookup_from("भू", "MW").is_empty());
+    }
+
+    #[test]
+    fn test_lexicon_merge_keeps_sources() {
+        let ca = Entry::Avyaya(AvyayaEntry {
+            word: "च".to_string(),
+        });
+        let va = Entry::Avyaya(AvyayaEntry {
+            word: "वा".to_string(),
+        });
+
+        let mut base = Lexicon::new();
+        base.add("च".to_string(), ca.clone());
+
+        let mut mw = Lexicon::new();
+        mw.add_from("च".to_string(), ca.clone(), "MW");
+        mw.add("वा".to_string(), va);
+
+        base.merge(mw);
+        assert_eq!(base.lookup("च").unwrap().len(), 1);
+        assert_eq!(base.lookup_from("च", "MW"), vec![&ca]);
+        assert!(base.lookup_from("वा", "MW").is_empty());
+
+        let json = base.to_json().unwrap();
+        assert_eq!(
+            Lexicon::from_json(&json).unwrap().lookup_from("च", "MW"),
+            vec![&ca]
+        );
+        let legacy = r#"{"entries":{"च":[{"Avyaya":{"word":"च"}}]}}"#;
+        assert!(Lexicon::from_json(legacy)
+            .unwrap()
+            .lookup_from("च", "MW")
+            .is_empty());
+    }
+
+    #[test]
+    fn test_lexicon_extend() {
+        let entry = Entry::Avyaya(AvyayaEntry {
+            word: "च".to_string(),
+        });
+
+        let mut lex = Lexicon::new();
+        lex.extend(vec![
+            ("च".to_string(), entry.clone()),
+            ("च".to_string(), entry.clone()),
+            ("वा".to_string(), entry),
+        ]);
+
+        assert_eq!(lex.len(), 2);
+        assert_eq!(lex.lookup("च").unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_from_jsonl() {
+        let jsonl = r#"{"word": "च", "entry": {"Avyaya": {"word": "च"}}}
+
+{"word": "गम्", "entry": {"Dhatu": {"root": "गम्", "gana": "भ्वादि", "artha": "to go", "code": null}}}
+{"word": "गच्छति", "entry": {"Tinanta": {"root": "गम्", "lakara": "lat", "purusha": "prathama", "vacana": "eka"}}}
+"#;
+        let lex = Lexicon::from_jsonl(jsonl.as_bytes()).unwrap();
+
+        assert_eq!(lex.len(), 3);
+        assert_eq!(
+            lex.lookup("च").unwrap(),
+            &vec![Entry::Avyaya(AvyayaEntry {
+                word: "च".to_string()
+            })]
+        );
+        assert_eq!(lex.lookup("गम्").unwrap(), &vec![gam("to go")]);
+        assert!(matches!(
+            &lex.lookup("गच्छति").unwrap()[..],
+            [Entry::Tinanta(TinantaEntry { root, .. })] if root == "गम्"
+        ));
+
+        let err = Lexicon::from_jsonl("{\"word\": \"च\"}\n".as_bytes());
+        assert!(err.is_err());
+    }
+
+    #[test]
+    fn test_lookup_canonical() {
+        let avyaya = |word: &str| {
+            Entry::Avyaya(AvyayaEntry {
+                word: word.to_string(),
+            })
+        };
+        let mut lex = Lexicon::new();
+        lex.add("saMkalpa".to_string(), avyaya("saMkalpa"));
+        lex.add("Santa".to_string(), avyaya("Santa"));
+
+        assert_eq!(lex.lookup("saNkalpa"), None);
+        assert_eq!(lex.lookup_canonical("saNkalpa"), vec![&avyaya("saMkalpa")]);
+        assert_eq!(lex.lookup_canonical("SaMta"), vec![&avyaya("Santa")]);
+        assert!(lex.lookup_canonical("Sama").is_empty());
+
+        // Words added after a lookup are found too
+        lex.add("saNkalpa".to_string(), avyaya("saNkalpa"));
+        assert_eq!(lex.lookup_canonical("saMkalpa").len(), 2);
+
+        let lex = Lexicon::from_json(&lex.to_json().unwrap()).unwrap();
+        assert_eq!(lex.lookup_canonical("SaMta"), vec![&avyaya("Santa")]);
     }
 }
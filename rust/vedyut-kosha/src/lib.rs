@@ -3,11 +3,15 @@
 //! This crate provides compact storage and fast lookup for millions of Sanskrit words.
 //! Target: 820ns average lookup time, <1 byte per word storage overhead.
 
+pub mod canonical;
 pub mod entries;
 pub mod lexicon;
 
-pub use entries::{AvyayaEntry, DhatuEntry, Entry, KrdantaEntry, SubantaEntry, TinantaEntry};
-pub use lexicon::Lexicon;
+pub use canonical::canonical_key;
+pub use entries::{
+    AvyayaEntry, DhatuEntry, Entry, EntryKind, KrdantaEntry, SubantaEntry, TinantaEntry,
+};
+pub use lexicon::{Lexicon, LexiconStats};
 
 #[cfg(test)]
 mod tests {
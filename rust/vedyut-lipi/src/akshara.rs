@@ -0,0 +1,237 @@
+//! Akṣara (orthographic syllable) segmentation
+//!
+//! An akṣara is a consonant cluster with its vowel and any trailing anusvāra,
+//! visarga or candrabindu, e.g. धर्मक्षेत्रे → ध, र्म, क्षे, त्रे. Splitting on
+//! akṣaras keeps matras and viramas attached to their base consonant.
+
+use crate::mappings::{is_slp1_consonant, is_slp1_vowel};
+use crate::{transliterate, Scheme};
+
+/// Split text into akṣaras
+///
+/// Devanagari is segmented directly; other schemes are segmented in SLP1 and
+/// each akṣara is converted back. Characters outside the script (spaces,
+/// punctuation) are returned as akṣaras of their own.
+pub fn aksharas(text: &str, scheme: Scheme) -> Vec<String> {
+    match scheme {
+        Scheme::Devanagari => devanagari_aksharas(text),
+        Scheme::Slp1 => slp1_aksharas(text),
+        _ => slp1_aksharas(&transliterate(text, scheme, Scheme::Slp1))
+            .iter()
+            .map(|a| transliterate(a, Scheme::Slp1, scheme))
+            .collect(),
+    }
+}
+
+/// Reverse the order of akṣaras, e.g. राम → मरा
+pub fn reverse_aksharas(text: &str, scheme: Scheme) -> String {
+    aksharas(text, scheme).into_iter().rev().collect()
+}
+
+/// Keep the first `n` akṣaras of the text
+pub fn truncate_aksharas(text: &str, scheme: Scheme, n: usize) -> String {
+    aksharas(text, scheme).into_iter().take(n).collect()
+}
+
+/// Split SLP1 text into syllables, each with its byte offset in the text
+///
+/// A syllable is a consonant cluster with the vowel after it and any
+/// anusvāra, visarga or candrabindu, as for [`aksharas`], but a word-final
+/// cluster with no vowel closes the syllable before it: `marut` → `ma`,
+/// `rut`. Spaces and punctuation only separate words and are not returned.
+pub fn slp1_syllables(text: &str) -> Vec<(usize, String)> {
+    let mut result: Vec<(usize, String)> = Vec::new();
+    let mut current: Option<(usize, String)> = None;
+    let mut has_vowel = false;
+    // Index in `result` of the current word's first syllable
+    let mut word_start = 0;
+
+    for (i, c) in text.char_indices() {
+        if is_slp1_consonant(c) || is_slp1_vowel(c) {
+            if has_vowel {
+                result.extend(current.take());
+            }
+            current.get_or_insert_with(|| (i, String::new())).1.push(c);
+            has_vowel = is_slp1_vowel(c);
+        } else if let (Some((_, syllable)), 'M' | 'H' | '~') = (&mut current, c) {
+            syllable.push(c);
+        } else {
+            close_word(&mut result, current.take(), has_vowel, word_start);
+            has_vowel = false;
+            word_start = result.len();
+        }
+    }
+
+    close_word(&mut result, current, has_vowel, word_start);
+    result
+}
+
+/// Add the last syllable of a word, merging a vowelless cluster into the
+/// syllable before it
+fn close_word(
+    result: &mut Vec<(usize, String)>,
+    last: Option<(usize, String)>,
+    has_vowel: bool,
+    word_start: usize,
+) {
+    let in_word = result.len() > word_start;
+    match (last, result.last_mut()) {
+        (Some((_, cluster)), Some((_, previous))) if !has_vowel && in_word => {
+            previous.push_str(&cluster)
+        }
+        (Some(syllable), _) => result.push(syllable),
+        (None, _) => {}
+    }
+}
+
+fn devanagari_aksharas(text: &str) -> Vec<String> {
+    let mut result: Vec<String> = Vec::new();
+    let mut current = String::new();
+
+    for c in text.chars() {
+        let joins = if is_deva_consonant(c) {
+            current.ends_with('्')
+        } else {
+            is_deva_sign(c) && !current.is_empty()
+        };
+
+        if !joins && !current.is_empty() {
+            result.push(std::mem::take(&mut current));
+        }
+        current.push(c);
+
+        if !is_deva_consonant(c) && !is_deva_vowel(c) && !is_deva_sign(c) {
+            result.push(std::mem::take(&mut current));
+        }
+    }
+
+    if !current.is_empty() {
+        result.push(current);
+    }
+    result
+}
+
+fn slp1_aksharas(text: &str) -> Vec<String> {
+    let mut result: Vec<String> = Vec::new();
+    let mut current = String::new();
+    let mut has_vowel = false;
+
+    for c in text.chars() {
+        if is_slp1_consonant(c) || is_slp1_vowel(c) {
+            if has_vowel {
+                result.push(std::mem::take(&mut current));
+            }
+            current.push(c);
+            has_vowel = is_slp1_vowel(c);
+        } else if matches!(c, 'M' | 'H' | '~') && !current.is_empty() {
+            current.push(c);
+        } else {
+            if !current.is_empty() {
+                result.push(std::mem::take(&mut current));
+            }
+            has_vowel = false;
+            result.push(c.to_string());
+        }
+    }
+
+    if !current.is_empty() {
+        result.push(current);
+    }
+    result
+}
+
+fn is_deva_consonant(c: char) -> bool {
+    matches!(c, '\u{0915}'..='\u{0939}' | '\u{0958}'..='\u{095F}' | '\u{0978}'..='\u{097F}')
+}
+
+fn is_deva_vowel(c: char) -> bool {
+    matches!(c, '\u{0904}'..='\u{0914}' | '\u{0960}'..='\u{0961}' | '\u{0972}'..='\u{0977}')
+}
+
+/// Signs that attach to the preceding letter: candrabindu, anusvāra, visarga,
+/// nukta, matras, virama and vowel-length marks
+fn is_deva_sign(c: char) -> bool {
+    matches!(
+        c,
+        '\u{0900}'..='\u{0903}'
+            | '\u{093A}'..='\u{094F}'
+            | '\u{0951}'..='\u{0957}'
+            | '\u{0962}'..='\u{0963}'
+            | '\u{200C}'
+            | '\u{200D}'
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_devanagari_aksharas() {
+        assert_eq!(
+            aksharas("धर्मक्षेत्रे", Scheme::Devanagari),
+            vec!["ध", "र्म", "क्षे", "त्रे"]
+        );
+        assert_eq!(
+            aksharas("वाक् रामः", Scheme::Devanagari),
+            vec!["वा", "क्", " ", "रा", "मः"]
+        );
+    }
+
+    #[test]
+    fn test_slp1_aksharas() {
+        assert_eq!(
+            aksharas("Darmakzetre", Scheme::Slp1),
+            vec!["Da", "rma", "kze", "tre"]
+        );
+        assert_eq!(aksharas("rAmaH", Scheme::Slp1), vec!["rA", "maH"]);
+        assert_eq!(aksharas("dharma", Scheme::Iast), vec!["dha", "rma"]);
+    }
+
+    #[test]
+    fn test_truncate_aksharas() {
+        assert_eq!(truncate_aksharas("धर्मक्षेत्रे", Scheme::Devanagari, 3), "धर्मक्षे");
+        assert_eq!(truncate_aksharas("राम", Scheme::Devanagari, 10), "राम");
+    }
+
+    #[test]
+    fn test_reverse_aksharas() {
+        assert_eq!(reverse_aksharas("राम", Scheme::Devanagari), "मरा");
+        assert_eq!(reverse_aksharas("कृष्ण", Scheme::Devanagari), "ष्णकृ");
+        assert_eq!(reverse_aksharas("rAma", Scheme::Slp1), "marA");
+    }
+
+    #[test]
+    fn test_slp1_syllables() {
+        assert_eq!(
+            slp1_syllables("dharmakzetre"),
+            vec![
+                (0, "dha".to_string()),
+                (3, "rma".to_string()),
+                (6, "kze".to_string()),
+                (9, "tre".to_string()),
+            ]
+        );
+        assert_eq!(
+            slp1_syllables("rAmaH gacCati"),
+            vec![
+                (0, "rA".to_string()),
+                (2, "maH".to_string()),
+                (6, "ga".to_string()),
+                (8, "cCa".to_string()),
+                (11, "ti".to_string()),
+            ]
+        );
+        assert_eq!(
+            slp1_syllables("marut vAk"),
+            vec![
+                (0, "ma".to_string()),
+                (2, "rut".to_string()),
+                (6, "vAk".to_string()),
+            ]
+        );
+        // A word with no vowel at all is kept as it is
+        assert_eq!(slp1_syllables("hrm"), vec![(0, "hrm".to_string())]);
+        assert!(slp1_syllables(" , ").is_empty());
+    }
+}
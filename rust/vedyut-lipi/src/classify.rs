@@ -0,0 +1,211 @@
+//! Character classification in any supported scheme
+
+use crate::indic::{gurmukhi_to_devanagari, malayalam_to_devanagari};
+use crate::mappings::{is_slp1_consonant, is_slp1_vowel};
+use crate::transliterate::DevanagariTables;
+use crate::{is_supported, transliterate, Scheme};
+
+/// Phonological role of a single character
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CharClass {
+    /// Independent vowel (अ, ā)
+    Vowel,
+    /// Consonant letter (क, k)
+    Consonant,
+    /// Dependent vowel sign (ा)
+    Matra,
+    /// Virama (्)
+    Virama,
+    /// Anusvāra or candrabindu (ं, ँ, ṃ)
+    Anusvara,
+    /// Visarga (ः, ḥ)
+    Visarga,
+    /// Anything else: digits, punctuation, whitespace, foreign letters
+    Other,
+}
+
+/// Classify a character written in the given scheme
+///
+/// Brahmic scripts are classified from their letter tables, Malayalam and
+/// Gurmukhi through their Devanagari value (a chillu is a consonant), and
+/// romanizations via their SLP1 value. Romanizations have no matras or virama.
+///
+/// # Returns
+/// `None` if the scheme is not implemented yet
+pub fn classify(c: char, scheme: Scheme) -> Option<CharClass> {
+    match scheme {
+        Scheme::Devanagari => Some(classify_devanagari(c)),
+        Scheme::Malayalam => Some(classify_via_devanagari(c, malayalam_to_devanagari)),
+        Scheme::Gurmukhi => Some(classify_via_devanagari(c, gurmukhi_to_devanagari)),
+        _ if scheme.is_brahmic() => None,
+        Scheme::Slp1 => Some(classify_slp1(c)),
+        _ if is_supported(scheme, Scheme::Slp1) => {
+            let slp1 = transliterate(&c.to_string(), scheme, Scheme::Slp1);
+            let mut chars = slp1.chars();
+            match (chars.next(), chars.next()) {
+                (Some(s), None) => Some(classify_slp1(s)),
+                _ => Some(CharClass::Other),
+            }
+        }
+        _ => None,
+    }
+}
+
+/// A malformed sign in Devanagari text, at a byte offset of the input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DevanagariIssue {
+    /// A vowel sign with no letter to attach to (after a space, a sign or
+    /// another vowel sign)
+    OrphanMatra { offset: usize },
+    /// A vowel sign after an independent vowel (आा)
+    MatraAfterVowel { offset: usize },
+    /// A virama with no consonant before it
+    LeadingVirama { offset: usize },
+}
+
+/// Find vowel signs and viramas that do not follow a consonant
+///
+/// Such signs are left behind by OCR or by cutting text in the middle of an
+/// akṣara, and are read inconsistently by the converters. A nukta after a
+/// consonant is skipped. A word-final virama (राजन्) is valid.
+pub fn validate_devanagari(text: &str) -> Vec<DevanagariIssue> {
+    let mut issues = Vec::new();
+    let mut prev = CharClass::Other;
+
+    for (offset, c) in text.char_indices() {
+        if c == '\u{093C}' {
+            continue;
+        }
+        let class = classify_devanagari(c);
+
+        match (class, prev) {
+            (CharClass::Matra, CharClass::Consonant) => {}
+            (CharClass::Matra, CharClass::Vowel) => {
+                issues.push(DevanagariIssue::MatraAfterVowel { offset })
+            }
+            (CharClass::Matra, _) => issues.push(DevanagariIssue::OrphanMatra { offset }),
+            (CharClass::Virama, CharClass::Consonant) => {}
+            (CharClass::Virama, _) => issues.push(DevanagariIssue::LeadingVirama { offset }),
+            _ => {}
+        }
+        prev = class;
+    }
+
+    issues
+}
+
+/// Classify a char of a script by the first char of its Devanagari spelling
+fn classify_via_devanagari(c: char, to_devanagari: fn(&str) -> String) -> CharClass {
+    to_devanagari(&c.to_string())
+        .chars()
+        .next()
+        .map_or(CharClass::Other, classify_devanagari)
+}
+
+fn classify_devanagari(c: char) -> CharClass {
+    let tables = DevanagariTables::get();
+
+    if tables.dev_vowel_index.contains_key(&c) {
+        CharClass::Vowel
+    } else if tables.dev_consonant_index.contains_key(&c) {
+        CharClass::Consonant
+    } else if tables.dev_matra_index.contains_key(&c) {
+        CharClass::Matra
+    } else {
+        match c {
+            '्' => CharClass::Virama,
+            'ं' | 'ँ' => CharClass::Anusvara,
+            'ः' => CharClass::Visarga,
+            _ => CharClass::Other,
+        }
+    }
+}
+
+fn classify_slp1(c: char) -> CharClass {
+    if is_slp1_vowel(c) {
+        CharClass::Vowel
+    } else if is_slp1_consonant(c) {
+        CharClass::Consonant
+    } else {
+        match c {
+            'M' | '~' => CharClass::Anusvara,
+            'H' => CharClass::Visarga,
+            _ => CharClass::Other,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_devanagari() {
+        assert_eq!(
+            classify('क', Scheme::Devanagari),
+            Some(CharClass::Consonant)
+        );
+        assert_eq!(classify('ा', Scheme::Devanagari), Some(CharClass::Matra));
+        assert_eq!(classify('आ', Scheme::Devanagari), Some(CharClass::Vowel));
+        assert_eq!(classify('्', Scheme::Devanagari), Some(CharClass::Virama));
+        assert_eq!(classify('ः', Scheme::Devanagari), Some(CharClass::Visarga));
+        assert_eq!(classify('।', Scheme::Devanagari), Some(CharClass::Other));
+    }
+
+    #[test]
+    fn test_validate_devanagari() {
+        assert!(validate_devanagari("रामः राजन् क़ि धर्मक्षेत्रे").is_empty());
+
+        // Orphan matra at the start of a word and after a virama
+        assert_eq!(
+            validate_devanagari("ि राम"),
+            vec![DevanagariIssue::OrphanMatra { offset: 0 }]
+        );
+        assert_eq!(
+            validate_devanagari("क्ा"),
+            vec![DevanagariIssue::OrphanMatra { offset: 6 }]
+        );
+        assert_eq!(
+            validate_devanagari("आा"),
+            vec![DevanagariIssue::MatraAfterVowel { offset: 3 }]
+        );
+
+        // Dangling virama: copied without its consonant, or after a vowel sign
+        assert_eq!(
+            validate_devanagari("्य कि्"),
+            vec![
+                DevanagariIssue::LeadingVirama { offset: 0 },
+                DevanagariIssue::LeadingVirama { offset: 13 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_classify_romanization() {
+        assert_eq!(classify('ā', Scheme::Iast), Some(CharClass::Vowel));
+        assert_eq!(classify('ṣ', Scheme::Iast), Some(CharClass::Consonant));
+        assert_eq!(classify('ṃ', Scheme::Iast), Some(CharClass::Anusvara));
+        assert_eq!(classify('A', Scheme::Slp1), Some(CharClass::Vowel));
+        assert_eq!(
+            classify('H', Scheme::HarvardKyoto),
+            Some(CharClass::Visarga)
+        );
+        assert_eq!(classify(' ', Scheme::Iast), Some(CharClass::Other));
+    }
+
+    #[test]
+    fn test_classify_malayalam_gurmukhi() {
+        assert_eq!(classify('ക', Scheme::Malayalam), Some(CharClass::Consonant));
+        assert_eq!(classify('ാ', Scheme::Malayalam), Some(CharClass::Matra));
+        assert_eq!(classify('ൻ', Scheme::Malayalam), Some(CharClass::Consonant));
+        assert_eq!(classify('്', Scheme::Malayalam), Some(CharClass::Virama));
+        assert_eq!(classify('ਅ', Scheme::Gurmukhi), Some(CharClass::Vowel));
+        assert_eq!(classify('ਕ', Scheme::Gurmukhi), Some(CharClass::Consonant));
+        assert_eq!(classify('ੰ', Scheme::Gurmukhi), Some(CharClass::Anusvara));
+    }
+
+    #[test]
+    fn test_classify_unimplemented_scheme() {
+        assert_eq!(classify('க', Scheme::Tamil), None);
+    }
+}
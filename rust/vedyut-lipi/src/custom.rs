@@ -0,0 +1,125 @@
+//! User-defined transliteration schemes
+//!
+//! A scheme only has to convert to and from SLP1, the pivot every built-in
+//! scheme goes through; [`TableScheme`] builds one from a mapping table.
+
+use crate::transliterate::map_to_slp1;
+use crate::{transliterate, Scheme};
+
+/// A scheme defined by its conversion to and from SLP1
+#[allow(clippy::wrong_self_convention)]
+pub trait TransliterationScheme {
+    fn to_slp1(&self, s: &str) -> String;
+    fn from_slp1(&self, s: &str) -> String;
+}
+
+impl TransliterationScheme for Scheme {
+    fn to_slp1(&self, s: &str) -> String {
+        transliterate(s, *self, Scheme::Slp1)
+    }
+
+    fn from_slp1(&self, s: &str) -> String {
+        transliterate(s, Scheme::Slp1, *self)
+    }
+}
+
+/// A scheme given as a table of (scheme text, SLP1) pairs
+///
+/// Conversion is greedy longest-match in both directions. When several
+/// spellings map to the same SLP1, the first one listed is used for output.
+#[derive(Debug, Clone)]
+pub struct TableScheme {
+    to_slp1: Vec<(String, String)>,
+    from_slp1: Vec<(String, String)>,
+}
+
+impl TableScheme {
+    pub fn from_pairs(pairs: &[(&str, &str)]) -> Self {
+        let longest_first = |mut map: Vec<(String, String)>| {
+            map.sort_by_key(|(key, _)| std::cmp::Reverse(key.len()));
+            map
+        };
+
+        let to_slp1 = pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        let from_slp1 = pairs
+            .iter()
+            .map(|(k, v)| (v.to_string(), k.to_string()))
+            .collect();
+
+        Self {
+            to_slp1: longest_first(to_slp1),
+            from_slp1: longest_first(from_slp1),
+        }
+    }
+}
+
+impl TransliterationScheme for TableScheme {
+    fn to_slp1(&self, s: &str) -> String {
+        map_with(s, &self.to_slp1)
+    }
+
+    fn from_slp1(&self, s: &str) -> String {
+        map_with(s, &self.from_slp1)
+    }
+}
+
+fn map_with(text: &str, table: &[(String, String)]) -> String {
+    let mapping: Vec<(&str, &str)> = table
+        .iter()
+        .map(|(k, v)| (k.as_str(), v.as_str()))
+        .collect();
+    map_to_slp1(text, &mapping)
+}
+
+/// Transliterate between two schemes, either of which may be user-defined
+pub fn transliterate_custom(
+    text: &str,
+    from: &dyn TransliterationScheme,
+    to: &dyn TransliterationScheme,
+) -> String {
+    to.from_slp1(&from.to_slp1(text))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Toy romanization with doubled vowels for length and `sh` for ś
+    fn toy() -> TableScheme {
+        TableScheme::from_pairs(&[
+            ("aa", "A"),
+            ("a", "a"),
+            ("ii", "I"),
+            ("i", "i"),
+            ("sh", "S"),
+            ("s", "s"),
+            ("k", "k"),
+            ("v", "v"),
+            ("r", "r"),
+            ("m", "m"),
+            ("h", "h"),
+            ("H", "H"),
+        ])
+    }
+
+    #[test]
+    fn test_table_scheme() {
+        let toy = toy();
+        assert_eq!(toy.to_slp1("shiva"), "Siva");
+        assert_eq!(toy.from_slp1("rAmaH"), "raamaH");
+    }
+
+    #[test]
+    fn test_transliterate_custom() {
+        let toy = toy();
+        assert_eq!(
+            transliterate_custom("shiivaa", &toy, &Scheme::Devanagari),
+            "शीवा"
+        );
+        assert_eq!(transliterate_custom("rāmaḥ", &Scheme::Iast, &toy), "raamaH");
+        assert_eq!(transliterate_custom("kaavya", &toy, &toy), "kaavya");
+    }
+}
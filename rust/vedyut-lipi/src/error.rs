@@ -0,0 +1,91 @@
+//! Errors of the fallible transliteration functions
+//!
+//! [`transliterate`](crate::transliterate) never fails: unknown schemes and
+//! characters pass through. The `try_` and validation functions report them
+//! with [`TransliterateError`] instead.
+
+use crate::schemes::Scheme;
+
+/// Error from a fallible transliteration function
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransliterateError {
+    /// No converter exists yet for this pair of schemes
+    SchemeNotImplemented { from: Scheme, to: Scheme },
+    /// The text is written in another script than the source scheme
+    ScriptMismatch { expected: Scheme, found: Scheme },
+    /// A character that the scheme does not use, at a byte offset of the input
+    InvalidInput {
+        scheme: Scheme,
+        offset: usize,
+        character: char,
+    },
+}
+
+impl std::fmt::Display for TransliterateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TransliterateError::SchemeNotImplemented { from, to } => {
+                write!(
+                    f,
+                    "Transliteration from {} to {} is not implemented",
+                    from, to
+                )
+            }
+            TransliterateError::ScriptMismatch { expected, found } => {
+                write!(f, "Expected {} text, found {}", expected, found)
+            }
+            TransliterateError::InvalidInput {
+                scheme,
+                offset,
+                character,
+            } => write!(
+                f,
+                "Invalid {} input: {:?} at byte {}",
+                scheme, character, offset
+            ),
+        }
+    }
+}
+
+impl std::error::Error for TransliterateError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let err = TransliterateError::SchemeNotImplemented {
+            from: Scheme::Tamil,
+            to: Scheme::Slp1,
+        };
+        assert_eq!(
+            err.to_string(),
+            "Transliteration from Tamil to SLP1 is not implemented"
+        );
+
+        let err = TransliterateError::ScriptMismatch {
+            expected: Scheme::Devanagari,
+            found: Scheme::Telugu,
+        };
+        assert_eq!(err.to_string(), "Expected Devanagari text, found Telugu");
+
+        let err = TransliterateError::InvalidInput {
+            scheme: Scheme::Slp1,
+            offset: 2,
+            character: 'ā',
+        };
+        assert_eq!(err.to_string(), "Invalid SLP1 input: 'ā' at byte 2");
+    }
+
+    #[test]
+    fn test_boxed_error() {
+        fn convert() -> Result<String, Box<dyn std::error::Error>> {
+            Err(TransliterateError::SchemeNotImplemented {
+                from: Scheme::Tamil,
+                to: Scheme::Slp1,
+            })?
+        }
+        assert!(convert().is_err());
+    }
+}
@@ -0,0 +1,289 @@
+//! Brahmic scripts converted through Devanagari
+//!
+//! The Unicode blocks of the Indic scripts follow the layout of the
+//! Devanagari block, so most letters and signs map to Devanagari by a fixed
+//! offset. Signs a script adds or lacks (Malayalam chillus, the Gurmukhi
+//! addak, ...) are handled around the shift.
+
+const DEVANAGARI: u32 = 0x0900;
+const GURMUKHI: u32 = 0x0A00;
+const MALAYALAM: u32 = 0x0D00;
+
+/// Blocks laid out like the Devanagari block, from Bengali (U+0980) to
+/// Malayalam (U+0D00)
+const PARALLEL_BLOCKS: std::ops::Range<u32> = 0x0980..0x0D80;
+
+const VIRAMA: char = '\u{094D}';
+const MALAYALAM_VIRAMA: char = '\u{0D4D}';
+const ANUSVARA: char = '\u{0902}';
+
+const GURMUKHI_NUKTA: char = '\u{0A3C}';
+const TIPPI: char = '\u{0A70}';
+const ADDAK: char = '\u{0A71}';
+
+/// Chillus (U+0D7A–U+0D7F) and the consonants they stand for with a virama
+const MALAYALAM_CHILLUS: &[(char, char)] = &[
+    ('\u{0D7A}', '\u{0D23}'), // ṇ
+    ('\u{0D7B}', '\u{0D28}'), // n
+    ('\u{0D7C}', '\u{0D30}'), // r
+    ('\u{0D7D}', '\u{0D32}'), // l
+    ('\u{0D7E}', '\u{0D33}'), // ḷ
+    ('\u{0D7F}', '\u{0D15}'), // k
+];
+
+/// Move a char of the Devanagari block to the same offset in another block
+///
+/// Om and the dandas are shared by all scripts and stay as they are.
+fn from_devanagari_block(c: char, base: u32) -> char {
+    match c {
+        '\u{0950}' | '\u{0964}' | '\u{0965}' => c,
+        '\u{0900}'..='\u{097F}' => char::from_u32(c as u32 - DEVANAGARI + base).unwrap_or(c),
+        _ => c,
+    }
+}
+
+/// Move a char of a script's block to the same offset in the Devanagari block
+fn to_devanagari_block(c: char, base: u32) -> char {
+    match c as u32 {
+        code if (base..base + 0x80).contains(&code) => {
+            char::from_u32(code - base + DEVANAGARI).unwrap_or(c)
+        }
+        _ => c,
+    }
+}
+
+/// Read each char of a block parallel to Devanagari as the Devanagari char
+/// at the same offset, e.g. Kannada ಕ (U+0C95) as क (U+0915)
+pub(crate) fn parallel_blocks_to_devanagari(text: &str) -> String {
+    text.chars()
+        .map(|c| match c as u32 {
+            code if PARALLEL_BLOCKS.contains(&code) => to_devanagari_block(c, code & !0x7F),
+            _ => c,
+        })
+        .collect()
+}
+
+/// Rewrite Malayalam as Devanagari, spelling each chillu as consonant + virama
+pub(crate) fn malayalam_to_devanagari(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        if let Some(&(_, consonant)) = MALAYALAM_CHILLUS.iter().find(|(chillu, _)| *chillu == c) {
+            result.push(to_devanagari_block(consonant, MALAYALAM));
+            result.push(VIRAMA);
+        } else if c == '\u{0D57}' {
+            // The au length mark, written for the older au sign ൌ
+            result.push('\u{094C}');
+        } else {
+            result.push(to_devanagari_block(c, MALAYALAM));
+        }
+    }
+
+    result
+}
+
+/// Rewrite Devanagari as Malayalam
+///
+/// Malayalam has no nukta, so nuktas are dropped.
+pub(crate) fn devanagari_to_malayalam(text: &str) -> String {
+    text.chars()
+        .filter(|&c| c != '\u{093C}')
+        .map(|c| from_devanagari_block(c, MALAYALAM))
+        .collect()
+}
+
+/// Rewrite Gurmukhi as Devanagari
+///
+/// Tippi and bindi both read as anusvāra, and addak doubles the following
+/// consonant, with an aspirate doubled by its plain stop (ਅੱਖ → अक्ख).
+pub(crate) fn gurmukhi_to_devanagari(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            TIPPI => result.push(ANUSVARA),
+            ADDAK => {
+                if let Some(&next) = chars.peek() {
+                    let consonant = to_devanagari_block(next, GURMUKHI);
+                    if is_devanagari_consonant(consonant) {
+                        result.push(deaspirate(consonant));
+                        result.push(VIRAMA);
+                    }
+                }
+            }
+            // ਸ਼ and ਲ਼ decompose to the plain letter with nukta
+            '\u{0A38}' | '\u{0A32}' if chars.peek() == Some(&GURMUKHI_NUKTA) => {
+                chars.next();
+                result.push(if c == '\u{0A38}' { 'श' } else { 'ळ' });
+            }
+            _ => result.push(to_devanagari_block(c, GURMUKHI)),
+        }
+    }
+
+    result
+}
+
+/// Rewrite Devanagari as Gurmukhi
+///
+/// Doubled consonants are written with addak, and anusvāra as tippi after a
+/// short vowel and as bindi otherwise. Gurmukhi has no vocalic ṛ, ṝ, ḷ, ḹ
+/// or ṣ, so these are spelled ri, rī, li, lī and ś, and do not read back.
+pub(crate) fn devanagari_to_gurmukhi(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let chars: Vec<char> = text.chars().collect();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let geminate = is_devanagari_consonant(c)
+            && chars.get(i + 1) == Some(&VIRAMA)
+            && chars
+                .get(i + 2)
+                .is_some_and(|&next| is_devanagari_consonant(next) && deaspirate(next) == c)
+            && !result.is_empty();
+
+        if geminate {
+            result.push(ADDAK);
+            i += 2;
+            continue;
+        }
+
+        match c {
+            'ऋ' => result.push_str("ਰਿ"),
+            'ॠ' => result.push_str("ਰੀ"),
+            'ऌ' => result.push_str("ਲਿ"),
+            'ॡ' => result.push_str("ਲੀ"),
+            '\u{0943}' => result.push_str("੍ਰਿ"),
+            '\u{0944}' => result.push_str("੍ਰੀ"),
+            '\u{0962}' => result.push_str("੍ਲਿ"),
+            '\u{0963}' => result.push_str("੍ਲੀ"),
+            'ष' => result.push('\u{0A36}'),
+            // Gurmukhi has no avagraha of its own
+            'ऽ' => result.push(c),
+            ANUSVARA if result.chars().last().is_some_and(takes_tippi) => result.push(TIPPI),
+            _ => result.push(from_devanagari_block(c, GURMUKHI)),
+        }
+        i += 1;
+    }
+
+    result
+}
+
+/// Gurmukhi letters after which anusvāra is written as tippi: a consonant
+/// with inherent a, a short vowel sign, or a short independent vowel
+fn takes_tippi(c: char) -> bool {
+    matches!(
+        c,
+        '\u{0A15}'..='\u{0A39}' | '\u{0A59}'..='\u{0A5E}' | '\u{0A3F}' | '\u{0A41}' | '\u{0A42}' | 'ਅ' | 'ਇ' | 'ਉ'
+    )
+}
+
+fn is_devanagari_consonant(c: char) -> bool {
+    matches!(c, '\u{0915}'..='\u{0939}' | '\u{0958}'..='\u{095F}')
+}
+
+/// The plain stop of an aspirated stop, or the letter itself
+fn deaspirate(c: char) -> char {
+    match c {
+        'ख' => 'क',
+        'घ' => 'ग',
+        'छ' => 'च',
+        'झ' => 'ज',
+        'ठ' => 'ट',
+        'ढ' => 'ड',
+        'थ' => 'त',
+        'ध' => 'द',
+        'फ' => 'प',
+        'भ' => 'ब',
+        _ => c,
+    }
+}
+
+/// Write each word-final ṇ, n, r, l or ḷ with virama as its chillu,
+/// e.g. അവന് → അവൻ
+pub(crate) fn malayalam_chillus(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        let chillu = MALAYALAM_CHILLUS
+            .iter()
+            .take(5)
+            .find(|&&(_, consonant)| consonant == c)
+            .map(|&(chillu, _)| chillu);
+
+        if let (Some(chillu), Some(&MALAYALAM_VIRAMA)) = (chillu, chars.peek()) {
+            let mut after = chars.clone();
+            after.next();
+            if !after
+                .peek()
+                .is_some_and(|&next| continues_malayalam_word(next))
+            {
+                chars.next();
+                result.push(chillu);
+                continue;
+            }
+        }
+        result.push(c);
+    }
+
+    result
+}
+
+/// Malayalam letters and signs, and the joiners, which continue a word
+fn continues_malayalam_word(c: char) -> bool {
+    matches!(
+        c,
+        '\u{0D00}'..='\u{0D4F}' | '\u{0D54}'..='\u{0D63}' | '\u{0D7A}'..='\u{0D7F}' | '\u{200C}' | '\u{200D}'
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_malayalam_devanagari_shift() {
+        assert_eq!(malayalam_to_devanagari("രാമഃ"), "रामः");
+        assert_eq!(devanagari_to_malayalam("रामः ।"), "രാമഃ ।");
+        assert_eq!(malayalam_to_devanagari("അവൻ"), "अवन्");
+        assert_eq!(malayalam_to_devanagari("കൗ"), "कौ");
+    }
+
+    #[test]
+    fn test_parallel_blocks_to_devanagari() {
+        assert_eq!(parallel_blocks_to_devanagari("ಕರ್ನಾಟಕ"), "कर्नाटक");
+        assert_eq!(parallel_blocks_to_devanagari("ଧର୍ମ"), "धर्म");
+        assert_eq!(parallel_blocks_to_devanagari("राम abc"), "राम abc");
+    }
+
+    #[test]
+    fn test_gurmukhi_devanagari() {
+        assert_eq!(gurmukhi_to_devanagari("ਰਾਮਃ"), "रामः");
+        assert_eq!(devanagari_to_gurmukhi("रामः ।"), "ਰਾਮਃ ।");
+        assert_eq!(gurmukhi_to_devanagari("ਸੰਤ ਸਾਂਈ"), "संत सांई");
+        assert_eq!(devanagari_to_gurmukhi("संत सांई"), "ਸੰਤ ਸਾਂਈ");
+        assert_eq!(gurmukhi_to_devanagari("ਸ\u{0A3C}ਿਵ"), "शिव");
+        assert_eq!(devanagari_to_gurmukhi("कृष्ण"), "ਕ੍ਰਿ\u{0A36}੍ਣ");
+    }
+
+    #[test]
+    fn test_gurmukhi_addak() {
+        assert_eq!(gurmukhi_to_devanagari("ਪੱਤ"), "पत्त");
+        assert_eq!(gurmukhi_to_devanagari("ਅੱਖ"), "अक्ख");
+        assert_eq!(devanagari_to_gurmukhi("पत्त"), "ਪੱਤ");
+        assert_eq!(devanagari_to_gurmukhi("अक्ख"), "ਅੱਖ");
+        assert_eq!(devanagari_to_gurmukhi("सत्त्व"), "ਸੱਤ੍ਵ");
+        // Different consonants keep the virama
+        assert_eq!(devanagari_to_gurmukhi("धर्म"), "ਧਰ੍ਮ");
+    }
+
+    #[test]
+    fn test_malayalam_chillus() {
+        assert_eq!(malayalam_chillus("അവന്"), "അവൻ");
+        // Only word-final consonants become chillus
+        assert_eq!(malayalam_chillus("അന്യ"), "അന്യ");
+        assert_eq!(malayalam_chillus("പിതൄന് മരുത് കുര്, ഫലള്"), "പിതൄൻ മരുത് കുർ, ഫലൾ");
+    }
+}
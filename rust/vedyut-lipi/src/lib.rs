@@ -3,12 +3,30 @@
 //! This crate provides efficient transliteration between various scripts
 //! commonly used for Sanskrit text, including Devanagari, IAST, SLP1, and others.
 
+pub mod akshara;
+pub mod classify;
+pub mod custom;
+pub mod error;
+mod indic;
 pub mod mappings;
 pub mod schemes;
+mod schwa;
+pub mod script;
 pub mod transliterate;
+pub mod verse;
 
-pub use schemes::Scheme;
-pub use transliterate::transliterate;
+pub use akshara::{aksharas, reverse_aksharas, slp1_syllables, truncate_aksharas};
+pub use classify::{classify, validate_devanagari, CharClass, DevanagariIssue};
+pub use custom::{transliterate_custom, TableScheme, TransliterationScheme};
+pub use error::TransliterateError;
+pub use schemes::{Scheme, SchemeFeatures};
+pub use script::{contains_any_script, split_by_script, transliterate_brahmic_runs, ScriptRun};
+pub use transliterate::{
+    generic_brahmic_to_slp1, is_supported, supported_pairs, transliterate, transliterate_markup,
+    transliterate_with_options, try_transliterate, validate_slp1, AnusvaraGlyph, AnusvaraRender,
+    OmStyle, TransliterateOptions,
+};
+pub use verse::map_padas;
 
 #[cfg(test)]
 mod tests {
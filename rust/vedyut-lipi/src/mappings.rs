@@ -1,4 +1,6 @@
 use rustc_hash::FxHashMap;
+use std::cmp::Reverse;
+use std::sync::OnceLock;
 
 pub struct SchemeData {
     pub name: &'static str,
@@ -18,7 +20,7 @@ pub fn get_slp1_swaras() -> Vec<&'static str> {
 pub fn get_slp1_vyanjanas() -> Vec<&'static str> {
     vec![
         "k", "K", "g", "G", "N", "c", "C", "j", "J", "Y", "w", "W", "q", "Q", "R", "t", "T", "d",
-        "D", "n", "p", "P", "b", "B", "m", "y", "r", "l", "v", "S", "z", "s", "h",
+        "D", "n", "p", "P", "b", "B", "m", "y", "r", "l", "v", "S", "z", "s", "h", "L",
     ]
 }
 
@@ -35,10 +37,22 @@ pub fn get_devanagari_matras() -> Vec<&'static str> {
 pub fn get_devanagari_vyanjanas() -> Vec<&'static str> {
     vec![
         "क", "ख", "ग", "घ", "ङ", "च", "छ", "ज", "झ", "ञ", "ट", "ठ", "ड", "ढ", "ण", "त", "थ", "द",
-        "ध", "न", "प", "फ", "ब", "भ", "म", "य", "र", "ल", "व", "श", "ष", "स", "ह",
+        "ध", "न", "प", "फ", "ब", "भ", "म", "य", "र", "ल", "व", "श", "ष", "स", "ह", "ळ",
     ]
 }
 
+/// Devanagari letters outside the Sanskrit alphabet: the Sindhi implosives
+pub fn get_devanagari_extended_vyanjanas() -> Vec<&'static str> {
+    vec!["ॻ", "ॼ", "ॾ", "ॿ"]
+}
+
+/// SLP1 placeholders for the extended letters, using the IPA implosives
+///
+/// SLP1 has no ASCII letters to spare, and these never occur in Sanskrit text.
+pub fn get_slp1_extended_vyanjanas() -> Vec<&'static str> {
+    vec!["ɠ", "ʄ", "ɗ", "ɓ"]
+}
+
 pub fn get_slp1_scheme() -> SchemeData {
     SchemeData {
         name: "slp1",
@@ -112,12 +126,17 @@ pub fn get_iast_to_slp1() -> Vec<(&'static str, &'static str)> {
         ("ṣ", "z"),
         ("s", "s"),
         ("h", "h"),
+        // Vedic retroflex ḻ (line below), distinct from vocalic ḷ (dot below)
+        ("ḻ", "L"),
         ("ṃ", "M"),
+        // Dot-above anusvāra, read only; output uses ṃ unless configured
+        ("ṁ", "M"),
         ("ḥ", "H"),
+        ("m̐", "~"),
         ("'", "'"),
     ];
     // Sort by length of key descending
-    map.sort_by(|a, b| b.0.len().cmp(&a.0.len()));
+    map.sort_by_key(|(key, _)| Reverse(key.len()));
     map
 }
 
@@ -174,10 +193,125 @@ pub fn get_hk_to_slp1() -> Vec<(&'static str, &'static str)> {
         ("H", "H"),
         ("'", "'"),
     ];
-    map.sort_by(|a, b| b.0.len().cmp(&a.0.len()));
+    map.sort_by_key(|(key, _)| Reverse(key.len()));
     map
 }
 
+/// SLP1 -> Harvard-Kyoto, sorted by key length descending
+///
+/// HK writes ś as `z` and ṣ as `S`, the opposite case pairing of SLP1 (`S`/`z`).
+/// Those two entries are spelled out rather than derived by inverting
+/// [`get_hk_to_slp1`], so the sibilants can never be swapped by inversion order.
+pub fn get_slp1_to_hk() -> Vec<(&'static str, &'static str)> {
+    let mut map: Vec<(&'static str, &'static str)> = get_hk_to_slp1()
+        .into_iter()
+        .filter(|(hk, _)| !matches!(*hk, "z" | "S"))
+        .map(|(hk, slp1)| (slp1, hk))
+        .collect();
+    map.push(("S", "z"));
+    map.push(("z", "S"));
+    map.sort_by_key(|(key, _)| Reverse(key.len()));
+    map
+}
+
+/// ITRANS -> SLP1, sorted by key length descending
+///
+/// Alternative spellings (`aa` for `A`, `.m` for `M`, `w` for `v`, ...) are
+/// listed after the preferred one, which is what [`get_slp1_to_itrans`] writes.
+pub fn get_itrans_to_slp1() -> Vec<(&'static str, &'static str)> {
+    let mut map = ITRANS_TO_SLP1.to_vec();
+    map.sort_by_key(|(key, _)| Reverse(key.len()));
+    map
+}
+
+/// SLP1 -> ITRANS (preferred spellings), sorted by key length descending
+pub fn get_slp1_to_itrans() -> Vec<(&'static str, &'static str)> {
+    let mut map: Vec<(&'static str, &'static str)> = Vec::new();
+    for &(itrans, slp1) in ITRANS_TO_SLP1 {
+        if !slp1.is_empty() && !map.iter().any(|(key, _)| *key == slp1) {
+            map.push((slp1, itrans));
+        }
+    }
+    map.sort_by_key(|(key, _)| Reverse(key.len()));
+    map
+}
+
+const ITRANS_TO_SLP1: &[(&str, &str)] = &[
+    ("a", "a"),
+    ("A", "A"),
+    ("aa", "A"),
+    ("i", "i"),
+    ("I", "I"),
+    ("ii", "I"),
+    ("u", "u"),
+    ("U", "U"),
+    ("uu", "U"),
+    ("RRi", "f"),
+    ("R^i", "f"),
+    ("RRI", "F"),
+    ("R^I", "F"),
+    ("LLi", "x"),
+    ("L^i", "x"),
+    ("LLI", "X"),
+    ("L^I", "X"),
+    ("e", "e"),
+    ("ai", "E"),
+    ("o", "o"),
+    ("au", "O"),
+    ("k", "k"),
+    ("kh", "K"),
+    ("g", "g"),
+    ("gh", "G"),
+    ("~N", "N"),
+    ("N^", "N"),
+    ("ch", "c"),
+    ("c", "c"),
+    ("Ch", "C"),
+    ("chh", "C"),
+    ("j", "j"),
+    ("jh", "J"),
+    ("~n", "Y"),
+    ("JN", "Y"),
+    ("T", "w"),
+    ("Th", "W"),
+    ("D", "q"),
+    ("Dh", "Q"),
+    ("N", "R"),
+    ("t", "t"),
+    ("th", "T"),
+    ("d", "d"),
+    ("dh", "D"),
+    ("n", "n"),
+    ("p", "p"),
+    ("ph", "P"),
+    ("b", "b"),
+    ("bh", "B"),
+    ("m", "m"),
+    ("y", "y"),
+    ("r", "r"),
+    ("l", "l"),
+    ("v", "v"),
+    ("w", "v"),
+    ("sh", "S"),
+    ("Sh", "z"),
+    ("shh", "z"),
+    ("s", "s"),
+    ("h", "h"),
+    ("L", "L"),
+    ("x", "kz"),
+    ("GY", "jY"),
+    // Anusvāra, visarga, candrabindu and avagraha
+    ("M", "M"),
+    (".m", "M"),
+    (".n", "M"),
+    ("H", "H"),
+    (".N", "~"),
+    (".a", "'"),
+    // Explicit virama (a consonant cluster needs none)
+    (".h", ""),
+];
+
+/// Independent Devanagari form of an SLP1 letter or sign, e.g. `J` → झ
 pub fn get_slp1_to_devanagari(c: char) -> Option<&'static str> {
     match c {
         // Vowels
@@ -204,31 +338,36 @@ pub fn get_slp1_to_devanagari(c: char) -> Option<&'static str> {
         'c' => Some("च"),
         'C' => Some("छ"),
         'j' => Some("ज"),
-        'झ' => Some("झ"),
-        'ञ' => Some("ञ"),
-        'ट' => Some("ट"),
-        'ठ' => Some("ठ"),
-        'ड' => Some("ड"),
-        'ढ' => Some("ढ"),
-        'ण' => Some("ण"),
-        'त' => Some("त"),
-        'थ' => Some("थ"),
-        'द' => Some("द"),
-        'ध' => Some("ध"),
-        'न' => Some("न"),
-        'प' => Some("प"),
-        'फ' => Some("फ"),
-        'ब' => Some("ब"),
-        'भ' => Some("भ"),
-        'म' => Some("म"),
-        'य' => Some("य"),
-        'र' => Some("र"),
-        'ल' => Some("ल"),
-        'व' => Some("व"),
-        'श' => Some("श"),
-        'ष' => Some("ष"),
-        'स' => Some("स"),
-        'ह' => Some("ह"),
+        'J' => Some("झ"),
+        'Y' => Some("ञ"),
+        'w' => Some("ट"),
+        'W' => Some("ठ"),
+        'q' => Some("ड"),
+        'Q' => Some("ढ"),
+        'R' => Some("ण"),
+        't' => Some("त"),
+        'T' => Some("थ"),
+        'd' => Some("द"),
+        'D' => Some("ध"),
+        'n' => Some("न"),
+        'p' => Some("प"),
+        'P' => Some("फ"),
+        'b' => Some("ब"),
+        'B' => Some("भ"),
+        'm' => Some("म"),
+        'y' => Some("य"),
+        'r' => Some("र"),
+        'l' => Some("ल"),
+        'v' => Some("व"),
+        'S' => Some("श"),
+        'z' => Some("ष"),
+        's' => Some("स"),
+        'h' => Some("ह"),
+        'L' => Some("ळ"),
+        'ɠ' => Some("ॻ"),
+        'ʄ' => Some("ॼ"),
+        'ɗ' => Some("ॾ"),
+        'ɓ' => Some("ॿ"),
         // Others
         'M' => Some("ं"),
         'H' => Some("ः"),
@@ -300,6 +439,11 @@ pub fn is_slp1_consonant(c: char) -> bool {
             | 'z'
             | 's'
             | 'h'
+            | 'L'
+            | 'ɠ'
+            | 'ʄ'
+            | 'ɗ'
+            | 'ɓ'
     )
 }
 
@@ -321,6 +465,7 @@ pub static IAST_TO_SLP1: &[(&str, &str)] = &[
     ("ṝ", "F"),
     ("ḷ", "x"),
     ("ḹ", "X"),
+    ("ḻ", "L"),
     ("ṅ", "N"),
     ("ñ", "Y"),
     ("ṭ", "w"),
@@ -361,6 +506,84 @@ pub fn get_iast_to_slp1_map() -> &'static [(&'static str, &'static str)] {
     IAST_TO_SLP1
 }
 
+/// SLP1 -> IAST, the inverse of [`get_iast_to_slp1`], built on first use
+pub fn get_slp1_to_iast_map() -> &'static [(&'static str, &'static str)] {
+    static MAP: OnceLock<Vec<(&'static str, &'static str)>> = OnceLock::new();
+    MAP.get_or_init(|| invert_map(&get_iast_to_slp1()))
+}
+
+/// ISO 15919 -> SLP1
+///
+/// ISO 15919 follows IAST except that it marks long ē and ō, writes the
+/// vocalic liquids with a ring below (r̥, l̥), and uses ḷ for the retroflex
+/// lateral. Sanskrit has no short e or o, so plain e and o are also read as
+/// SLP1 `e` and `o`, which are written back as ē and ō. The IAST ṛ, ṝ and ḹ
+/// are accepted; IAST ḷ is not, as it means ḷa here.
+pub fn get_iso_to_slp1() -> Vec<(&'static str, &'static str)> {
+    let mut map = vec![
+        ("ē", "e"),
+        ("ō", "o"),
+        ("r\u{0325}\u{0304}", "F"),
+        ("r\u{0325}", "f"),
+        ("l\u{0325}\u{0304}", "X"),
+        ("l\u{0325}", "x"),
+        ("ḷ", "L"),
+    ];
+    map.extend(
+        get_iast_to_slp1()
+            .into_iter()
+            .filter(|(key, _)| *key != "ḷ"),
+    );
+    // Stable, so the ISO spellings above stay first for the inverse map
+    map.sort_by_key(|(key, _)| Reverse(key.len()));
+    map
+}
+
+/// [`get_iso_to_slp1`], built on first use
+pub fn get_iso_to_slp1_map() -> &'static [(&'static str, &'static str)] {
+    static MAP: OnceLock<Vec<(&'static str, &'static str)>> = OnceLock::new();
+    MAP.get_or_init(get_iso_to_slp1)
+}
+
+/// SLP1 -> ISO 15919, the inverse of [`get_iso_to_slp1`], built on first use
+pub fn get_slp1_to_iso_map() -> &'static [(&'static str, &'static str)] {
+    static MAP: OnceLock<Vec<(&'static str, &'static str)>> = OnceLock::new();
+    MAP.get_or_init(|| invert_map(&get_iso_to_slp1()))
+}
+
+/// [`get_slp1_to_hk`], built on first use
+pub fn get_slp1_to_hk_map() -> &'static [(&'static str, &'static str)] {
+    static MAP: OnceLock<Vec<(&'static str, &'static str)>> = OnceLock::new();
+    MAP.get_or_init(get_slp1_to_hk)
+}
+
+/// Swap keys and values, sorted by the new key length descending
+pub(crate) fn invert_map(
+    map: &[(&'static str, &'static str)],
+) -> Vec<(&'static str, &'static str)> {
+    let mut inv: Vec<(&'static str, &'static str)> = map.iter().map(|(k, v)| (*v, *k)).collect();
+    inv.sort_by_key(|(key, _)| Reverse(key.len()));
+    inv
+}
+
+/// Base consonant of a precomposed nukta letter, e.g. U+0958 (qa) → क
+///
+/// These letters are composition exclusions, so normalized text spells them
+/// as base + U+093C; both spellings are read the same way.
+pub fn get_devanagari_nukta_base(c: char) -> Option<char> {
+    match c {
+        '\u{0958}' => Some('क'),
+        '\u{0959}' => Some('ख'),
+        '\u{095A}' => Some('ग'),
+        '\u{095B}' => Some('ज'),
+        '\u{095C}' => Some('ड'),
+        '\u{095D}' => Some('ढ'),
+        '\u{095E}' => Some('फ'),
+        '\u{095F}' => Some('य'),
+        _ => None,
+    }
+}
+
 pub fn get_devanagari_consonant_to_slp1(c: char) -> Option<char> {
     match c {
         'क' => Some('k'),
@@ -396,6 +619,11 @@ pub fn get_devanagari_consonant_to_slp1(c: char) -> Option<char> {
         'ष' => Some('z'),
         'स' => Some('s'),
         'ह' => Some('h'),
+        'ळ' => Some('L'),
+        'ॻ' => Some('ɠ'),
+        'ॼ' => Some('ʄ'),
+        'ॾ' => Some('ɗ'),
+        'ॿ' => Some('ɓ'),
         _ => None,
     }
 }
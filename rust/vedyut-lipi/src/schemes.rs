@@ -53,6 +53,18 @@ pub enum Scheme {
     Grantha,
 }
 
+/// What a scheme can express, for advising on conversions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SchemeFeatures {
+    /// Can carry the Vedic svara marks (udātta, anudātta, svarita)
+    pub supports_vedic_accents: bool,
+    /// Has digits of its own (० १ २ ...) rather than borrowing 0-9
+    pub has_native_numerals: bool,
+    /// Writes consonant clusters as conjuncts or stacks instead of spelling
+    /// each consonant out with a visible virama
+    pub forms_conjuncts: bool,
+}
+
 impl std::str::FromStr for Scheme {
     type Err = ();
 
@@ -156,6 +168,113 @@ impl Scheme {
         }
     }
 
+    /// Stable numeric discriminant, in declaration order
+    ///
+    /// New variants must take the next free number; existing numbers never change.
+    pub fn index(&self) -> u8 {
+        match self {
+            Self::Iast => 0,
+            Self::Slp1 => 1,
+            Self::HarvardKyoto => 2,
+            Self::Itrans => 3,
+            Self::Iso15919 => 4,
+            Self::Velthuis => 5,
+            Self::Wx => 6,
+            Self::Devanagari => 7,
+            Self::Telugu => 8,
+            Self::Tamil => 9,
+            Self::Kannada => 10,
+            Self::Malayalam => 11,
+            Self::Bengali => 12,
+            Self::Gujarati => 13,
+            Self::Gurmukhi => 14,
+            Self::Odia => 15,
+            Self::Assamese => 16,
+            Self::Tibetan => 17,
+            Self::Sinhala => 18,
+            Self::Burmese => 19,
+            Self::Thai => 20,
+            Self::Grantha => 21,
+        }
+    }
+
+    /// Get the canonical lowercase key accepted by [`Scheme::from_str`]
+    pub fn canonical_name(&self) -> &'static str {
+        match self {
+            Self::Iast => "iast",
+            Self::Slp1 => "slp1",
+            Self::HarvardKyoto => "hk",
+            Self::Itrans => "itrans",
+            Self::Iso15919 => "iso",
+            Self::Velthuis => "velthuis",
+            Self::Wx => "wx",
+            Self::Devanagari => "devanagari",
+            Self::Telugu => "telugu",
+            Self::Tamil => "tamil",
+            Self::Kannada => "kannada",
+            Self::Malayalam => "malayalam",
+            Self::Bengali => "bengali",
+            Self::Gujarati => "gujarati",
+            Self::Gurmukhi => "gurmukhi",
+            Self::Odia => "odia",
+            Self::Assamese => "assamese",
+            Self::Tibetan => "tibetan",
+            Self::Sinhala => "sinhala",
+            Self::Burmese => "burmese",
+            Self::Thai => "thai",
+            Self::Grantha => "grantha",
+        }
+    }
+
+    /// Check whether SLP1 text converted into this scheme converts back unchanged
+    ///
+    /// Only schemes with complete mapping tables qualify; schemes that are not
+    /// implemented yet pass text through and are not lossless.
+    pub fn is_lossless(&self) -> bool {
+        matches!(
+            self,
+            Self::Slp1
+                | Self::Iast
+                | Self::Iso15919
+                | Self::HarvardKyoto
+                | Self::Itrans
+                | Self::Devanagari
+                | Self::Malayalam
+        )
+    }
+
+    /// Features of the script or romanization
+    ///
+    /// Vedic accents follow the scripts that Unicode lists for the svara signs
+    /// U+0951/U+0952, and the romanizations with an accent notation.
+    pub fn features(&self) -> SchemeFeatures {
+        let (supports_vedic_accents, has_native_numerals, forms_conjuncts) = match self {
+            Self::Iast | Self::Slp1 | Self::Itrans | Self::Iso15919 => (true, false, false),
+            Self::HarvardKyoto | Self::Velthuis | Self::Wx => (false, false, false),
+            Self::Devanagari
+            | Self::Telugu
+            | Self::Kannada
+            | Self::Malayalam
+            | Self::Bengali
+            | Self::Gujarati
+            | Self::Odia
+            | Self::Assamese => (true, true, true),
+            // Clusters are written with a visible pulli / halant
+            Self::Tamil | Self::Gurmukhi => (true, true, false),
+            // Grantha borrows the Tamil digits
+            Self::Grantha => (true, false, true),
+            Self::Tibetan | Self::Sinhala | Self::Burmese => (false, true, true),
+            // Thai writes the phinthu under each vowelless consonant
+            Self::Thai => (false, true, false),
+        };
+
+        SchemeFeatures {
+            supports_vedic_accents,
+            has_native_numerals,
+            forms_conjuncts,
+        }
+    }
+
     /// Check if this is a Brahmic script (as opposed to romanization)
     pub fn is_brahmic(&self) -> bool {
         !matches!(
@@ -176,3 +295,96 @@ impl std::fmt::Display for Scheme {
         write!(f, "{}", self.name())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_canonical_name_round_trip() {
+        for scheme in Scheme::all() {
+            assert_eq!(
+                Scheme::from_str(scheme.canonical_name()),
+                Some(scheme),
+                "{:?}",
+                scheme
+            );
+        }
+    }
+
+    #[test]
+    fn test_canonical_name_is_lowercase() {
+        for scheme in Scheme::all() {
+            let name = scheme.canonical_name();
+            assert_eq!(name, name.to_lowercase());
+        }
+    }
+
+    #[test]
+    fn test_all_covers_every_variant() {
+        // Exhaustive on purpose: adding a variant breaks the build here until
+        // it is counted below and listed in `Scheme::all()`
+        let counted = |scheme: Scheme| match scheme {
+            Scheme::Iast
+            | Scheme::Slp1
+            | Scheme::HarvardKyoto
+            | Scheme::Itrans
+            | Scheme::Iso15919
+            | Scheme::Velthuis
+            | Scheme::Wx
+            | Scheme::Devanagari
+            | Scheme::Telugu
+            | Scheme::Tamil
+            | Scheme::Kannada
+            | Scheme::Malayalam
+            | Scheme::Bengali
+            | Scheme::Gujarati
+            | Scheme::Gurmukhi
+            | Scheme::Odia
+            | Scheme::Assamese
+            | Scheme::Tibetan
+            | Scheme::Sinhala
+            | Scheme::Burmese
+            | Scheme::Thai
+            | Scheme::Grantha => true,
+        };
+        let all = Scheme::all();
+
+        assert!(all.iter().all(|&scheme| counted(scheme)));
+        assert_eq!(all.len(), 22);
+        let mut indices: Vec<u8> = all.iter().map(Scheme::index).collect();
+        indices.sort();
+        assert_eq!(indices, (0..all.len() as u8).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_features() {
+        let devanagari = Scheme::Devanagari.features();
+        assert!(devanagari.forms_conjuncts);
+        assert!(devanagari.has_native_numerals);
+        assert!(devanagari.supports_vedic_accents);
+
+        let iast = Scheme::Iast.features();
+        assert!(!iast.has_native_numerals);
+        assert!(!iast.forms_conjuncts);
+
+        assert!(!Scheme::Tamil.features().forms_conjuncts);
+        for scheme in Scheme::all() {
+            if !scheme.is_brahmic() {
+                assert!(!scheme.features().has_native_numerals, "{:?}", scheme);
+                assert!(!scheme.features().forms_conjuncts, "{:?}", scheme);
+            }
+        }
+    }
+
+    #[test]
+    fn test_names_unique() {
+        let all = Scheme::all();
+        let names: std::collections::HashSet<_> = all.iter().map(Scheme::name).collect();
+        let canonical: std::collections::HashSet<_> =
+            all.iter().map(Scheme::canonical_name).collect();
+
+        assert_eq!(names.len(), all.len());
+        assert_eq!(canonical.len(), all.len());
+    }
+}
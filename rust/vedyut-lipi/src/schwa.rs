@@ -0,0 +1,185 @@
+//! Hindi-style schwa deletion for Devanagari text
+//!
+//! Sanskrit pronounces every inherent `a`, but modern Hindi drops the word-final
+//! one and some medial ones (राम is "rām", कमला is "kamlā"). Deletion is done on
+//! the Devanagari itself by adding a virama, so the regular converters can run
+//! afterwards unchanged.
+
+use crate::mappings;
+
+const VIRAMA: char = '्';
+const NUKTA: char = '़';
+
+/// Vowel carried by an akṣara
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Vowel {
+    /// Consonant with its inherent `a`
+    Inherent,
+    /// Consonant with a vowel sign, or an independent vowel
+    Explicit,
+    /// Consonant with a virama
+    None,
+}
+
+#[derive(Debug)]
+struct Akshara {
+    is_consonant: bool,
+    vowel: Vowel,
+    /// Followed by anusvāra, visarga or candrabindu
+    has_coda: bool,
+    /// Char index after which a virama is inserted on deletion
+    end: usize,
+}
+
+/// Delete inherent schwas Hindi-style by inserting viramas
+pub(crate) fn delete_schwas(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut result = String::with_capacity(text.len());
+    let mut start = 0;
+
+    while start < chars.len() {
+        let len = chars[start..]
+            .iter()
+            .take_while(|&&c| is_word_char(c) == is_word_char(chars[start]))
+            .count();
+        let run = &chars[start..start + len];
+
+        if is_word_char(chars[start]) {
+            result.push_str(&delete_in_word(run));
+        } else {
+            result.extend(run);
+        }
+        start += len;
+    }
+
+    result
+}
+
+fn delete_in_word(chars: &[char]) -> String {
+    let mut aksharas = parse_aksharas(chars);
+    let syllables = aksharas.iter().filter(|a| a.vowel != Vowel::None).count();
+    let n = aksharas.len();
+
+    let deletable = |a: &Akshara| a.is_consonant && a.vowel == Vowel::Inherent && !a.has_coda;
+
+    // Word-final schwa, unless the word is monosyllabic or ends in a conjunct
+    if n > 1 && syllables > 1 && deletable(&aksharas[n - 1]) && aksharas[n - 2].vowel != Vowel::None
+    {
+        aksharas[n - 1].vowel = Vowel::None;
+    }
+
+    // Medial schwas in a VC_CV context, right to left
+    for i in (1..n.saturating_sub(1)).rev() {
+        let (prev, next) = (&aksharas[i - 1], &aksharas[i + 1]);
+        if deletable(&aksharas[i])
+            && prev.vowel != Vowel::None
+            && next.is_consonant
+            && next.vowel != Vowel::None
+        {
+            aksharas[i].vowel = Vowel::None;
+        }
+    }
+
+    let mut deleted_ends = aksharas
+        .iter()
+        .filter(|a| a.is_consonant && a.vowel == Vowel::None)
+        .map(|a| a.end)
+        .peekable();
+
+    let mut result = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        result.push(c);
+        if deleted_ends.peek() == Some(&i) {
+            deleted_ends.next();
+            if chars.get(i + 1) != Some(&VIRAMA) {
+                result.push(VIRAMA);
+            }
+        }
+    }
+    result
+}
+
+fn parse_aksharas(chars: &[char]) -> Vec<Akshara> {
+    let mut consonants = mappings::get_devanagari_vyanjanas();
+    consonants.extend(mappings::get_devanagari_extended_vyanjanas());
+    let matras = mappings::get_devanagari_matras();
+    let vowels = mappings::get_devanagari_swaras();
+
+    let mut aksharas: Vec<Akshara> = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i].to_string();
+
+        if consonants.contains(&c.as_str()) {
+            let mut end = i;
+            if chars.get(end + 1) == Some(&NUKTA) {
+                end += 1;
+            }
+            let vowel = match chars.get(end + 1) {
+                Some(&VIRAMA) => Vowel::None,
+                Some(m) if matras[1..].contains(&m.to_string().as_str()) => Vowel::Explicit,
+                _ => Vowel::Inherent,
+            };
+            aksharas.push(Akshara {
+                is_consonant: true,
+                vowel,
+                has_coda: false,
+                end,
+            });
+            i = if vowel == Vowel::Inherent {
+                end + 1
+            } else {
+                end + 2
+            };
+        } else if vowels.contains(&c.as_str()) {
+            aksharas.push(Akshara {
+                is_consonant: false,
+                vowel: Vowel::Explicit,
+                has_coda: false,
+                end: i,
+            });
+            i += 1;
+        } else {
+            if matches!(chars[i], 'ं' | 'ः' | 'ँ') {
+                if let Some(last) = aksharas.last_mut() {
+                    last.has_coda = true;
+                }
+            }
+            i += 1;
+        }
+    }
+
+    aksharas
+}
+
+/// Devanagari letters and signs, excluding dandas and digits
+fn is_word_char(c: char) -> bool {
+    ('\u{0900}'..='\u{0963}').contains(&c) || ('\u{0970}'..='\u{097F}').contains(&c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_final_schwa() {
+        assert_eq!(delete_schwas("राम"), "राम्");
+        assert_eq!(delete_schwas("भारत"), "भारत्");
+        // Monosyllables and final conjuncts keep the schwa
+        assert_eq!(delete_schwas("न"), "न");
+        assert_eq!(delete_schwas("मित्र"), "मित्र");
+    }
+
+    #[test]
+    fn test_medial_schwa() {
+        assert_eq!(delete_schwas("कमला"), "कम्ला");
+        assert_eq!(delete_schwas("समझ"), "समझ्");
+        assert_eq!(delete_schwas("बदलना"), "बदल्ना");
+    }
+
+    #[test]
+    fn test_sentence() {
+        assert_eq!(delete_schwas("राम और सीता।"), "राम् और् सीता।");
+    }
+}
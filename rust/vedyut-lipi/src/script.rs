@@ -0,0 +1,178 @@
+//! Script detection for mixed-script text
+//!
+//! Splits text such as "namaste नमस्ते world" into runs of one script, so the
+//! Brahmic runs can be transliterated while Latin text is left alone.
+
+use crate::{transliterate, Scheme};
+
+/// Script of a run of text
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScriptRun {
+    /// A Brahmic script block (Devanagari, Telugu, Grantha, ...)
+    Brahmic,
+    /// Latin letters, including IAST/ISO diacritics
+    Latin,
+    /// Any other script, or text with no letters at all
+    Other,
+}
+
+/// Split text into maximal runs of one script
+///
+/// Whitespace, punctuation and digits belong to no script and join the run
+/// before them (or the first run, at the start of the text), so
+/// "namaste नमस्ते world" gives three runs: "namaste ", "नमस्ते " and "world".
+pub fn split_by_script(text: &str) -> Vec<(ScriptRun, &str)> {
+    let mut runs: Vec<(ScriptRun, &str)> = Vec::new();
+    let mut start = 0;
+    let mut current: Option<ScriptRun> = None;
+
+    for (i, c) in text.char_indices() {
+        let Some(script) = script_of(c) else {
+            continue;
+        };
+        match current {
+            Some(run) if run != script => {
+                runs.push((run, &text[start..i]));
+                start = i;
+                current = Some(script);
+            }
+            Some(_) => {}
+            None => current = Some(script),
+        }
+    }
+
+    if start < text.len() {
+        runs.push((current.unwrap_or(ScriptRun::Other), &text[start..]));
+    }
+    runs
+}
+
+/// Check whether text has any letter in one of the given scripts
+pub fn contains_any_script(text: &str, scripts: &[ScriptRun]) -> bool {
+    text.chars()
+        .filter_map(script_of)
+        .any(|script| scripts.contains(&script))
+}
+
+/// Transliterate the Brahmic runs of mixed-script text, leaving the rest as is
+///
+/// The source scheme of each run is taken from the Unicode block of its first
+/// letter.
+pub fn transliterate_brahmic_runs(text: &str, to: Scheme) -> String {
+    split_by_script(text)
+        .into_iter()
+        .map(|(script, run)| {
+            let from = run.chars().find_map(brahmic_scheme);
+            match (script, from) {
+                (ScriptRun::Brahmic, Some(from)) => transliterate(run, from, to),
+                _ => run.to_string(),
+            }
+        })
+        .collect()
+}
+
+/// Script of a letter or sign, or `None` for script-neutral characters
+fn script_of(c: char) -> Option<ScriptRun> {
+    if brahmic_scheme(c).is_some() || matches!(c, '\u{1CD0}'..='\u{1CFF}' | '\u{A8E0}'..='\u{A8FF}')
+    {
+        Some(ScriptRun::Brahmic)
+    } else if is_latin(c) {
+        Some(ScriptRun::Latin)
+    } else if c.is_alphabetic() || is_combining_mark(c) {
+        Some(ScriptRun::Other)
+    } else {
+        None
+    }
+}
+
+/// Scheme of the Brahmic Unicode block a char belongs to
+///
+/// The danda and double danda (U+0964, U+0965) are shared by all Indic
+/// scripts; they are read as Devanagari.
+pub(crate) fn brahmic_scheme(c: char) -> Option<Scheme> {
+    let scheme = match c {
+        '\u{0900}'..='\u{097F}' => Scheme::Devanagari,
+        '\u{0980}'..='\u{09FF}' => Scheme::Bengali,
+        '\u{0A00}'..='\u{0A7F}' => Scheme::Gurmukhi,
+        '\u{0A80}'..='\u{0AFF}' => Scheme::Gujarati,
+        '\u{0B00}'..='\u{0B7F}' => Scheme::Odia,
+        '\u{0B80}'..='\u{0BFF}' => Scheme::Tamil,
+        '\u{0C00}'..='\u{0C7F}' => Scheme::Telugu,
+        '\u{0C80}'..='\u{0CFF}' => Scheme::Kannada,
+        '\u{0D00}'..='\u{0D7F}' => Scheme::Malayalam,
+        '\u{0D80}'..='\u{0DFF}' => Scheme::Sinhala,
+        '\u{0E00}'..='\u{0E7F}' => Scheme::Thai,
+        '\u{0F00}'..='\u{0FFF}' => Scheme::Tibetan,
+        '\u{1000}'..='\u{109F}' => Scheme::Burmese,
+        '\u{11300}'..='\u{1137F}' => Scheme::Grantha,
+        _ => return None,
+    };
+    Some(scheme)
+}
+
+/// Latin letters and combining diacritics (× and ÷ sit inside Latin-1 but
+/// are not letters)
+fn is_latin(c: char) -> bool {
+    match c {
+        '\u{00D7}' | '\u{00F7}' => false,
+        '\u{00C0}'..='\u{024F}' | '\u{1E00}'..='\u{1EFF}' | '\u{0300}'..='\u{036F}' => true,
+        _ => c.is_ascii_alphabetic(),
+    }
+}
+
+/// Combining marks outside the Latin and Brahmic blocks
+fn is_combining_mark(c: char) -> bool {
+    matches!(c, '\u{1AB0}'..='\u{1AFF}' | '\u{1DC0}'..='\u{1DFF}' | '\u{20D0}'..='\u{20FF}')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_by_script() {
+        assert_eq!(
+            split_by_script("namaste नमस्ते world"),
+            vec![
+                (ScriptRun::Latin, "namaste "),
+                (ScriptRun::Brahmic, "नमस्ते "),
+                (ScriptRun::Latin, "world"),
+            ]
+        );
+        assert_eq!(
+            split_by_script("“śivaḥ” — शिवः।"),
+            vec![
+                (ScriptRun::Latin, "“śivaḥ” — "),
+                (ScriptRun::Brahmic, "शिवः।")
+            ]
+        );
+        assert_eq!(split_by_script("123 !"), vec![(ScriptRun::Other, "123 !")]);
+        assert_eq!(
+            split_by_script("Привет"),
+            vec![(ScriptRun::Other, "Привет")]
+        );
+        assert!(split_by_script("").is_empty());
+    }
+
+    #[test]
+    fn test_contains_any_script() {
+        assert!(contains_any_script("hi नमस्ते", &[ScriptRun::Brahmic]));
+        assert!(!contains_any_script("hello, world", &[ScriptRun::Brahmic]));
+        assert!(contains_any_script(
+            "hello",
+            &[ScriptRun::Brahmic, ScriptRun::Latin]
+        ));
+    }
+
+    #[test]
+    fn test_transliterate_brahmic_runs() {
+        assert_eq!(
+            transliterate_brahmic_runs("namaste नमस्ते world", Scheme::Iast),
+            "namaste namaste world"
+        );
+        assert_eq!(
+            transliterate_brahmic_runs("Hello रामः!", Scheme::Slp1),
+            "Hello rAmaH!"
+        );
+    }
+}
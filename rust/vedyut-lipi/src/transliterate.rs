@@ -1,191 +1,747 @@
+use crate::error::TransliterateError;
+use crate::indic;
 use crate::mappings;
 use crate::schemes::Scheme;
+use crate::schwa;
+use crate::script::brahmic_scheme;
+use rustc_hash::FxHashMap;
+use std::borrow::Cow;
+use std::sync::OnceLock;
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::{is_nfc, UnicodeNormalization};
+
+/// Options that adjust transliteration output
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TransliterateOptions {
+    /// Delete inherent schwas Hindi-style when romanizing Devanagari
+    /// ("राम" → "rām" instead of "rāma")
+    pub schwa_deletion: bool,
+    /// How the praṇava ॐ is written
+    pub om_style: OmStyle,
+    /// How an anusvāra before a stop is written
+    pub anusvara: AnusvaraRender,
+    /// Glyph for the anusvāra in IAST and ISO 15919 output
+    pub anusvara_iast: AnusvaraGlyph,
+    /// Carry word capitalization over from IAST input to ISO 15919 output
+    /// ("Śiva" → "Śiva"); other targets have no case to restore. Case in
+    /// SLP1, Harvard-Kyoto and ITRANS marks distinct sounds, so it is lost
+    /// in a round trip through them: IAST "Śiva" → SLP1 "Siva" → IAST "śiva".
+    pub preserve_case: bool,
+    /// Write a word-final ṇ, n, r, l or ḷ as a Malayalam chillu (ൺ ൻ ർ ൽ ൾ)
+    /// instead of the consonant with virama
+    pub chillu: bool,
+    /// Write characters without an SLP1 spelling as `\u{XXXX}` escapes in
+    /// SLP1 output, and decode such escapes in SLP1 input, so SLP1 stays
+    /// pure ASCII and converts back losslessly
+    pub escape_unmapped: bool,
+    /// Read a doubled short vowel in IAST input ("aa", "ii", "uu", "ṛṛ") as
+    /// the long vowel instead of two vowels in hiatus
+    pub merge_vowels: bool,
+}
+
+/// Convention for writing the praṇava (ॐ, U+0950)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OmStyle {
+    /// Romanize as `oṃ` (SLP1 `oM`)
+    #[default]
+    Om,
+    /// Romanize as `auṃ` (SLP1 `auM`)
+    Aum,
+    /// Keep the ॐ symbol, and write a standalone `oṃ` as ॐ in Brahmic output
+    Pranava,
+}
+
+/// Rendering of the anusvāra (SLP1 `M`)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AnusvaraRender {
+    /// Always the anusvāra dot, e.g. संकल्प
+    #[default]
+    Dot,
+    /// The nasal of the following stop's varga, e.g. सङ्कल्प
+    /// (8.4.58 anusvārasya yayi parasavarṇaḥ)
+    Homorganic,
+}
+
+/// Romanized anusvāra glyph
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AnusvaraGlyph {
+    /// `ṃ` (m with dot below)
+    #[default]
+    DotBelow,
+    /// `ṁ` (m with dot above)
+    DotAbove,
+    /// A nasal letter: the nasal of the varga before a stop (saṅkalpa), n
+    /// before a sibilant or h (sanskṛta), and m elsewhere (samyoga, rāmam)
+    NasalLetter,
+}
+
+const OM: char = 'ॐ';
+/// Combining nukta (U+093C); SLP1 has no nukta letters, so it travels through
+/// SLP1 as the sign itself, right after its consonant
+const NUKTA: char = '\u{093C}';
 
 /// Transliterate text from one scheme to another
 pub fn transliterate(text: &str, from: Scheme, to: Scheme) -> String {
+    transliterate_with_options(text, from, to, &TransliterateOptions::default())
+}
+
+/// Transliterate text, failing instead of passing it through unchanged
+///
+/// # Errors
+/// [`TransliterateError::SchemeNotImplemented`] if the pair is not
+/// [supported](is_supported), [`TransliterateError::ScriptMismatch`] if a
+/// Brahmic source text has letters of another script, and
+/// [`TransliterateError::InvalidInput`] if SLP1 input fails [`validate_slp1`].
+pub fn try_transliterate(
+    text: &str,
+    from: Scheme,
+    to: Scheme,
+) -> Result<String, TransliterateError> {
+    if !is_supported(from, to) {
+        return Err(TransliterateError::SchemeNotImplemented { from, to });
+    }
+
+    if from.is_brahmic() {
+        // The dandas are shared by all Indic scripts
+        let found = text
+            .chars()
+            .filter(|c| !matches!(c, '\u{0964}' | '\u{0965}'))
+            .filter_map(brahmic_scheme)
+            .find(|&scheme| scheme != from);
+        if let Some(found) = found {
+            return Err(TransliterateError::ScriptMismatch {
+                expected: from,
+                found,
+            });
+        }
+    } else if from == Scheme::Slp1 {
+        validate_slp1(text)?;
+    }
+
+    Ok(transliterate(text, from, to))
+}
+
+/// Check that text uses only SLP1 letters besides spaces, digits and punctuation
+///
+/// The symbols SLP1 carries unchanged (ॐ, the nukta, and the placeholders of
+/// [`get_slp1_extended_vyanjanas`](mappings::get_slp1_extended_vyanjanas)) are
+/// accepted too.
+///
+/// # Errors
+/// [`TransliterateError::InvalidInput`] at the first other letter or mark
+pub fn validate_slp1(text: &str) -> Result<(), TransliterateError> {
+    let extended = mappings::get_slp1_extended_vyanjanas();
+
+    for (offset, c) in text.char_indices() {
+        let valid = c.is_ascii()
+            || !(c.is_alphabetic() || is_combining_mark(c))
+            || c == OM
+            || c == NUKTA
+            || extended.iter().any(|e| e.starts_with(c));
+        if !valid {
+            return Err(TransliterateError::InvalidInput {
+                scheme: Scheme::Slp1,
+                offset,
+                character: c,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Transliterate text from one scheme to another with output options
+pub fn transliterate_with_options(
+    text: &str,
+    from: Scheme,
+    to: Scheme,
+    options: &TransliterateOptions,
+) -> String {
     if from == to {
         return text.to_string();
     }
 
-    let slp1 = to_slp1(text, from);
-    from_slp1(&slp1, to)
+    // Direct converters for the most common pair; output is identical to
+    // the route through SLP1
+    if *options == TransliterateOptions::default() {
+        match (from, to) {
+            (Scheme::Iast, Scheme::Devanagari) => return iast_to_devanagari(text),
+            (Scheme::Devanagari, Scheme::Iast) => return devanagari_to_iast(text),
+            _ => {}
+        }
+    }
+
+    via_slp1(text, from, to, options)
+}
+
+/// Transliterate by converting to SLP1 and from SLP1 to the target scheme
+fn via_slp1(text: &str, from: Scheme, to: Scheme, options: &TransliterateOptions) -> String {
+    let input = if options.schwa_deletion && from == Scheme::Devanagari && !to.is_brahmic() {
+        schwa::delete_schwas(text)
+    } else if options.escape_unmapped && from == Scheme::Slp1 {
+        unescape_unmapped(text)
+    } else {
+        text.to_string()
+    };
+
+    // Unimplemented schemes pass text through unchanged
+    let slp1 = to_slp1(&input, from).unwrap_or(input);
+    let slp1 = if options.merge_vowels && from == Scheme::Iast {
+        merge_doubled_vowels(&slp1)
+    } else {
+        slp1
+    };
+    let slp1 = apply_om_style(&slp1, to, options.om_style);
+    let slp1 = match options.anusvara {
+        AnusvaraRender::Dot => slp1,
+        AnusvaraRender::Homorganic => homorganic_anusvara(&slp1),
+    };
+    let slp1 = match (options.anusvara_iast, to) {
+        (AnusvaraGlyph::NasalLetter, Scheme::Iast | Scheme::Iso15919) => {
+            nasal_letter_anusvara(&slp1)
+        }
+        _ => slp1,
+    };
+    let output = from_slp1(&slp1, to).unwrap_or(slp1);
+
+    let output = match (options.anusvara_iast, to) {
+        (AnusvaraGlyph::DotAbove, Scheme::Iast | Scheme::Iso15919) => output.replace('ṃ', "ṁ"),
+        _ => output,
+    };
+    let output = match (options.chillu, to) {
+        (true, Scheme::Malayalam) => indic::malayalam_chillus(&output),
+        _ => output,
+    };
+
+    if options.preserve_case && is_caseless_roman(from) && is_caseless_roman(to) {
+        restore_case(text, &output)
+    } else if options.escape_unmapped && to == Scheme::Slp1 {
+        escape_unmapped(&output)
+    } else {
+        output
+    }
+}
+
+/// Collapse each pair of identical short vowels in SLP1 into the long vowel
+/// ("aa" → "A"); a run of three keeps its last vowel short
+fn merge_doubled_vowels(slp1: &str) -> String {
+    let mut result = String::with_capacity(slp1.len());
+    let mut chars = slp1.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        let long = match c {
+            'a' => 'A',
+            'i' => 'I',
+            'u' => 'U',
+            'f' => 'F',
+            _ => {
+                result.push(c);
+                continue;
+            }
+        };
+        if chars.next_if_eq(&c).is_some() {
+            result.push(long);
+        } else {
+            result.push(c);
+        }
+    }
+
+    result
+}
+
+/// Escape every non-ASCII char of SLP1 text, and the backslash itself, as
+/// `\u{XXXX}` (e.g. 😀 → `\u{1F600}`)
+fn escape_unmapped(slp1: &str) -> String {
+    let mut result = String::with_capacity(slp1.len());
+    for c in slp1.chars() {
+        if c.is_ascii() && c != '\\' {
+            result.push(c);
+        } else {
+            result.push_str(&format!("\\u{{{:X}}}", c as u32));
+        }
+    }
+    result
 }
 
-/// Convert text to SLP1
-fn to_slp1(text: &str, from: Scheme) -> String {
-    match from {
+/// Decode the escapes written by [`escape_unmapped`]; anything that is not a
+/// valid escape is kept as it is
+fn unescape_unmapped(slp1: &str) -> String {
+    let mut result = String::with_capacity(slp1.len());
+    let mut rest = slp1;
+
+    while let Some(start) = rest.find("\\u{") {
+        result.push_str(&rest[..start]);
+        let after = &rest[start + 3..];
+        let decoded = after.find('}').and_then(|end| {
+            let c = u32::from_str_radix(&after[..end], 16)
+                .ok()
+                .and_then(char::from_u32)?;
+            Some((c, end))
+        });
+        match decoded {
+            Some((c, end)) => {
+                result.push(c);
+                rest = &after[end + 1..];
+            }
+            None => {
+                result.push_str(&rest[start..start + 3]);
+                rest = after;
+            }
+        }
+    }
+
+    result.push_str(rest);
+    result
+}
+
+/// Romanizations whose letters carry no meaning in their case
+///
+/// Their input is matched case-insensitively. SLP1, Harvard-Kyoto and the
+/// like use capitals for distinct sounds and are left alone.
+fn is_caseless_roman(scheme: Scheme) -> bool {
+    matches!(scheme, Scheme::Iast | Scheme::Iso15919)
+}
+
+/// Copy the capitalization of each word in `original` onto the same word of
+/// `output`: an all-caps word stays all caps, otherwise only an initial
+/// capital is kept
+fn restore_case(original: &str, output: &str) -> String {
+    original
+        .split_inclusive(char::is_whitespace)
+        .zip(output.split_inclusive(char::is_whitespace))
+        .map(|(orig, out)| {
+            let mut cased = orig
+                .chars()
+                .filter(|c| c.is_lowercase() || c.is_uppercase());
+            let initial_upper = cased.next().is_some_and(char::is_uppercase);
+            let all_upper = initial_upper
+                && orig.chars().filter(|c| c.is_alphabetic()).count() > 1
+                && cased.all(char::is_uppercase);
+
+            if all_upper {
+                out.to_uppercase()
+            } else if initial_upper {
+                let mut chars = out.chars();
+                chars
+                    .next()
+                    .map(|first| first.to_uppercase().chain(chars).collect())
+                    .unwrap_or_default()
+            } else {
+                out.to_string()
+            }
+        })
+        .collect()
+}
+
+/// Transliterate the text nodes of HTML/XML markup
+///
+/// Tags (`<...>`) and character entities (`&amp;`, `&#2325;`) are copied
+/// unchanged; only the text between them is converted.
+pub fn transliterate_markup(text: &str, from: Scheme, to: Scheme) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut run_start = 0;
+    let mut i = 0;
+
+    while i < text.len() {
+        let rest = &text[i..];
+        let markup_len = if rest.starts_with('<') {
+            rest.find('>').map(|end| end + 1)
+        } else if rest.starts_with('&') {
+            entity_len(rest)
+        } else {
+            None
+        };
+
+        match markup_len {
+            Some(len) => {
+                result.push_str(&transliterate(&text[run_start..i], from, to));
+                result.push_str(&rest[..len]);
+                i += len;
+                run_start = i;
+            }
+            None => i += rest.chars().next().map_or(1, char::len_utf8),
+        }
+    }
+
+    result.push_str(&transliterate(&text[run_start..], from, to));
+    result
+}
+
+/// Length of a character entity at the start of `text`, if there is one
+fn entity_len(text: &str) -> Option<usize> {
+    let end = text.find(';')?;
+    let name = &text[1..end];
+    let valid = !name.is_empty()
+        && name
+            .strip_prefix('#')
+            .unwrap_or(name)
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric());
+    valid.then_some(end + 1)
+}
+
+/// Rewrite the praṇava in SLP1 text for the target scheme
+///
+/// ॐ has no SLP1 spelling, so it travels through SLP1 as the symbol itself.
+fn apply_om_style(slp1: &str, to: Scheme, style: OmStyle) -> String {
+    match (style, to.is_brahmic()) {
+        (OmStyle::Om, false) => slp1.replace(OM, "oM"),
+        (OmStyle::Aum, false) => slp1.replace(OM, "auM"),
+        (OmStyle::Pranava, true) => slp1
+            .split_inclusive(char::is_whitespace)
+            .map(|chunk| match chunk.trim_end() {
+                "oM" => format!("{}{}", OM, &chunk[2..]),
+                _ => chunk.to_string(),
+            })
+            .collect(),
+        _ => slp1.to_string(),
+    }
+}
+
+/// Replace each SLP1 `M` before a stop with the nasal of that stop's varga
+fn homorganic_anusvara(slp1: &str) -> String {
+    let chars: Vec<char> = slp1.chars().collect();
+    let mut result = String::with_capacity(slp1.len());
+
+    for (i, &c) in chars.iter().enumerate() {
+        let nasal = match (c, chars.get(i + 1)) {
+            ('M', Some('k' | 'K' | 'g' | 'G')) => 'N',
+            ('M', Some('c' | 'C' | 'j' | 'J')) => 'Y',
+            ('M', Some('w' | 'W' | 'q' | 'Q')) => 'R',
+            ('M', Some('t' | 'T' | 'd' | 'D')) => 'n',
+            ('M', Some('p' | 'P' | 'b' | 'B')) => 'm',
+            _ => c,
+        };
+        result.push(nasal);
+    }
+
+    result
+}
+
+/// Replace each SLP1 `M` with a nasal letter, for [`AnusvaraGlyph::NasalLetter`]
+fn nasal_letter_anusvara(slp1: &str) -> String {
+    let homorganic = homorganic_anusvara(slp1);
+    let chars: Vec<char> = homorganic.chars().collect();
+
+    chars
+        .iter()
+        .enumerate()
+        .map(|(i, &c)| match (c, chars.get(i + 1)) {
+            ('M', Some('S' | 'z' | 's' | 'h')) => 'n',
+            ('M', _) => 'm',
+            _ => c,
+        })
+        .collect()
+}
+
+/// Check whether text can actually be converted from one scheme to another
+///
+/// Derived from the same conversion match arms that [`transliterate`] uses, so
+/// it cannot drift from the implementation.
+pub fn is_supported(from: Scheme, to: Scheme) -> bool {
+    from == to || (to_slp1("", from).is_some() && from_slp1("", to).is_some())
+}
+
+/// All ordered pairs of distinct schemes that [`is_supported`] accepts
+pub fn supported_pairs() -> Vec<(Scheme, Scheme)> {
+    let all = Scheme::all();
+    all.iter()
+        .flat_map(|&from| all.iter().map(move |&to| (from, to)))
+        .filter(|&(from, to)| from != to && is_supported(from, to))
+        .collect()
+}
+
+/// Approximate SLP1 for a Brahmic script that has no table of its own yet
+///
+/// The Unicode blocks from Bengali to Malayalam follow the layout of the
+/// Devanagari block, so each char is read as the Devanagari char at the same
+/// offset. This is a stopgap for Odia, Bengali, Kannada, ...: signs a script
+/// adds or places differently, such as the Kannada short e and o or the
+/// Malayalam chillus, do not come out right. Other text passes through as
+/// for Devanagari input.
+pub fn generic_brahmic_to_slp1(text: &str) -> String {
+    devanagari_to_slp1(&indic::parallel_blocks_to_devanagari(text))
+}
+
+/// Convert text to SLP1, or `None` if the scheme is not implemented yet
+fn to_slp1(text: &str, from: Scheme) -> Option<String> {
+    let slp1 = match from {
         Scheme::Slp1 => text.to_string(),
         Scheme::Devanagari => devanagari_to_slp1(text),
-        Scheme::Iast => map_to_slp1(text, &mappings::get_iast_to_slp1()),
+        Scheme::Iast => map_to_slp1(&fold_iast(text), &mappings::get_iast_to_slp1()),
+        Scheme::Iso15919 => map_to_slp1(&fold_iast(text), mappings::get_iso_to_slp1_map()),
         Scheme::HarvardKyoto => map_to_slp1(text, &mappings::get_hk_to_slp1()),
-        _ => text.to_string(), // Not implemented yet
-    }
+        Scheme::Itrans => map_to_slp1(text, &mappings::get_itrans_to_slp1()),
+        Scheme::Malayalam => devanagari_to_slp1(&indic::malayalam_to_devanagari(text)),
+        Scheme::Gurmukhi => devanagari_to_slp1(&indic::gurmukhi_to_devanagari(text)),
+        _ => return None,
+    };
+    Some(slp1)
 }
 
-/// Convert text from SLP1
-fn from_slp1(text: &str, to: Scheme) -> String {
-    match to {
+/// Convert text from SLP1, or `None` if the scheme is not implemented yet
+fn from_slp1(text: &str, to: Scheme) -> Option<String> {
+    let converted = match to {
         Scheme::Slp1 => text.to_string(),
         Scheme::Devanagari => slp1_to_devanagari(text),
-        Scheme::Iast => map_from_slp1(text, &invert_map(&mappings::get_iast_to_slp1())),
-        Scheme::HarvardKyoto => map_from_slp1(text, &invert_map(&mappings::get_hk_to_slp1())),
-        _ => text.to_string(), // Not implemented yet
+        Scheme::Iast => map_from_slp1(text, mappings::get_slp1_to_iast_map()),
+        Scheme::Iso15919 => map_from_slp1(text, mappings::get_slp1_to_iso_map()),
+        Scheme::HarvardKyoto => map_from_slp1(text, mappings::get_slp1_to_hk_map()),
+        Scheme::Itrans => map_from_slp1(text, &mappings::get_slp1_to_itrans()),
+        Scheme::Malayalam => indic::devanagari_to_malayalam(&slp1_to_devanagari(text)),
+        Scheme::Gurmukhi => indic::devanagari_to_gurmukhi(&slp1_to_devanagari(text)),
+        _ => return None,
+    };
+    Some(converted)
+}
+
+/// Prepare IAST for table matching: compose to NFC and fold case, since
+/// capitals (a sentence-initial "Ś") carry no phonetic meaning
+fn fold_iast(text: &str) -> Cow<'_, str> {
+    let text = compose(text);
+    if text.chars().any(char::is_uppercase) {
+        Cow::Owned(text.to_lowercase())
+    } else {
+        text
     }
 }
 
-fn invert_map(map: &[(&'static str, &'static str)]) -> Vec<(&'static str, &'static str)> {
-    let mut inv: Vec<(&'static str, &'static str)> = map.iter().map(|(k, v)| (*v, *k)).collect();
-    inv.sort_by(|a, b| b.0.len().cmp(&a.0.len()));
-    inv
+/// Compose text to NFC so decomposed diacritics match the IAST table
+///
+/// `ṝ` may arrive as `ṛ` + U+0304 or `r` + U+0323 + U+0304; all become U+1E5D.
+fn compose(text: &str) -> Cow<'_, str> {
+    if is_nfc(text) {
+        Cow::Borrowed(text)
+    } else {
+        Cow::Owned(text.nfc().collect())
+    }
 }
 
 /// Generic greedy mapper
-fn map_to_slp1(text: &str, mapping: &[(&str, &str)]) -> String {
-    let mut result = String::with_capacity(text.len());
+pub(crate) fn map_to_slp1(text: &str, mapping: &[(&str, &str)]) -> String {
+    map_chars(text, mapping).collect()
+}
+
+/// Greedily map text, yielding the mapped chars one at a time
+///
+/// Keys are tried in order, so the mapping must list the longest keys first.
+/// Text that matches no key is passed through.
+fn map_chars<'a>(
+    text: &'a str,
+    mapping: &'a [(&'a str, &'a str)],
+) -> impl Iterator<Item = char> + 'a {
     let mut i = 0;
+    let mut pending = "".chars();
 
-    while i < text.len() {
-        let mut matched = false;
-        // Try to match longest key first
-        for (key, val) in mapping {
-            if text[i..].starts_with(key) {
-                result.push_str(val);
+    std::iter::from_fn(move || loop {
+        if let Some(c) = pending.next() {
+            return Some(c);
+        }
+
+        let rest = &text[i..];
+        match mapping.iter().find(|(key, _)| rest.starts_with(key)) {
+            Some((key, val)) => {
                 i += key.len();
-                matched = true;
-                break;
+                pending = val.chars();
             }
-        }
-        if !matched {
-            if let Some(c) = text[i..].chars().next() {
-                result.push(c);
+            None => {
+                let c = rest.chars().next()?;
                 i += c.len_utf8();
-            } else {
-                break;
+                return Some(c);
             }
         }
-    }
-    result
+    })
 }
 
 fn map_from_slp1(text: &str, mapping: &[(&str, &str)]) -> String {
     map_to_slp1(text, mapping)
 }
 
+/// Devanagari and SLP1 letter inventories with char lookup indexes
+///
+/// Entries at the same position correspond, e.g. `dev_consonants[i]` is
+/// written `slp1_consonants[i]` in SLP1.
+pub(crate) struct DevanagariTables {
+    dev_vowels: Vec<&'static str>,
+    dev_matras: Vec<&'static str>,
+    dev_consonants: Vec<&'static str>,
+    slp1_vowels: Vec<&'static str>,
+    slp1_consonants: Vec<&'static str>,
+    pub(crate) dev_vowel_index: FxHashMap<char, usize>,
+    pub(crate) dev_matra_index: FxHashMap<char, usize>,
+    pub(crate) dev_consonant_index: FxHashMap<char, usize>,
+    slp1_vowel_index: FxHashMap<char, usize>,
+    slp1_consonant_index: FxHashMap<char, usize>,
+}
+
+impl DevanagariTables {
+    fn new() -> Self {
+        let mut dev_consonants = mappings::get_devanagari_vyanjanas();
+        dev_consonants.extend(mappings::get_devanagari_extended_vyanjanas());
+        let mut slp1_consonants = mappings::get_slp1_vyanjanas();
+        slp1_consonants.extend(mappings::get_slp1_extended_vyanjanas());
+
+        let dev_vowels = mappings::get_devanagari_swaras();
+        let dev_matras = mappings::get_devanagari_matras();
+        let slp1_vowels = mappings::get_slp1_swaras();
+
+        Self {
+            dev_vowel_index: char_index(&dev_vowels),
+            dev_matra_index: char_index(&dev_matras),
+            dev_consonant_index: char_index(&dev_consonants),
+            slp1_vowel_index: char_index(&slp1_vowels),
+            slp1_consonant_index: char_index(&slp1_consonants),
+            dev_vowels,
+            dev_matras,
+            dev_consonants,
+            slp1_vowels,
+            slp1_consonants,
+        }
+    }
+
+    pub(crate) fn get() -> &'static Self {
+        static TABLES: OnceLock<DevanagariTables> = OnceLock::new();
+        TABLES.get_or_init(Self::new)
+    }
+}
+
+/// Map each single-char entry to its position (the empty inherent-a matra is skipped)
+fn char_index(items: &[&str]) -> FxHashMap<char, usize> {
+    items
+        .iter()
+        .enumerate()
+        .filter_map(|(pos, item)| item.chars().next().map(|c| (c, pos)))
+        .collect()
+}
+
 fn devanagari_to_slp1(text: &str) -> String {
-    let vowels = mappings::get_devanagari_swaras();
-    let matras = mappings::get_devanagari_matras();
-    let consonants = mappings::get_devanagari_vyanjanas();
+    let mut result = String::with_capacity(text.len());
+    devanagari_to_slp1_with(text, |c| result.push(c));
+    result
+}
 
-    let slp1_vowels = mappings::get_slp1_swaras();
-    let slp1_consonants = mappings::get_slp1_vyanjanas();
+/// Convert Devanagari to SLP1, passing each output char to `emit`
+fn devanagari_to_slp1_with(text: &str, mut emit: impl FnMut(char)) {
+    let tables = DevanagariTables::get();
+    let mut emit_str = |s: &str| s.chars().for_each(&mut emit);
 
-    let mut result = String::new();
-    let chars: Vec<char> = text.chars().collect();
-    let mut i = 0;
+    let mut chars = text.chars().peekable();
 
-    while i < chars.len() {
-        let c = chars[i];
-        let c_str = c.to_string();
-
-        if let Some(pos) = vowels.iter().position(|&v| v == c_str) {
-            result.push_str(slp1_vowels[pos]);
-            i += 1;
-        } else if let Some(pos) = consonants.iter().position(|&v| v == c_str) {
-            let slp1_cons = slp1_consonants[pos];
-            result.push_str(slp1_cons);
-
-            if i + 1 < chars.len() {
-                let next = chars[i + 1];
-                let next_str = next.to_string();
-
-                if let Some(m_pos) = matras.iter().position(|&m| m == next_str) {
-                    result.push_str(slp1_vowels[m_pos]);
-                    i += 2;
-                } else if next == '्' {
-                    i += 2;
-                } else {
-                    result.push('a');
-                    i += 1;
-                }
-            } else {
-                result.push('a');
-                i += 1;
+    while let Some(c) = chars.next() {
+        let nukta_base = mappings::get_devanagari_nukta_base(c);
+        let c = nukta_base.unwrap_or(c);
+
+        if let Some(&pos) = tables.dev_vowel_index.get(&c) {
+            emit_str(tables.slp1_vowels[pos]);
+        } else if let Some(&pos) = tables.dev_consonant_index.get(&c) {
+            emit_str(tables.slp1_consonants[pos]);
+            if nukta_base.is_some() || chars.next_if_eq(&NUKTA).is_some() {
+                emit_str(NUKTA.encode_utf8(&mut [0; 4]));
             }
-        } else {
-            if c == 'ं' {
-                result.push('M');
-            } else if c == 'ः' {
-                result.push('H');
-            } else if c == 'ऽ' {
-                result.push('\'');
+
+            let next = chars.peek().copied();
+            if let Some(&m_pos) = next.and_then(|n| tables.dev_matra_index.get(&n)) {
+                emit_str(tables.slp1_vowels[m_pos]);
+                chars.next();
+            } else if next == Some('्') {
+                chars.next();
             } else {
-                result.push(c);
+                emit_str("a");
             }
-            i += 1;
+        } else {
+            let other = mappings::get_devanagari_other_to_slp1(c).unwrap_or(c);
+            emit_str(other.encode_utf8(&mut [0; 4]));
         }
     }
-
-    result
 }
 
 fn slp1_to_devanagari(text: &str) -> String {
-    let slp1_vowels = mappings::get_slp1_swaras();
-    let slp1_consonants = mappings::get_slp1_vyanjanas();
+    slp1_chars_to_devanagari(text.chars())
+}
 
-    let dev_vowels = mappings::get_devanagari_swaras();
-    let dev_matras = mappings::get_devanagari_matras();
-    let dev_consonants = mappings::get_devanagari_vyanjanas();
+fn slp1_chars_to_devanagari(chars: impl Iterator<Item = char>) -> String {
+    let tables = DevanagariTables::get();
 
     let mut result = String::new();
-    let chars: Vec<char> = text.chars().collect();
-    let mut i = 0;
+    let mut chars = chars.peekable();
 
-    while i < chars.len() {
-        let c = chars[i];
-        let c_str = c.to_string();
-
-        if let Some(pos) = slp1_consonants.iter().position(|&v| v == c_str) {
-            result.push_str(dev_consonants[pos]);
-
-            if i + 1 < chars.len() {
-                let next = chars[i + 1];
-                let next_str = next.to_string();
-
-                if let Some(v_pos) = slp1_vowels.iter().position(|&v| v == next_str) {
-                    if next == 'a' {
-                        // Implicit 'a'
-                    } else {
-                        result.push_str(dev_matras[v_pos]);
-                    }
-                    i += 2;
-                } else {
-                    result.push('्');
-                    i += 1;
-                }
+    while let Some(c) = chars.next() {
+        if let Some(&pos) = tables.slp1_consonant_index.get(&c) {
+            result.push_str(tables.dev_consonants[pos]);
+            if chars.next_if_eq(&NUKTA).is_some() {
+                result.push(NUKTA);
+            }
+
+            let next = chars.peek().copied();
+            if let Some(&v_pos) = next.and_then(|n| tables.slp1_vowel_index.get(&n)) {
+                // Implicit 'a' has an empty matra
+                result.push_str(tables.dev_matras[v_pos]);
+                chars.next();
             } else {
                 result.push('्');
-                i += 1;
             }
-        } else if let Some(pos) = slp1_vowels.iter().position(|&v| v == c_str) {
-            result.push_str(dev_vowels[pos]);
-            i += 1;
+        } else if let Some(&pos) = tables.slp1_vowel_index.get(&c) {
+            result.push_str(tables.dev_vowels[pos]);
         } else {
-            if c == 'M' {
-                result.push('ं');
-            } else if c == 'H' {
-                result.push('ः');
-            } else if c == '\'' {
-                result.push('ऽ');
-            } else {
-                result.push(c);
+            match mappings::get_slp1_to_devanagari(c) {
+                Some(sign) => result.push_str(sign),
+                None => result.push(c),
             }
-            i += 1;
         }
     }
 
     result
 }
 
+/// IAST mapping tables for the direct IAST↔Devanagari converters
+struct IastTables {
+    /// IAST → SLP1, longest key first
+    to_slp1: Vec<(&'static str, &'static str)>,
+    /// SLP1 char → IAST, first entry of the inverted table winning
+    from_slp1: FxHashMap<char, &'static str>,
+}
+
+impl IastTables {
+    fn get() -> &'static Self {
+        static TABLES: OnceLock<IastTables> = OnceLock::new();
+        TABLES.get_or_init(|| {
+            let to_slp1 = mappings::get_iast_to_slp1();
+            let mut from_slp1 = FxHashMap::default();
+            for (slp1, iast) in mappings::invert_map(&to_slp1) {
+                let mut chars = slp1.chars();
+                if let (Some(c), None) = (chars.next(), chars.next()) {
+                    from_slp1.entry(c).or_insert(iast);
+                }
+            }
+            IastTables { to_slp1, from_slp1 }
+        })
+    }
+}
+
+fn iast_to_devanagari(text: &str) -> String {
+    let text = fold_iast(text);
+    slp1_chars_to_devanagari(map_chars(&text, &IastTables::get().to_slp1))
+}
+
+fn devanagari_to_iast(text: &str) -> String {
+    let table = &IastTables::get().from_slp1;
+    let mut result = String::with_capacity(text.len());
+    let mut push = |c: char| match table.get(&c) {
+        Some(iast) => result.push_str(iast),
+        None => result.push(c),
+    };
+
+    devanagari_to_slp1_with(text, |c| {
+        if c == OM {
+            // Default OmStyle::Om
+            push('o');
+            push('M');
+        } else {
+            push(c);
+        }
+    });
+    result
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -216,6 +772,21 @@ mod tests {
         assert_eq!(transliterate("RR", Scheme::HarvardKyoto, Scheme::Slp1), "F");
     }
 
+    #[test]
+    fn test_slp1_to_hk_sibilants() {
+        // Regression: HK swaps the case of the ś/ṣ pair relative to SLP1
+        assert_eq!(transliterate("S", Scheme::Slp1, Scheme::HarvardKyoto), "z");
+        assert_eq!(transliterate("z", Scheme::Slp1, Scheme::HarvardKyoto), "S");
+        assert_eq!(
+            transliterate("kfzRaH SivaH", Scheme::Slp1, Scheme::HarvardKyoto),
+            "kRSNaH zivaH"
+        );
+        assert_eq!(
+            transliterate("kRSNaH zivaH", Scheme::HarvardKyoto, Scheme::Slp1),
+            "kfzRaH SivaH"
+        );
+    }
+
     #[test]
     fn test_deva_to_slp1() {
         assert_eq!(
@@ -250,6 +821,450 @@ mod tests {
         assert_eq!(input, back);
     }
 
+    #[test]
+    fn test_vedic_retroflex_la() {
+        // agnim īḻe (Ṛgveda 1.1.1), with ḻ kept apart from vocalic ḷ
+        assert_eq!(
+            transliterate("agnimILe", Scheme::Slp1, Scheme::Devanagari),
+            "अग्निमीळे"
+        );
+        assert_eq!(
+            transliterate("अग्निमीळे", Scheme::Devanagari, Scheme::Slp1),
+            "agnimILe"
+        );
+        assert_eq!(
+            transliterate("agnimILe", Scheme::Slp1, Scheme::Iast),
+            "agnimīḻe"
+        );
+        assert_eq!(
+            transliterate("agnimīḻe", Scheme::Iast, Scheme::Slp1),
+            "agnimILe"
+        );
+        assert_eq!(transliterate("kxpta", Scheme::Slp1, Scheme::Iast), "kḷpta");
+    }
+
+    #[test]
+    fn test_schwa_deletion_option() {
+        let hindi = TransliterateOptions {
+            schwa_deletion: true,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            transliterate_with_options("राम", Scheme::Devanagari, Scheme::Iast, &hindi),
+            "rām"
+        );
+        assert_eq!(
+            transliterate_with_options(
+                "राम",
+                Scheme::Devanagari,
+                Scheme::Iast,
+                &TransliterateOptions::default()
+            ),
+            "rāma"
+        );
+        assert_eq!(
+            transliterate_with_options("कमला", Scheme::Devanagari, Scheme::Iast, &hindi),
+            "kamlā"
+        );
+        // Only applies when romanizing
+        assert_eq!(
+            transliterate_with_options("राम", Scheme::Devanagari, Scheme::Slp1, &hindi),
+            "rAm"
+        );
+    }
+
+    #[test]
+    fn test_om_style() {
+        let om = TransliterateOptions::default();
+        let aum = TransliterateOptions {
+            om_style: OmStyle::Aum,
+            ..Default::default()
+        };
+        let pranava = TransliterateOptions {
+            om_style: OmStyle::Pranava,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            transliterate("ॐ नमः", Scheme::Devanagari, Scheme::Iast),
+            "oṃ namaḥ"
+        );
+        assert_eq!(transliterate("ॐ", Scheme::Devanagari, Scheme::Slp1), "oM");
+        assert_eq!(
+            transliterate_with_options("ॐ", Scheme::Devanagari, Scheme::Iast, &aum),
+            "auṃ"
+        );
+
+        // Pranava keeps the symbol through SLP1 and writes oṃ back as ॐ
+        let slp1 = transliterate_with_options("ॐ", Scheme::Devanagari, Scheme::Slp1, &pranava);
+        assert_eq!(slp1, "ॐ");
+        assert_eq!(
+            transliterate_with_options(&slp1, Scheme::Slp1, Scheme::Devanagari, &pranava),
+            "ॐ"
+        );
+        assert_eq!(
+            transliterate_with_options("oṃ namaḥ", Scheme::Iast, Scheme::Devanagari, &pranava),
+            "ॐ नमः"
+        );
+        assert_eq!(
+            transliterate_with_options("oṃ", Scheme::Iast, Scheme::Devanagari, &om),
+            "ओं"
+        );
+    }
+
+    #[test]
+    fn test_devanagari_full_inventory() {
+        let tables = DevanagariTables::get();
+
+        for (c, &pos) in &tables.dev_consonant_index {
+            let slp1_cons = tables.slp1_consonants[pos];
+            assert_eq!(
+                mappings::get_devanagari_consonant_to_slp1(*c).map(String::from),
+                Some(slp1_cons.to_string())
+            );
+
+            for (v, slp1_vowel) in tables.slp1_vowels.iter().enumerate() {
+                let deva = format!("{}{}", c, tables.dev_matras[v]);
+                let slp1 = format!("{}{}", slp1_cons, slp1_vowel);
+                assert_eq!(devanagari_to_slp1(&deva), slp1);
+                assert_eq!(slp1_to_devanagari(&slp1), deva);
+            }
+
+            let halant = format!("{}्", c);
+            assert_eq!(devanagari_to_slp1(&halant), slp1_cons);
+            assert_eq!(slp1_to_devanagari(slp1_cons), halant);
+        }
+
+        for (v, deva) in tables.dev_vowels.iter().enumerate() {
+            assert_eq!(devanagari_to_slp1(deva), tables.slp1_vowels[v]);
+            assert_eq!(slp1_to_devanagari(tables.slp1_vowels[v]), *deva);
+        }
+    }
+
+    #[test]
+    fn test_transliterate_markup() {
+        assert_eq!(
+            transliterate_markup("<b>rāma</b>", Scheme::Iast, Scheme::Devanagari),
+            "<b>राम</b>"
+        );
+        assert_eq!(
+            transliterate_markup(
+                r#"<a href="deva.html">deva</a> &amp; <i>sItA</i>"#,
+                Scheme::Slp1,
+                Scheme::Iast
+            ),
+            r#"<a href="deva.html">deva</a> &amp; <i>sītā</i>"#
+        );
+        // A bare & or an unclosed < is ordinary text
+        assert_eq!(
+            transliterate_markup("rAma & sItA", Scheme::Slp1, Scheme::Iast),
+            "rāma & sītā"
+        );
+        assert_eq!(
+            transliterate_markup("a < A", Scheme::Slp1, Scheme::Iast),
+            "a < ā"
+        );
+    }
+
+    #[test]
+    fn test_iast_devanagari_fast_path() {
+        let iast = "dharmakṣetre kurukṣetre samavetā yuyutsavaḥ |\n\
+                    māmakāḥ pāṇḍavāś caiva kim akurvata sañjaya || 1 ||\n\
+                    oṃ saṃskṛtam ham̐sa vāk ḻa 'stu Ābc";
+        let deva = "धर्मक्षेत्रे कुरुक्षेत्रे समवेता युयुत्सवः ।\n\
+                    मामकाः पाण्डवाश्चैव किमकुर्वत सञ्जय ॥ १ ॥\n\
+                    ॐ संस्कृतम् हँस वाक् ळ ऽस्तु";
+
+        let options = TransliterateOptions::default();
+        for text in [iast, deva] {
+            assert_eq!(
+                transliterate(text, Scheme::Iast, Scheme::Devanagari),
+                via_slp1(text, Scheme::Iast, Scheme::Devanagari, &options)
+            );
+            assert_eq!(
+                transliterate(text, Scheme::Devanagari, Scheme::Iast),
+                via_slp1(text, Scheme::Devanagari, Scheme::Iast, &options)
+            );
+        }
+        assert_eq!(iast_to_devanagari("rāmaḥ"), "रामः");
+        assert_eq!(devanagari_to_iast("रामः"), "rāmaḥ");
+    }
+
+    #[test]
+    fn test_iast_long_vocalic_nfc_nfd() {
+        let forms = [
+            "pit\u{1E5D}n",
+            "pit\u{1E5B}\u{0304}n",
+            "pitr\u{0323}\u{0304}n",
+            "pitr\u{0304}\u{0323}n",
+        ];
+        for pitrn in forms {
+            assert_eq!(transliterate(pitrn, Scheme::Iast, Scheme::Slp1), "pitFn");
+            assert_eq!(
+                transliterate(pitrn, Scheme::Iast, Scheme::Devanagari),
+                "पितॄन्"
+            );
+        }
+
+        for klp in ["\u{1E39}", "\u{1E37}\u{0304}", "l\u{0323}\u{0304}"] {
+            assert_eq!(transliterate(klp, Scheme::Iast, Scheme::Slp1), "X");
+        }
+    }
+
+    #[test]
+    fn test_iast_capitals() {
+        assert_eq!(
+            transliterate("Śiva uvāca", Scheme::Iast, Scheme::Slp1),
+            "Siva uvAca"
+        );
+        assert_eq!(transliterate("ŚIVAḤ", Scheme::Iast, Scheme::Slp1), "SivaH");
+        assert_eq!(
+            transliterate("Śiva uvāca", Scheme::Iast, Scheme::Devanagari),
+            "शिव उवाच"
+        );
+        assert_eq!(
+            transliterate("Ṛṣiḥ Ā", Scheme::Iast, Scheme::Devanagari),
+            "ऋषिः आ"
+        );
+    }
+
+    #[test]
+    fn test_restore_case() {
+        assert_eq!(restore_case("Śiva uvāca", "śiva uvāca"), "Śiva uvāca");
+        assert_eq!(restore_case("ŚIVA uvāca", "śiva uvāca"), "ŚIVA uvāca");
+        assert_eq!(restore_case("Ā iti", "ā iti"), "Ā iti");
+        assert_eq!(restore_case("rāma", "rāma"), "rāma");
+
+        let options = TransliterateOptions {
+            preserve_case: true,
+            anusvara_iast: AnusvaraGlyph::DotAbove,
+            ..Default::default()
+        };
+        // Case is phonemic in SLP1, so nothing is restored there
+        assert_eq!(
+            transliterate_with_options("Śiva Saṃskṛtam", Scheme::Iast, Scheme::Slp1, &options),
+            "Siva saMskftam"
+        );
+
+        let iso =
+            transliterate_with_options("Śiva uvāca", Scheme::Iast, Scheme::Iso15919, &options);
+        assert_eq!(iso, "Śiva uvāca");
+        let iso = transliterate_with_options("ŚIVA Ṛṣi", Scheme::Iast, Scheme::Iso15919, &options);
+        assert_eq!(iso, "ŚIVA R̥ṣi");
+        // SLP1 cannot carry the case back
+        let slp1 = transliterate_with_options("Śiva uvāca", Scheme::Iast, Scheme::Slp1, &options);
+        assert_eq!(slp1, "Siva uvAca");
+        assert_eq!(
+            transliterate_with_options(&slp1, Scheme::Slp1, Scheme::Iast, &options),
+            "śiva uvāca"
+        );
+    }
+
+    #[test]
+    fn test_combining_nukta() {
+        let precomposed = "\u{0958}\u{0932}\u{092E} \u{095B}\u{093F}\u{0926}";
+        let combining = "\u{0915}\u{093C}\u{0932}\u{092E} \u{091C}\u{093C}\u{093F}\u{0926}";
+
+        let slp1 = transliterate(precomposed, Scheme::Devanagari, Scheme::Slp1);
+        assert_eq!(slp1, "k\u{093C}alama j\u{093C}ida");
+        assert_eq!(
+            transliterate(combining, Scheme::Devanagari, Scheme::Slp1),
+            slp1
+        );
+
+        // Back in Devanagari the nukta is written as a combining sign
+        assert_eq!(
+            transliterate(&slp1, Scheme::Slp1, Scheme::Devanagari),
+            combining
+        );
+        assert_eq!(
+            transliterate("\u{095C}\u{094D}", Scheme::Devanagari, Scheme::Slp1),
+            "q\u{093C}"
+        );
+    }
+
+    #[test]
+    fn test_itrans() {
+        assert_eq!(
+            transliterate("kRRiShNaH shivaH", Scheme::Itrans, Scheme::Slp1),
+            "kfzRaH SivaH"
+        );
+        assert_eq!(
+            transliterate("kfzRaH SivaH", Scheme::Slp1, Scheme::Itrans),
+            "kRRiShNaH shivaH"
+        );
+        assert_eq!(
+            transliterate("raama ii R^i", Scheme::Itrans, Scheme::Slp1),
+            "rAma I f"
+        );
+        assert_eq!(transliterate("x GY", Scheme::Itrans, Scheme::Slp1), "kz jY");
+    }
+
+    #[test]
+    fn test_itrans_avagraha_anusvara() {
+        // so 'ham saMskRRitam, with the anusvāra spelled both ways
+        for itrans in ["so.aha.m saMskRRitam", "so.ahaM sa.mskRRitam"] {
+            assert_eq!(
+                transliterate(itrans, Scheme::Itrans, Scheme::Slp1),
+                "so'haM saMskftam"
+            );
+        }
+        assert_eq!(
+            transliterate("so'haM saMskftam", Scheme::Slp1, Scheme::Itrans),
+            "so.ahaM saMskRRitam"
+        );
+        assert_eq!(
+            transliterate("so.ahaM ha.Nsa", Scheme::Itrans, Scheme::Devanagari),
+            "सोऽहं हँस"
+        );
+        assert_eq!(transliterate("vAk.h", Scheme::Itrans, Scheme::Slp1), "vAk");
+    }
+
+    #[test]
+    fn test_candrabindu_round_trip() {
+        for (deva, slp1, iast) in [("हँस", "ha~sa", "ham̐sa"), ("चाँद", "cA~da", "cām̐da")]
+        {
+            assert_eq!(transliterate(deva, Scheme::Devanagari, Scheme::Slp1), slp1);
+            assert_eq!(transliterate(slp1, Scheme::Slp1, Scheme::Devanagari), deva);
+            assert_eq!(transliterate(slp1, Scheme::Slp1, Scheme::Iast), iast);
+            assert_eq!(transliterate(iast, Scheme::Iast, Scheme::Slp1), slp1);
+            assert_eq!(transliterate(deva, Scheme::Devanagari, Scheme::Iast), iast);
+            assert_eq!(transliterate(iast, Scheme::Iast, Scheme::Devanagari), deva);
+        }
+    }
+
+    #[test]
+    fn test_sindhi_implosives() {
+        assert_eq!(
+            transliterate("ॻालिह", Scheme::Devanagari, Scheme::Slp1),
+            "ɠAliha"
+        );
+
+        for word in ["ॻालिह", "ॿारु", "ॾिठो", "ॼाणु", "ग्ॻ"]
+        {
+            let slp1 = transliterate(word, Scheme::Devanagari, Scheme::Slp1);
+            assert!(!slp1.chars().any(|c| ('\u{0900}'..='\u{097F}').contains(&c)));
+            assert_eq!(transliterate(&slp1, Scheme::Slp1, Scheme::Devanagari), word);
+        }
+    }
+
+    #[test]
+    fn test_anusvara_glyph() {
+        let dot_above = TransliterateOptions {
+            anusvara_iast: AnusvaraGlyph::DotAbove,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            transliterate("saMskftam", Scheme::Slp1, Scheme::Iast),
+            "saṃskṛtam"
+        );
+        assert_eq!(
+            transliterate_with_options("saMskftam", Scheme::Slp1, Scheme::Iast, &dot_above),
+            "saṁskṛtam"
+        );
+        // Both glyphs are read back as M
+        assert_eq!(
+            transliterate("saṁskṛtam", Scheme::Iast, Scheme::Slp1),
+            "saMskftam"
+        );
+        // Other targets are unaffected
+        assert_eq!(
+            transliterate_with_options("saMskftam", Scheme::Slp1, Scheme::Devanagari, &dot_above),
+            "संस्कृतम्"
+        );
+    }
+
+    #[test]
+    fn test_anusvara_render() {
+        let dot = TransliterateOptions::default();
+        let homorganic = TransliterateOptions {
+            anusvara: AnusvaraRender::Homorganic,
+            ..Default::default()
+        };
+        let deva = |text, options| {
+            transliterate_with_options(text, Scheme::Slp1, Scheme::Devanagari, options)
+        };
+
+        assert_eq!(deva("saMkalpa", &dot), "संकल्प");
+        assert_eq!(deva("saMkalpa", &homorganic), "सङ्कल्प");
+        assert_eq!(deva("aMka", &dot), "अंक");
+        assert_eq!(deva("aMka", &homorganic), "अङ्क");
+        assert_eq!(deva("aMcala", &homorganic), "अञ्चल");
+        assert_eq!(deva("kaRWa", &homorganic), "कण्ठ");
+        assert_eq!(deva("SaMBu", &homorganic), "शम्भु");
+        // No stop follows: the anusvāra stays in both renderings
+        assert_eq!(deva("saMskfta", &dot), "संस्कृत");
+        assert_eq!(deva("saMskfta", &homorganic), "संस्कृत");
+        // An explicit nasal is never touched
+        assert_eq!(deva("aNka", &dot), "अङ्क");
+    }
+
+    #[test]
+    fn test_supported_pairs() {
+        assert!(is_supported(Scheme::Slp1, Scheme::Devanagari));
+        assert!(is_supported(Scheme::Devanagari, Scheme::Slp1));
+        assert!(is_supported(Scheme::Iast, Scheme::Slp1));
+        assert!(is_supported(Scheme::Slp1, Scheme::Iast));
+        assert!(!is_supported(Scheme::Devanagari, Scheme::Tibetan));
+        assert!(!is_supported(Scheme::Tibetan, Scheme::Iast));
+
+        let pairs = supported_pairs();
+        assert!(pairs.contains(&(Scheme::HarvardKyoto, Scheme::Devanagari)));
+        assert!(pairs.iter().all(|(from, to)| from != to));
+        for scheme in Scheme::all().into_iter().filter(|s| s.is_lossless()) {
+            assert!(pairs.contains(&(scheme, Scheme::Slp1)) || scheme == Scheme::Slp1);
+        }
+    }
+
+    #[test]
+    fn test_iso15919_long_e_o() {
+        assert_eq!(
+            transliterate("dēvō", Scheme::Iso15919, Scheme::Slp1),
+            "devo"
+        );
+        // Sanskrit e and o are always long, so plain e and o read the same
+        assert_eq!(
+            transliterate("devo", Scheme::Iso15919, Scheme::Slp1),
+            "devo"
+        );
+        assert_eq!(
+            transliterate("devo", Scheme::Slp1, Scheme::Iso15919),
+            "dēvō"
+        );
+
+        for iso in ["ē", "dēvō", "kēśava", "ōṣadhiḥ", "gaurī"] {
+            let slp1 = transliterate(iso, Scheme::Iso15919, Scheme::Slp1);
+            assert_eq!(transliterate(&slp1, Scheme::Slp1, Scheme::Iso15919), iso);
+            let deva = transliterate(iso, Scheme::Iso15919, Scheme::Devanagari);
+            assert_eq!(
+                transliterate(&deva, Scheme::Devanagari, Scheme::Iso15919),
+                iso
+            );
+        }
+        // IAST has no long mark, and gains none from ISO input
+        assert_eq!(
+            transliterate("dēvō", Scheme::Iso15919, Scheme::Iast),
+            "devo"
+        );
+    }
+
+    #[test]
+    fn test_iso15919_liquids() {
+        assert_eq!(
+            transliterate("kr̥ṣṇaḥ kl̥ptam", Scheme::Iso15919, Scheme::Slp1),
+            "kfzRaH kxptam"
+        );
+        assert_eq!(
+            transliterate("pitFn", Scheme::Slp1, Scheme::Iso15919),
+            "pitr̥̄n"
+        );
+        // ḷ is the retroflex lateral, not vocalic l as in IAST
+        assert_eq!(transliterate("īḷē", Scheme::Iso15919, Scheme::Slp1), "ILe");
+        assert_eq!(transliterate("ILe", Scheme::Slp1, Scheme::Iso15919), "īḷē");
+    }
+
     #[test]
     fn test_iast_to_devanagari() {
         let text = "namaste";
@@ -263,4 +1278,274 @@ mod tests {
         let result = transliterate(text, Scheme::Iast, Scheme::Devanagari);
         assert_eq!(result, "धर्मक्षेत्रे");
     }
+
+    #[test]
+    fn test_try_transliterate() {
+        assert_eq!(
+            try_transliterate("rAmaH", Scheme::Slp1, Scheme::Devanagari).as_deref(),
+            Ok("रामः")
+        );
+        assert_eq!(
+            try_transliterate("rāmaḥ", Scheme::Tamil, Scheme::Slp1),
+            Err(TransliterateError::SchemeNotImplemented {
+                from: Scheme::Tamil,
+                to: Scheme::Slp1
+            })
+        );
+        assert_eq!(
+            try_transliterate("राम తెలుగు", Scheme::Devanagari, Scheme::Slp1),
+            Err(TransliterateError::ScriptMismatch {
+                expected: Scheme::Devanagari,
+                found: Scheme::Telugu
+            })
+        );
+        // Shared dandas are not a mismatch
+        assert!(try_transliterate("रामः ।", Scheme::Devanagari, Scheme::Slp1).is_ok());
+    }
+
+    #[test]
+    fn test_validate_slp1() {
+        assert_eq!(validate_slp1("rAmaH, 'stu ॐ 12."), Ok(()));
+        assert_eq!(
+            validate_slp1("rAmaH śivaH"),
+            Err(TransliterateError::InvalidInput {
+                scheme: Scheme::Slp1,
+                offset: 6,
+                character: 'ś'
+            })
+        );
+        assert!(validate_slp1("ra\u{0301}ma").is_err());
+    }
+
+    #[test]
+    fn test_try_transliterate_with_question_mark() {
+        fn to_iast(slp1: &str) -> Result<String, TransliterateError> {
+            let deva = try_transliterate(slp1, Scheme::Slp1, Scheme::Devanagari)?;
+            try_transliterate(&deva, Scheme::Devanagari, Scheme::Iast)
+        }
+        assert_eq!(to_iast("kfzRa").as_deref(), Ok("kṛṣṇa"));
+        assert!(matches!(
+            to_iast("kṛṣṇa"),
+            Err(TransliterateError::InvalidInput { offset: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn test_escape_unmapped_round_trip() {
+        let escape = TransliterateOptions {
+            escape_unmapped: true,
+            ..Default::default()
+        };
+
+        let slp1 =
+            transliterate_with_options("राम 😀 \\", Scheme::Devanagari, Scheme::Slp1, &escape);
+        assert_eq!(slp1, "rAma \\u{1F600} \\u{5C}");
+        assert!(slp1.is_ascii());
+        assert_eq!(
+            transliterate_with_options(&slp1, Scheme::Slp1, Scheme::Devanagari, &escape),
+            "राम 😀 \\"
+        );
+
+        // Without the option the emoji passes through raw
+        assert_eq!(
+            transliterate("राम 😀", Scheme::Devanagari, Scheme::Slp1),
+            "rAma 😀"
+        );
+    }
+
+    #[test]
+    fn test_unescape_unmapped_invalid() {
+        assert_eq!(unescape_unmapped("a\\u{zz}b\\u{41"), "a\\u{zz}b\\u{41");
+        assert_eq!(unescape_unmapped("\\u{D800}"), "\\u{D800}");
+        assert_eq!(unescape_unmapped("\\u{915}\\u{93C}"), "\u{915}\u{93C}");
+    }
+
+    #[test]
+    fn test_malayalam() {
+        assert_eq!(
+            transliterate("rAmaH", Scheme::Slp1, Scheme::Malayalam),
+            "രാമഃ"
+        );
+        assert_eq!(
+            transliterate("ധർമ്മക്ഷേത്രേ", Scheme::Malayalam, Scheme::Iast),
+            "dharmmakṣetre"
+        );
+        assert_eq!(
+            transliterate("kfzRaH", Scheme::Slp1, Scheme::Malayalam),
+            "കൃഷ്ണഃ"
+        );
+    }
+
+    #[test]
+    fn test_malayalam_chillu_round_trip() {
+        let chillu = TransliterateOptions {
+            chillu: true,
+            ..Default::default()
+        };
+
+        let mal = transliterate_with_options("Bagavan", Scheme::Slp1, Scheme::Malayalam, &chillu);
+        assert_eq!(mal, "ഭഗവൻ");
+        assert_eq!(
+            transliterate(&mal, Scheme::Malayalam, Scheme::Slp1),
+            "Bagavan"
+        );
+
+        // Without the option the virama spelling is kept, and reads the same
+        let mal = transliterate("Bagavan", Scheme::Slp1, Scheme::Malayalam);
+        assert_eq!(mal, "ഭഗവന്");
+        assert_eq!(
+            transliterate(&mal, Scheme::Malayalam, Scheme::Slp1),
+            "Bagavan"
+        );
+
+        for word in ["pitFn", "kurvan", "gIr", "kamal", "praBuL", "vARiR"] {
+            let mal = transliterate_with_options(word, Scheme::Slp1, Scheme::Malayalam, &chillu);
+            assert_eq!(transliterate(&mal, Scheme::Malayalam, Scheme::Slp1), word);
+        }
+    }
+
+    #[test]
+    fn test_gurmukhi() {
+        assert_eq!(
+            transliterate("rAmaH", Scheme::Slp1, Scheme::Gurmukhi),
+            "ਰਾਮਃ"
+        );
+        assert_eq!(
+            transliterate("ਧਰ੍ਮ", Scheme::Gurmukhi, Scheme::Iast),
+            "dharma"
+        );
+
+        for word in ["rAmaH", "Darma", "saMtaH", "gaNgA", "Siva"] {
+            let gur = transliterate(word, Scheme::Slp1, Scheme::Gurmukhi);
+            assert_eq!(transliterate(&gur, Scheme::Gurmukhi, Scheme::Slp1), word);
+        }
+    }
+
+    #[test]
+    fn test_generic_brahmic_to_slp1() {
+        // Kannada
+        assert_eq!(generic_brahmic_to_slp1("ಕರ್ನಾಟಕ"), "karnAwaka");
+        assert_eq!(generic_brahmic_to_slp1("ಸಂಸ್ಕೃತಂ"), "saMskftaM");
+        // Odia and Bengali
+        assert_eq!(generic_brahmic_to_slp1("ଧର୍ମ"), "Darma");
+        assert_eq!(generic_brahmic_to_slp1("রাম"), "rAma");
+        // Matches the hand-tuned table where the scripts agree
+        assert_eq!(
+            generic_brahmic_to_slp1("ക്ഷേത്രേ രാമഃ"),
+            transliterate("ക്ഷേത്രേ രാമഃ", Scheme::Malayalam, Scheme::Slp1)
+        );
+    }
+
+    #[test]
+    fn test_gurmukhi_addak() {
+        assert_eq!(transliterate("ਪੱਤ", Scheme::Gurmukhi, Scheme::Slp1), "patta");
+        assert_eq!(transliterate("ਅੱਖ", Scheme::Gurmukhi, Scheme::Slp1), "akKa");
+
+        for word in ["pattram", "sattvam", "akKa", "udDava", "budDa"] {
+            let gur = transliterate(word, Scheme::Slp1, Scheme::Gurmukhi);
+            assert!(gur.contains('\u{0A71}'));
+            assert_eq!(transliterate(&gur, Scheme::Gurmukhi, Scheme::Slp1), word);
+        }
+    }
+
+    #[test]
+    fn test_cached_inverse_maps() {
+        let fresh_iast = mappings::invert_map(&mappings::get_iast_to_slp1());
+        let fresh_hk = mappings::get_slp1_to_hk();
+        assert_eq!(mappings::get_slp1_to_iast_map(), &fresh_iast[..]);
+        assert_eq!(mappings::get_slp1_to_hk_map(), &fresh_hk[..]);
+
+        for text in [
+            "rAmaH",
+            "kfzRaH",
+            "SivaH zaRmuKaH",
+            "jYAnam",
+            "x",
+            "OM namaH",
+            "?!",
+        ] {
+            assert_eq!(
+                from_slp1(text, Scheme::Iast).unwrap(),
+                map_from_slp1(text, &fresh_iast)
+            );
+            assert_eq!(
+                from_slp1(text, Scheme::HarvardKyoto).unwrap(),
+                map_from_slp1(text, &fresh_hk)
+            );
+        }
+    }
+
+    #[test]
+    fn test_merge_vowels() {
+        let merge = TransliterateOptions {
+            merge_vowels: true,
+            ..Default::default()
+        };
+
+        // Kept separate by default
+        assert_eq!(transliterate("aa", Scheme::Iast, Scheme::Slp1), "aa");
+        assert_eq!(
+            transliterate("rāmaasti", Scheme::Iast, Scheme::Slp1),
+            "rAmaasti"
+        );
+
+        assert_eq!(
+            transliterate_with_options("aa", Scheme::Iast, Scheme::Slp1, &merge),
+            "A"
+        );
+        assert_eq!(
+            transliterate_with_options("rāmaasti", Scheme::Iast, Scheme::Slp1, &merge),
+            "rAmAsti"
+        );
+        assert_eq!(
+            transliterate_with_options(
+                "kaviindra puurṇa uvāca aaa",
+                Scheme::Iast,
+                Scheme::Slp1,
+                &merge
+            ),
+            "kavIndra pUrRa uvAca Aa"
+        );
+        // Only IAST input is affected
+        assert_eq!(
+            transliterate_with_options("aa", Scheme::Slp1, Scheme::Iast, &merge),
+            "aa"
+        );
+    }
+
+    #[test]
+    fn test_anusvara_nasal_letter() {
+        let nasal = TransliterateOptions {
+            anusvara_iast: AnusvaraGlyph::NasalLetter,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            transliterate("संस्कृत", Scheme::Devanagari, Scheme::Iast),
+            "saṃskṛta"
+        );
+        assert_eq!(
+            transliterate_with_options("संस्कृत", Scheme::Devanagari, Scheme::Iast, &nasal),
+            "sanskṛta"
+        );
+        assert_eq!(
+            transliterate_with_options(
+                "saMkalpaH saMyogam rAmaM",
+                Scheme::Slp1,
+                Scheme::Iast,
+                &nasal
+            ),
+            "saṅkalpaḥ samyogam rāmam"
+        );
+        assert_eq!(
+            transliterate_with_options("aMSa", Scheme::Slp1, Scheme::Iast, &nasal),
+            "anśa"
+        );
+
+        // Brahmic output keeps the anusvāra
+        assert_eq!(
+            transliterate_with_options("saMskfta", Scheme::Slp1, Scheme::Devanagari, &nasal),
+            "संस्कृत"
+        );
+    }
 }
@@ -0,0 +1,110 @@
+//! Verse (śloka) helpers
+//!
+//! A verse is split into pādas at daṇḍas (।, ॥) and line breaks. Separators
+//! keep their surrounding whitespace, so a verse can be rebuilt exactly.
+
+/// Apply `f` to every pāda of a verse and rejoin with the original separators
+///
+/// Whitespace around a separator belongs to the separator, as does whitespace
+/// at the start and end of the text, so `f` sees each pāda trimmed. Empty
+/// pādas (e.g. between "।" and a newline) are not passed to `f`.
+pub fn map_padas<F: Fn(&str) -> String>(text: &str, f: F) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut pada_start = 0;
+    let mut i = 0;
+
+    while i < text.len() {
+        let c = text[i..].chars().next().unwrap_or_default();
+        if !is_separator(c) {
+            i += c.len_utf8();
+            continue;
+        }
+
+        let sep_start = pada_start + text[pada_start..i].trim_end().len();
+        let sep_end = text[i..]
+            .char_indices()
+            .find(|&(_, c)| !is_separator(c) && !c.is_whitespace())
+            .map_or(text.len(), |(offset, _)| i + offset);
+
+        push_pada(&mut result, &text[pada_start..sep_start], &f);
+        result.push_str(&text[sep_start..sep_end]);
+        pada_start = sep_end;
+        i = sep_end;
+    }
+
+    push_pada(&mut result, &text[pada_start..], &f);
+    result
+}
+
+/// Push `f` of the trimmed pāda, keeping the whitespace around it as is
+fn push_pada<F: Fn(&str) -> String>(result: &mut String, pada: &str, f: &F) {
+    let trimmed = pada.trim();
+    if trimmed.is_empty() {
+        result.push_str(pada);
+        return;
+    }
+
+    let start = pada.len() - pada.trim_start().len();
+    result.push_str(&pada[..start]);
+    result.push_str(&f(trimmed));
+    result.push_str(&pada[start + trimmed.len()..]);
+}
+
+fn is_separator(c: char) -> bool {
+    matches!(c, '।' | '॥' | '\n' | '\r')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GITA_1_1: &str = "धर्मक्षेत्रे कुरुक्षेत्रे समवेता युयुत्सवः ।\n\
+                            मामकाः पाण्डवाश्चैव किमकुर्वत सञ्जय ॥ १ ॥\n";
+
+    #[test]
+    fn test_identity_round_trip() {
+        assert_eq!(map_padas(GITA_1_1, |p| p.to_string()), GITA_1_1);
+        assert_eq!(map_padas("", |p| p.to_string()), "");
+        assert_eq!(map_padas("।। a ।", |p| p.to_string()), "।। a ।");
+    }
+
+    #[test]
+    fn test_padas_are_trimmed() {
+        let padas = std::cell::RefCell::new(Vec::new());
+        map_padas(GITA_1_1, |p| {
+            padas.borrow_mut().push(p.to_string());
+            p.to_string()
+        });
+
+        assert_eq!(
+            padas.into_inner(),
+            vec![
+                "धर्मक्षेत्रे कुरुक्षेत्रे समवेता युयुत्सवः",
+                "मामकाः पाण्डवाश्चैव किमकुर्वत सञ्जय",
+                "१",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_leading_whitespace() {
+        let padas = std::cell::RefCell::new(Vec::new());
+        let text = "  rAma ।\n sItA  ";
+        let rejoined = map_padas(text, |p| {
+            padas.borrow_mut().push(p.to_string());
+            p.to_string()
+        });
+
+        assert_eq!(rejoined, text);
+        assert_eq!(padas.into_inner(), vec!["rAma", "sItA"]);
+        assert_eq!(map_padas("   ", |p| p.to_uppercase()), "   ");
+    }
+
+    #[test]
+    fn test_map_padas_transform() {
+        assert_eq!(
+            map_padas("rAmaH । sItA ॥", |p| p.to_uppercase()),
+            "RAMAH । SITA ॥"
+        );
+    }
+}
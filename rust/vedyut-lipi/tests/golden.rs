@@ -0,0 +1,134 @@
+//! Golden-file tests: the same text in every scheme, converted pairwise
+//!
+//! `tests/golden/<scheme>.txt` is named by the scheme's canonical name and
+//! holds one case per line; line n of every file is the same text. Each file
+//! is converted into every other scheme and compared line by line, and all
+//! mismatches are reported together.
+
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use vedyut_lipi::{is_supported, transliterate, Scheme};
+
+/// The cases of one golden file
+struct Golden {
+    scheme: Scheme,
+    lines: Vec<String>,
+}
+
+/// A line whose conversion differs from the golden file of the target scheme
+struct Mismatch {
+    from: Scheme,
+    to: Scheme,
+    line: usize,
+    input: String,
+    expected: String,
+    actual: String,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{} -> {}, line {}: {:?}",
+            self.from, self.to, self.line, self.input
+        )?;
+        writeln!(f, "  expected: {:?}", self.expected)?;
+        write!(f, "  actual:   {:?}", self.actual)
+    }
+}
+
+/// Load every golden file, in scheme order
+fn load_goldens() -> Vec<Golden> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/golden");
+    let entries =
+        fs::read_dir(&dir).unwrap_or_else(|e| panic!("cannot read {}: {}", dir.display(), e));
+
+    let mut goldens: Vec<Golden> = entries
+        .map(|entry| {
+            let path = entry.expect("golden directory entry").path();
+            let name = path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .unwrap_or_default();
+            let scheme = Scheme::from_str(name)
+                .unwrap_or_else(|| panic!("{} does not name a scheme", path.display()));
+            let text = fs::read_to_string(&path)
+                .unwrap_or_else(|e| panic!("cannot read {}: {}", path.display(), e));
+
+            Golden {
+                scheme,
+                lines: text.lines().map(str::to_string).collect(),
+            }
+        })
+        .collect();
+
+    goldens.sort_by_key(|golden| golden.scheme.index());
+    goldens
+}
+
+/// Convert every line of `from` and compare it with the same line of `to`
+fn compare(from: &Golden, to: &Golden) -> Vec<Mismatch> {
+    from.lines
+        .iter()
+        .zip(&to.lines)
+        .enumerate()
+        .filter_map(|(i, (input, expected))| {
+            let actual = transliterate(input, from.scheme, to.scheme);
+            (actual != *expected).then(|| Mismatch {
+                from: from.scheme,
+                to: to.scheme,
+                line: i + 1,
+                input: input.clone(),
+                expected: expected.clone(),
+                actual,
+            })
+        })
+        .collect()
+}
+
+#[test]
+fn test_golden_files_aligned() {
+    let goldens = load_goldens();
+    assert!(goldens.len() >= 2, "need at least two golden files");
+
+    let lines = goldens[0].lines.len();
+    assert!(lines > 0);
+    for golden in &goldens {
+        assert_eq!(
+            golden.lines.len(),
+            lines,
+            "{} has {} lines, expected {}",
+            golden.scheme,
+            golden.lines.len(),
+            lines
+        );
+    }
+}
+
+#[test]
+fn test_golden_pairs() {
+    let goldens = load_goldens();
+    let mut mismatches = Vec::new();
+    let mut pairs = 0;
+
+    for from in &goldens {
+        for to in &goldens {
+            if from.scheme == to.scheme || !is_supported(from.scheme, to.scheme) {
+                continue;
+            }
+            pairs += 1;
+            mismatches.extend(compare(from, to));
+        }
+    }
+
+    assert!(pairs > 0);
+    let report: Vec<String> = mismatches.iter().map(Mismatch::to_string).collect();
+    assert!(
+        mismatches.is_empty(),
+        "{} golden mismatches in {} scheme pairs:\n{}",
+        mismatches.len(),
+        pairs,
+        report.join("\n")
+    );
+}
@@ -0,0 +1,168 @@
+//! Round-trip tests for every lossless scheme
+
+use vedyut_lipi::mappings::{
+    get_devanagari_extended_vyanjanas, get_devanagari_swaras, get_devanagari_vyanjanas,
+    get_slp1_extended_vyanjanas, get_slp1_swaras, get_slp1_to_devanagari, get_slp1_vyanjanas,
+};
+use vedyut_lipi::{transliterate, transliterate_with_options, Scheme, TransliterateOptions};
+
+const CASES: usize = 500;
+
+/// Small deterministic xorshift generator so failures are reproducible
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+
+    fn pick<'a>(&mut self, items: &[&'a str]) -> &'a str {
+        items[self.below(items.len())]
+    }
+}
+
+/// Build a random, phonotactically valid SLP1 word
+///
+/// Words are sequences of (C)(C)V syllables with an optional final M/H or
+/// consonant. Every non-initial syllable has an onset, so there is no vowel
+/// hiatus (which romanizations write ambiguously, e.g. a+i vs ai), `h` never
+/// follows a consonant (k+h vs kh), `l` never precedes a vocalic `f`/`F`
+/// (HK `lR` is vocalic ḷ), and `L` is never doubled (ITRANS `LLi` is vocalic ḷ).
+fn random_word(rng: &mut Rng) -> String {
+    let vowels = get_slp1_swaras();
+    let consonants = get_slp1_vyanjanas();
+
+    let mut word = String::new();
+    let syllables = 1 + rng.below(4);
+
+    for i in 0..syllables {
+        let onset_len = if i == 0 {
+            rng.below(3)
+        } else {
+            1 + rng.below(2)
+        };
+        let mut prev: Option<&str> = None;
+        for _ in 0..onset_len {
+            let mut c = rng.pick(&consonants);
+            while (prev.is_some() && c == "h") || (prev == Some("L") && c == "L") {
+                c = rng.pick(&consonants);
+            }
+            word.push_str(c);
+            prev = Some(c);
+        }
+
+        let mut v = rng.pick(&vowels);
+        while prev == Some("l") && (v == "f" || v == "F") {
+            v = rng.pick(&vowels);
+        }
+        word.push_str(v);
+    }
+
+    match rng.below(4) {
+        0 => word.push('M'),
+        1 => word.push('H'),
+        2 => {
+            let c = rng.pick(&consonants);
+            word.push_str(if c == "h" { "t" } else { c });
+        }
+        _ => {}
+    }
+
+    word
+}
+
+#[test]
+fn test_round_trip_all_lossless_schemes() {
+    let schemes: Vec<Scheme> = Scheme::all()
+        .into_iter()
+        .filter(|s| s.is_lossless())
+        .collect();
+    assert!(schemes.contains(&Scheme::Devanagari));
+
+    let mut rng = Rng(0x5eed_1e55_c0ff_ee01);
+    for _ in 0..CASES {
+        let words: Vec<String> = (0..1 + rng.below(3))
+            .map(|_| random_word(&mut rng))
+            .collect();
+        let input = words.join(" ");
+
+        for &scheme in &schemes {
+            let there = transliterate(&input, Scheme::Slp1, scheme);
+            let back = transliterate(&there, scheme, Scheme::Slp1);
+            assert_eq!(
+                back, input,
+                "round trip through {} failed: {:?} -> {:?} -> {:?}",
+                scheme, input, there, back
+            );
+        }
+    }
+}
+
+/// Consonant-final words must not pick up an inherent `a` on any path
+#[test]
+fn test_consonant_final_matrix() {
+    let words = [
+        ("vAk", "वाक्", "vāk"),
+        ("marut", "मरुत्", "marut"),
+        ("saMrAw", "संराट्", "saṃrāṭ"),
+    ];
+    let schwa = TransliterateOptions {
+        schwa_deletion: true,
+        ..Default::default()
+    };
+
+    for (slp1, deva, iast) in words {
+        assert_eq!(transliterate(slp1, Scheme::Slp1, Scheme::Devanagari), deva);
+        assert_eq!(transliterate(deva, Scheme::Devanagari, Scheme::Slp1), slp1);
+
+        // Direct IAST ↔ Devanagari converters
+        assert_eq!(transliterate(iast, Scheme::Iast, Scheme::Devanagari), deva);
+        assert_eq!(transliterate(deva, Scheme::Devanagari, Scheme::Iast), iast);
+
+        // Schwa deletion must leave an explicit halanta alone
+        assert_eq!(
+            transliterate_with_options(deva, Scheme::Devanagari, Scheme::Iast, &schwa),
+            iast
+        );
+
+        for scheme in Scheme::all().into_iter().filter(|s| s.is_lossless()) {
+            let there = transliterate(slp1, Scheme::Slp1, scheme);
+            assert_eq!(
+                transliterate(&there, scheme, Scheme::Slp1),
+                slp1,
+                "{} via {}",
+                slp1,
+                scheme
+            );
+        }
+    }
+}
+
+/// The per-letter table agrees with the SLP1 and Devanagari inventories
+#[test]
+fn test_slp1_to_devanagari_inventory() {
+    let slp1 = get_slp1_swaras()
+        .into_iter()
+        .chain(get_slp1_vyanjanas())
+        .chain(get_slp1_extended_vyanjanas());
+    let devanagari = get_devanagari_swaras()
+        .into_iter()
+        .chain(get_devanagari_vyanjanas())
+        .chain(get_devanagari_extended_vyanjanas());
+
+    for (letter, expected) in slp1.zip(devanagari) {
+        let c = letter.chars().next().unwrap();
+        assert_eq!(get_slp1_to_devanagari(c), Some(expected), "{}", letter);
+    }
+
+    // Vedic ḷa is not the vocalic ḷ
+    assert_eq!(get_slp1_to_devanagari('L'), Some("ळ"));
+    assert_eq!(get_slp1_to_devanagari('x'), Some("ऌ"));
+}
@@ -6,6 +6,12 @@ pub struct Dhatu {
     pub root: String,
     /// Gaṇa (verb class): bhvādi, adādi, etc.
     pub gana: Gana,
+    /// Pada (voice) the root conjugates in
+    pub pada: PadaType,
+    /// Dhātupāṭha code (e.g., "01.0001")
+    pub code: Option<String>,
+    /// Meaning as given in the dhātupāṭha (e.g., "sattāyām")
+    pub artha: Option<String>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -32,9 +38,149 @@ pub enum Gana {
     Curadi,
 }
 
+/// Pada (voice) of the tiṅ endings a dhātu takes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PadaType {
+    /// Parasmaipada (परस्मैपद)
+    Para,
+    /// Ātmanepada (आत्मनेपद)
+    Atma,
+    /// Ubhayapada (उभयपद) - takes either set of endings
+    Ubhaya,
+}
+
+impl Gana {
+    /// All gaṇas in dhātupāṭha order
+    pub const ALL: [Gana; 10] = [
+        Gana::Bhvadi,
+        Gana::Adadi,
+        Gana::Juhotyadi,
+        Gana::Divadi,
+        Gana::Svadi,
+        Gana::Tudadi,
+        Gana::Rudhadi,
+        Gana::Tanadi,
+        Gana::Kryadi,
+        Gana::Curadi,
+    ];
+
+    /// Parse a gaṇa from its roman name, number (1–10) or Devanagari name
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Option<Self> {
+        let s = s.trim();
+        let lower = s.to_lowercase();
+        Self::ALL.into_iter().find(|gana| {
+            lower == gana.name()
+                || lower == gana.name_iast()
+                || s == gana.name_devanagari()
+                || s == gana.number().to_string()
+        })
+    }
+
+    /// Roman name, e.g. "bhvadi"
+    pub fn name(&self) -> &'static str {
+        match self {
+            Gana::Bhvadi => "bhvadi",
+            Gana::Adadi => "adadi",
+            Gana::Juhotyadi => "juhotyadi",
+            Gana::Divadi => "divadi",
+            Gana::Svadi => "svadi",
+            Gana::Tudadi => "tudadi",
+            Gana::Rudhadi => "rudhadi",
+            Gana::Tanadi => "tanadi",
+            Gana::Kryadi => "kryadi",
+            Gana::Curadi => "curadi",
+        }
+    }
+
+    /// IAST name, e.g. "bhvādi"
+    pub fn name_iast(&self) -> &'static str {
+        match self {
+            Gana::Bhvadi => "bhvādi",
+            Gana::Adadi => "adādi",
+            Gana::Juhotyadi => "juhotyādi",
+            Gana::Divadi => "divādi",
+            Gana::Svadi => "svādi",
+            Gana::Tudadi => "tudādi",
+            Gana::Rudhadi => "rudhādi",
+            Gana::Tanadi => "tanādi",
+            Gana::Kryadi => "kryādi",
+            Gana::Curadi => "curādi",
+        }
+    }
+
+    /// Devanagari name, e.g. "भ्वादि"
+    pub fn name_devanagari(&self) -> &'static str {
+        match self {
+            Gana::Bhvadi => "भ्वादि",
+            Gana::Adadi => "अदादि",
+            Gana::Juhotyadi => "जुहोत्यादि",
+            Gana::Divadi => "दिवादि",
+            Gana::Svadi => "स्वादि",
+            Gana::Tudadi => "तुदादि",
+            Gana::Rudhadi => "रुधादि",
+            Gana::Tanadi => "तनादि",
+            Gana::Kryadi => "क्र्यादि",
+            Gana::Curadi => "चुरादि",
+        }
+    }
+
+    /// Gaṇa number in the dhātupāṭha (1–10)
+    pub fn number(&self) -> u8 {
+        match self {
+            Gana::Bhvadi => 1,
+            Gana::Adadi => 2,
+            Gana::Juhotyadi => 3,
+            Gana::Divadi => 4,
+            Gana::Svadi => 5,
+            Gana::Tudadi => 6,
+            Gana::Rudhadi => 7,
+            Gana::Tanadi => 8,
+            Gana::Kryadi => 9,
+            Gana::Curadi => 10,
+        }
+    }
+}
+
+impl PadaType {
+    /// Concrete padas a root with this pada type is conjugated in
+    ///
+    /// Ubhayapada expands to parasmaipada followed by ātmanepada.
+    pub fn padas(&self) -> &'static [PadaType] {
+        match self {
+            PadaType::Para => &[PadaType::Para],
+            PadaType::Atma => &[PadaType::Atma],
+            PadaType::Ubhaya => &[PadaType::Para, PadaType::Atma],
+        }
+    }
+}
+
 impl Dhatu {
+    /// Create a parasmaipada dhātu
     pub fn new(root: String, gana: Gana) -> Self {
-        Self { root, gana }
+        Self::with_pada(root, gana, PadaType::Para)
+    }
+
+    /// Create a dhātu with an explicit pada
+    pub fn with_pada(root: String, gana: Gana, pada: PadaType) -> Self {
+        Self {
+            root,
+            gana,
+            pada,
+            code: None,
+            artha: None,
+        }
+    }
+
+    /// Create a dhātu with its dhātupāṭha code and meaning
+    ///
+    /// The metadata tells homophonous roots apart, e.g. the two `div` roots.
+    pub fn with_meta(root: String, gana: Gana, pada: PadaType, code: &str, artha: &str) -> Self {
+        Self {
+            code: Some(code.to_string()),
+            artha: Some(artha.to_string()),
+            ..Self::with_pada(root, gana, pada)
+        }
     }
 }
 
@@ -47,5 +193,60 @@ mod tests {
         let dhatu = Dhatu::new("भू".to_string(), Gana::Bhvadi);
         assert_eq!(dhatu.root, "भू");
         assert_eq!(dhatu.gana, Gana::Bhvadi);
+        assert_eq!(dhatu.pada, PadaType::Para);
+        assert_eq!(dhatu.code, None);
+        assert_eq!(dhatu.artha, None);
+    }
+
+    #[test]
+    fn test_dhatu_meta() {
+        let krida = Dhatu::with_meta(
+            "दिव्".to_string(),
+            Gana::Divadi,
+            PadaType::Para,
+            "04.0001",
+            "krīḍāvijigīṣāvyavahāradyutistutimodamadasvapnakāntigatiṣu",
+        );
+        let mardana = Dhatu::with_meta(
+            "दिव्".to_string(),
+            Gana::Curadi,
+            PadaType::Para,
+            "10.0200",
+            "mardane",
+        );
+
+        assert_eq!(krida.code.as_deref(), Some("04.0001"));
+        assert_eq!(mardana.artha.as_deref(), Some("mardane"));
+        assert_eq!(krida.root, mardana.root);
+        assert_ne!(krida, mardana);
+    }
+
+    #[test]
+    fn test_gana_from_str() {
+        assert_eq!(Gana::from_str("bhvadi"), Some(Gana::Bhvadi));
+        assert_eq!(Gana::from_str("Bhvādi"), Some(Gana::Bhvadi));
+        assert_eq!(Gana::from_str("1"), Some(Gana::Bhvadi));
+        assert_eq!(Gana::from_str("भ्वादि"), Some(Gana::Bhvadi));
+        assert_eq!(Gana::from_str("10"), Some(Gana::Curadi));
+        assert_eq!(Gana::from_str("क्र्यादि"), Some(Gana::Kryadi));
+        assert_eq!(Gana::from_str("tudadi"), Some(Gana::Tudadi));
+        assert_eq!(Gana::from_str("0"), None);
+        assert_eq!(Gana::from_str("11"), None);
+        assert_eq!(Gana::from_str("unknown"), None);
+    }
+
+    #[test]
+    fn test_gana_round_trip() {
+        for (i, gana) in Gana::ALL.into_iter().enumerate() {
+            assert_eq!(gana.number() as usize, i + 1);
+            assert_eq!(Gana::from_str(gana.name()), Some(gana));
+            assert_eq!(Gana::from_str(gana.name_devanagari()), Some(gana));
+        }
+    }
+
+    #[test]
+    fn test_ubhaya_padas() {
+        let dhatu = Dhatu::with_pada("यज्".to_string(), Gana::Bhvadi, PadaType::Ubhaya);
+        assert_eq!(dhatu.pada.padas(), &[PadaType::Para, PadaType::Atma]);
     }
 }
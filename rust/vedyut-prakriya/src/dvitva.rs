@@ -0,0 +1,74 @@
+//! Reduplication (dvitva) shared by liṭ and the sanādi suffixes
+
+use crate::sanadi::is_vowel;
+
+/// Reduplicated syllable (abhyāsa) of a root
+///
+/// Halādiḥ śeṣaḥ (7.4.60) and śarpūrvāḥ khayaḥ (7.4.61) keep one consonant
+/// (none for a vowel-initial root), changed by kuhoś cuḥ (7.4.62) and abhyāse
+/// carca (8.4.54). Hrasvaḥ (7.4.59) shortens the vowel, an ec vowel to its ik
+/// (1.1.48), and ṛ becomes a (7.4.66 ur at). `vowel` then applies the rules
+/// of the suffix, e.g. sany ataḥ (7.4.79) for san: kf → ci.
+pub(crate) fn abhyasa(root: &str, vowel: impl Fn(char) -> char) -> String {
+    let chars: Vec<char> = root.chars().collect();
+
+    let consonant = match chars.as_slice() {
+        [s, c, ..] if matches!(s, 'S' | 'z' | 's') && is_khay(*c) => Some(*c),
+        [c, ..] if !is_vowel(*c) => Some(*c),
+        _ => None,
+    };
+
+    let short = match chars.iter().copied().find(|&c| is_vowel(c)).unwrap_or('a') {
+        'i' | 'I' | 'e' | 'E' => 'i',
+        'u' | 'U' | 'o' | 'O' => 'u',
+        _ => 'a',
+    };
+
+    consonant
+        .map(abhyasa_consonant)
+        .into_iter()
+        .chain([vowel(short)])
+        .collect()
+}
+
+/// Kuhoś cuḥ (7.4.62) and abhyāse carca (8.4.54)
+pub(crate) fn abhyasa_consonant(c: char) -> char {
+    match c {
+        'k' | 'K' => 'c',
+        'g' | 'G' | 'h' => 'j',
+        'C' => 'c',
+        'J' => 'j',
+        'W' => 'w',
+        'Q' => 'q',
+        'T' => 't',
+        'D' => 'd',
+        'P' => 'p',
+        'B' => 'b',
+        other => other,
+    }
+}
+
+fn is_khay(c: char) -> bool {
+    matches!(c, 'k' | 'K' | 'c' | 'C' | 'w' | 'W' | 't' | 'T' | 'p' | 'P')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_abhyasa() {
+        let same = |v| v;
+        assert_eq!(abhyasa("kf", same), "ca");
+        assert_eq!(abhyasa("nI", same), "ni");
+        assert_eq!(abhyasa("sTA", same), "ta");
+        assert_eq!(abhyasa("Bid", same), "bi");
+        assert_eq!(abhyasa("iz", same), "i");
+        assert_eq!(abhyasa("f", same), "a");
+
+        // Sany ataḥ (7.4.79)
+        let san = |v| if v == 'a' { 'i' } else { v };
+        assert_eq!(abhyasa("kf", san), "ci");
+        assert_eq!(abhyasa("BU", san), "bu");
+    }
+}
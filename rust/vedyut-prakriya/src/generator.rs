@@ -1,7 +1,14 @@
-use crate::dhatu::Gana;
+use crate::dhatu::{Gana, PadaType};
 /// Word generation following Pāṇinian grammar
+use crate::lakara::SuffixType;
+use crate::lit::generate_lit;
+use crate::lun::generate_lun;
+use crate::tag::allows_guna;
+use crate::term::Term;
+use crate::vowels::guna;
 use crate::{Dhatu, Lakara};
 use vedyut_lipi::{transliterate, Scheme};
+use vedyut_sandhi::apply_sandhi;
 
 /// Generate tiṅanta (verb form) from dhātu
 ///
@@ -12,53 +19,276 @@ use vedyut_lipi::{transliterate, Scheme};
 /// * `vacana` - Number (singular, dual, plural)
 ///
 /// # Returns
-/// Generated verb form (in Devanagari)
+/// Generated verb form (in Devanagari). Ubhayapada roots yield the
+/// parasmaipada form; use [`generate_tinanta_all_padas`] to get both.
 pub fn generate_tinanta(dhatu: &Dhatu, lakara: Lakara, purusha: Purusha, vacana: Vacana) -> String {
-    // 1. Transliterate root to SLP1 for processing
-    // Assuming input is Devanagari for now, but ideally Dhatu struct should track script or normalize
+    let pada = dhatu.pada.padas()[0];
+    generate_tinanta_in_pada(dhatu, lakara, pada, purusha, vacana)
+}
+
+/// Generate the tiṅanta in every pada the dhātu takes
+///
+/// # Returns
+/// One form for parasmaipada/ātmanepada roots, and the parasmaipada form
+/// followed by the ātmanepada form for ubhayapada roots (in Devanagari)
+pub fn generate_tinanta_all_padas(
+    dhatu: &Dhatu,
+    lakara: Lakara,
+    purusha: Purusha,
+    vacana: Vacana,
+) -> Vec<String> {
+    dhatu
+        .pada
+        .padas()
+        .iter()
+        .map(|&pada| generate_tinanta_in_pada(dhatu, lakara, pada, purusha, vacana))
+        .collect()
+}
+
+/// The nine forms of a lakāra in one pada: a row per purusha (prathama,
+/// madhyama, uttama) and a column per vacana
+pub type Paradigm = [[String; 3]; 3];
+
+/// Generate the parasmaipada and ātmanepada paradigms of a dhātu (in
+/// Devanagari)
+///
+/// # Returns
+/// `(parasmaipada, ātmanepada)`, with `None` for a pada the dhātu does not take
+pub fn generate_tinanta_both(
+    dhatu: &Dhatu,
+    lakara: Lakara,
+) -> (Option<Paradigm>, Option<Paradigm>) {
+    let table = |pada: PadaType| {
+        dhatu.pada.padas().contains(&pada).then(|| {
+            Purusha::all().map(|purusha| {
+                Vacana::all()
+                    .map(|vacana| generate_tinanta_in_pada(dhatu, lakara, pada, purusha, vacana))
+            })
+        })
+    };
+
+    (table(PadaType::Para), table(PadaType::Atma))
+}
+
+/// Generate tiṅanta with upasargas (verbal prefixes)
+///
+/// Upasargas are given in the same script as the root (Devanagari) and in
+/// their surface order, e.g. `["आ"]` + गम् → आगच्छति. Each upasarga is joined
+/// to the verb with junction sandhi, and prefixes that change the pada of
+/// `sthā`/`gam` select ātmanepada endings.
+pub fn generate_tinanta_with_upasarga(
+    upasargas: &[&str],
+    dhatu: &Dhatu,
+    lakara: Lakara,
+    purusha: Purusha,
+    vacana: Vacana,
+) -> String {
     let root_slp1 = transliterate(&dhatu.root, Scheme::Devanagari, Scheme::Slp1);
+    let upasargas_slp1: Vec<String> = upasargas
+        .iter()
+        .map(|u| transliterate(u, Scheme::Devanagari, Scheme::Slp1))
+        .collect();
 
-    if dhatu.gana != Gana::Bhvadi {
-        return format!("[Unsupported Gana: {:?}]", dhatu.gana);
+    let pada = upasarga_pada(&upasargas_slp1, &root_slp1).unwrap_or(dhatu.pada.padas()[0]);
+    let verb = generate_tinanta_in_pada(dhatu, lakara, pada, purusha, vacana);
+
+    upasargas
+        .iter()
+        .rev()
+        .fold(verb, |form, upasarga| apply_sandhi(upasarga, &form))
+}
+
+/// Pada forced by an upasarga on specific roots (SLP1 input)
+fn upasarga_pada(upasargas: &[String], root: &str) -> Option<PadaType> {
+    let has = |u: &str| upasargas.iter().any(|x| x == u);
+
+    match root {
+        // Samavapravibhyaḥ sthaḥ (1.3.22)
+        "sTA" if has("sam") || has("ava") || has("pra") || has("vi") => Some(PadaType::Atma),
+        // Samo gamyṛcchi... (1.3.29)
+        "gam" if has("sam") => Some(PadaType::Atma),
+        _ => None,
     }
+}
 
-    // 2. Process based on Lakara (Only Lat supported in this simplified version)
-    let result_slp1 = match lakara {
-        Lakara::Lat => generate_lat(&root_slp1, purusha, vacana),
-        _ => format!("[Unsupported Lakara: {:?}]", lakara),
+fn generate_tinanta_in_pada(
+    dhatu: &Dhatu,
+    lakara: Lakara,
+    pada: PadaType,
+    purusha: Purusha,
+    vacana: Vacana,
+) -> String {
+    // 1. Transliterate root to SLP1 for processing
+    // Assuming input is Devanagari for now, but ideally Dhatu struct should track script or normalize
+    let root_slp1 = transliterate(&dhatu.root, Scheme::Devanagari, Scheme::Slp1);
+
+    // 2. Build the aṅga for the lakāra's suffix type, then add the endings
+    // (Lat, Lin, and parasmaipada Lit and s-aorist Lun supported in this
+    // simplified version)
+    let result_slp1 = match lakara.suffix_type() {
+        SuffixType::Sarvadhatuka => {
+            let Some(anga) = present_anga(&root_slp1, dhatu.gana) else {
+                return format!("[Unsupported Gana: {:?}]", dhatu.gana);
+            };
+            match lakara {
+                Lakara::Lat => generate_lat(&anga, pada, purusha, vacana),
+                Lakara::Lin => generate_lin(&anga, pada, purusha, vacana),
+                _ => format!("[Unsupported Lakara: {:?}]", lakara),
+            }
+        }
+        // Ārdhadhātuka suffixes take no vikaraṇa, so any gaṇa will do
+        SuffixType::Ardhadhatuka => match (lakara, pada) {
+            (Lakara::Lit, PadaType::Para) => generate_lit(&root_slp1, purusha, vacana)
+                .unwrap_or_else(|| format!("[Unsupported Lit root: {}]", root_slp1)),
+            (Lakara::Lun, PadaType::Para) => generate_lun(&root_slp1, purusha, vacana)
+                .unwrap_or_else(|| format!("[Unsupported Lun root: {}]", root_slp1)),
+            _ => format!("[Unsupported Lakara: {:?}]", lakara),
+        },
     };
 
     // 3. Transliterate back to Devanagari
     transliterate(&result_slp1, Scheme::Slp1, Scheme::Devanagari)
 }
 
-fn generate_lat(root: &str, purusha: Purusha, vacana: Vacana) -> String {
-    // Simplified Bhvadi-class Lat Generator
+/// Present stem of a dhātu: the aṅga after the gaṇa vikaraṇa and before the
+/// endings (SLP1), e.g. भू → `Bava`, दिव् → `dIvya`, तुद् → `tuda`
+///
+/// # Returns
+/// `None` for gaṇas other than bhvādi, divādi and tudādi, whose vikaraṇas are
+/// not implemented
+pub fn present_stem(dhatu: &Dhatu) -> Option<String> {
+    let root_slp1 = transliterate(&dhatu.root, Scheme::Devanagari, Scheme::Slp1);
+    present_anga(&root_slp1, dhatu.gana)
+}
 
-    // Step 1: Handle irregular roots (Adesha)
-    let base = match root {
-        "gam" => "gacC", // gam -> gaccha
-        "pA" => "pib",   // paa -> piba
-        "Sru" => "SfR",  // shru -> shrNo? svadi
-        _ => root,
+/// Aṅga before a sārvadhātuka ending, for the gaṇas with an implemented vikaraṇa
+fn present_anga(root: &str, gana: Gana) -> Option<String> {
+    let vikarana = vikarana(gana)?;
+    match gana {
+        Gana::Divadi => Some(divadi_anga(root)),
+        Gana::Tudadi => Some(tudadi_anga(root)),
+        _ => Some(bhvadi_anga(root, &vikarana)),
+    }
+}
+
+/// The vikaraṇa of a gaṇa, with its it tags
+///
+/// Kartari śap (3.1.68), divādibhyaḥ śyan (3.1.69), tudādibhyaḥ śaḥ (3.1.77).
+fn vikarana(gana: Gana) -> Option<Term> {
+    let upadesha = match gana {
+        Gana::Bhvadi => "Sap",
+        Gana::Divadi => "Syan",
+        Gana::Tudadi => "Sa",
+        _ => return None,
     };
+    Some(Term::pratyaya(upadesha))
+}
 
-    // Step 2: Apply Guna to the root vowel (or penultimate short vowel)
-    let gunated_root = apply_guna(base);
+/// Roots whose vowel is lengthened before śyan (7.3.74 śamām aṣṭānāṃ dīrghaḥ śyani)
+const SAMADI: &[&str] = &["Sam", "tam", "dam", "Sram", "Bram", "kzam", "klam", "mad"];
+
+/// Tudādi roots that take a nasal before śa (7.1.59 śe mucādīnām), with the
+/// nasal already in place
+const MUCADI: &[(&str, &str)] = &[
+    ("muc", "muYc"),
+    ("lup", "lump"),
+    ("vid", "vind"),
+    ("lip", "limp"),
+    ("sic", "siYc"),
+    ("kft", "kfnt"),
+    ("Kid", "Kind"),
+    ("piS", "piMS"),
+];
+
+/// Build the Divādi-class aṅga (root + śyan)
+///
+/// Divādibhyaḥ śyan (3.1.69). Śyan is apit and so ṅit-like (1.2.4), and the
+/// root takes no guṇa (1.1.5).
+fn divadi_anga(root: &str) -> String {
+    let chars: Vec<char> = root.chars().collect();
+
+    let stem = match chars.as_slice() {
+        [.., 'a', last] if SAMADI.contains(&root) => {
+            format!("{}A{}", &root[..root.len() - 2], last)
+        }
+        // Hali ca (8.2.77): i/u before a final r or v is long before the y
+        [.., v @ ('i' | 'u'), last @ ('r' | 'v')] => {
+            let long = if *v == 'i' { 'I' } else { 'U' };
+            format!("{}{}{}", &root[..root.len() - 2], long, last)
+        }
+        // Otaḥ śyani (7.3.71): So → Sya
+        [.., 'o'] => root[..root.len() - 1].to_string(),
+        // Ṝta id dhātoḥ (7.1.100), then hali ca (8.2.77): jF → jIr
+        [.., 'F'] => format!("{}Ir", &root[..root.len() - 1]),
+        _ => root.to_string(),
+    };
+
+    format!("{}ya", stem)
+}
+
+/// Build the Tudādi-class aṅga (root + śa)
+///
+/// Tudādibhyaḥ śaḥ (3.1.77). Śa is ṅit-like (1.2.4), so there is no guṇa;
+/// a final i/u becomes iy/uv (6.4.77 aci śnudhātubhruvāṃ yvor iyaṅuvaṅau),
+/// ṛ becomes riy (7.4.28 riṅ śayagliṅkṣu) and ṝ becomes ir (7.1.100 ṝta id
+/// dhātoḥ).
+fn tudadi_anga(root: &str) -> String {
+    let stem = match root {
+        // Iṣugamiyamāṃ chaḥ (7.3.77)
+        "iz" => "icC".to_string(),
+        // Grahijyā... (6.1.16): samprasāraṇa of prac before the ṅit śa
+        "pracC" => "pfcC".to_string(),
+        _ => match MUCADI.iter().find(|(plain, _)| *plain == root) {
+            Some((_, nasal)) => nasal.to_string(),
+            None => root.to_string(),
+        },
+    };
+
+    let stem = match stem.chars().last() {
+        Some('i' | 'I') => format!("{}iy", &stem[..stem.len() - 1]),
+        Some('u' | 'U') => format!("{}uv", &stem[..stem.len() - 1]),
+        Some('f') => format!("{}riy", &stem[..stem.len() - 1]),
+        Some('F') => format!("{}ir", &stem[..stem.len() - 1]),
+        _ => stem,
+    };
+
+    format!("{}a", stem)
+}
+
+/// Build the Bhvadi-class aṅga (root + guṇa + śap) shared by the sārvadhātuka lakāras
+///
+/// Kartari śap (3.1.68) and guṇa before a sārvadhātuka suffix (7.3.84), as
+/// allowed by the tags of `vikarana`.
+fn bhvadi_anga(root: &str, vikarana: &Term) -> String {
+    // Step 1: Handle irregular roots (Adesha)
+    // Substitutes already have their final shape, so they skip guna.
+    let gunated_root = match root {
+        "gam" => "gacC".to_string(), // gam -> gaccha
+        "pA" => "pib".to_string(),   // paa -> piba
+        "sTA" => "tizW".to_string(), // sthaa -> tishTha (7.3.78)
+        "Sru" => "SfR".to_string(),  // shru -> shrNo? svadi
+        // Step 2: Apply Guna to the root vowel (or penultimate short vowel)
+        // Śap is pit, so it lets the root take guṇa
+        _ if allows_guna(&vikarana.tags, true) => apply_guna(root),
+        _ => root.to_string(),
+    };
 
     // Step 3: Add 'a' (Shap) and apply Sandhi
-    let anga = apply_shap(&gunated_root);
+    apply_shap(&gunated_root)
+}
+
+fn generate_lat(anga: &str, pada: PadaType, purusha: Purusha, vacana: Vacana) -> String {
+    // Simplified Bhvadi-class Lat Generator
+    if pada == PadaType::Atma {
+        return lat_atmanepada(anga, purusha, vacana);
+    }
 
     // Step 4: Add Tin ending
     match (purusha, vacana) {
         (Purusha::Prathama, Vacana::Eka) => format!("{}ti", anga),
         (Purusha::Prathama, Vacana::Dvi) => format!("{}taH", anga),
         (Purusha::Prathama, Vacana::Bahu) => {
-            let base = if anga.ends_with('a') {
-                &anga[..anga.len() - 1]
-            } else {
-                &anga
-            };
+            let base = anga.strip_suffix('a').unwrap_or(anga);
             format!("{}anti", base)
         }
 
@@ -67,67 +297,122 @@ fn generate_lat(root: &str, purusha: Purusha, vacana: Vacana) -> String {
         (Purusha::Madhyama, Vacana::Bahu) => format!("{}Ta", anga),
 
         (Purusha::Uttama, Vacana::Eka) => {
-            let base = if anga.ends_with('a') {
-                format!("{}A", &anga[..anga.len() - 1])
-            } else {
-                anga.clone()
+            let base = match anga.strip_suffix('a') {
+                Some(stem) => format!("{}A", stem),
+                None => anga.to_string(),
             };
             format!("{}mi", base)
         }
         (Purusha::Uttama, Vacana::Dvi) => {
-            let base = if anga.ends_with('a') {
-                format!("{}A", &anga[..anga.len() - 1])
-            } else {
-                anga.clone()
+            let base = match anga.strip_suffix('a') {
+                Some(stem) => format!("{}A", stem),
+                None => anga.to_string(),
             };
             format!("{}vaH", base)
         }
         (Purusha::Uttama, Vacana::Bahu) => {
-            let base = if anga.ends_with('a') {
-                format!("{}A", &anga[..anga.len() - 1])
-            } else {
-                anga.clone()
+            let base = match anga.strip_suffix('a') {
+                Some(stem) => format!("{}A", stem),
+                None => anga.to_string(),
             };
             format!("{}maH", base)
         }
     }
 }
 
-fn apply_guna(root: &str) -> String {
-    let vowels = ["i", "I", "u", "U", "f", "F", "x", "X"];
+/// Laṭ ātmanepada endings after an a-final aṅga
+///
+/// The ṭit endings take `e` for their final vowel (3.4.79 ṭita ātmanepadānāṃ ṭer e),
+/// and the `A` of AtAm/ATAm becomes `iy` (7.2.81 āto ṅitaḥ), giving `e` after the aṅga.
+fn lat_atmanepada(anga: &str, purusha: Purusha, vacana: Vacana) -> String {
+    let stem = anga.strip_suffix('a').unwrap_or(anga);
 
-    if let Some(c) = root.chars().last() {
-        let c_str = c.to_string();
-        if vowels.contains(&c_str.as_str()) {
-            let base = &root[..root.len() - c.len_utf8()];
-            let gunated_vowel = match c_str.as_str() {
-                "i" | "I" => "e",
-                "u" | "U" => "o",
-                "f" | "F" => "ar",
-                "x" | "X" => "al",
-                _ => &c_str,
-            };
-            return format!("{}{}", base, gunated_vowel);
-        }
+    match (purusha, vacana) {
+        (Purusha::Prathama, Vacana::Eka) => format!("{}ate", stem),
+        (Purusha::Prathama, Vacana::Dvi) => format!("{}ete", stem),
+        (Purusha::Prathama, Vacana::Bahu) => format!("{}ante", stem),
+
+        (Purusha::Madhyama, Vacana::Eka) => format!("{}ase", stem),
+        (Purusha::Madhyama, Vacana::Dvi) => format!("{}eTe", stem),
+        (Purusha::Madhyama, Vacana::Bahu) => format!("{}aDve", stem),
+
+        // iṭ -> e, and a + e is pararūpa (6.1.97 ato guṇe)
+        (Purusha::Uttama, Vacana::Eka) => format!("{}e", stem),
+        (Purusha::Uttama, Vacana::Dvi) => format!("{}Avahe", stem),
+        (Purusha::Uttama, Vacana::Bahu) => format!("{}Amahe", stem),
+    }
+}
+
+fn generate_lin(anga: &str, pada: PadaType, purusha: Purusha, vacana: Vacana) -> String {
+    // Simplified Bhvadi-class Vidhi-lin Generator
+    //
+    // Liṅ takes the secondary (ṅit) endings with the yāsuṭ augment (3.4.103).
+    // After an a-final aṅga, yās becomes `iy` (7.2.80 ato yeyaḥ), `a` + `i`
+    // gives guṇa `e` (6.1.87), and the `y` is dropped before a consonant
+    // (6.1.66 lopo vyor vali). Non-thematic classes would keep `yāt` instead.
+    let base = match anga.strip_suffix('a') {
+        Some(stem) => format!("{}e", stem),
+        None => anga.to_string(),
+    };
+
+    if pada == PadaType::Atma {
+        return lin_atmanepada(&base, purusha, vacana);
+    }
+
+    match (purusha, vacana) {
+        (Purusha::Prathama, Vacana::Eka) => format!("{}t", base),
+        (Purusha::Prathama, Vacana::Dvi) => format!("{}tAm", base),
+        // jhi -> jus (3.4.108 jher jus), the `y` survives before the vowel
+        (Purusha::Prathama, Vacana::Bahu) => format!("{}yuH", base),
+
+        (Purusha::Madhyama, Vacana::Eka) => format!("{}H", base),
+        (Purusha::Madhyama, Vacana::Dvi) => format!("{}tam", base),
+        (Purusha::Madhyama, Vacana::Bahu) => format!("{}ta", base),
+
+        // mip -> am (3.4.101 tasthasthamipāṃ tāṃtaṃtāmaḥ)
+        (Purusha::Uttama, Vacana::Eka) => format!("{}yam", base),
+        (Purusha::Uttama, Vacana::Dvi) => format!("{}va", base),
+        (Purusha::Uttama, Vacana::Bahu) => format!("{}ma", base),
+    }
+}
+
+/// Vidhi-liṅ ātmanepada endings after the guṇa-ed `e` base
+///
+/// Ātmanepada takes sīyuṭ (3.4.102) whose `s` is dropped (7.2.79 liṅaḥ salopo 'nantyasya),
+/// jha -> ran (3.4.105), and iṭ -> a (3.4.106).
+fn lin_atmanepada(base: &str, purusha: Purusha, vacana: Vacana) -> String {
+    match (purusha, vacana) {
+        (Purusha::Prathama, Vacana::Eka) => format!("{}ta", base),
+        (Purusha::Prathama, Vacana::Dvi) => format!("{}yAtAm", base),
+        (Purusha::Prathama, Vacana::Bahu) => format!("{}ran", base),
+
+        (Purusha::Madhyama, Vacana::Eka) => format!("{}TAH", base),
+        (Purusha::Madhyama, Vacana::Dvi) => format!("{}yATAm", base),
+        (Purusha::Madhyama, Vacana::Bahu) => format!("{}Dvam", base),
+
+        (Purusha::Uttama, Vacana::Eka) => format!("{}ya", base),
+        (Purusha::Uttama, Vacana::Dvi) => format!("{}vahi", base),
+        (Purusha::Uttama, Vacana::Bahu) => format!("{}mahi", base),
     }
+}
 
+/// Guṇa of a final ik vowel, or of a light penultimate one
+/// (7.3.84 sārvadhātukārdhadhātukayoḥ, 7.3.86 pugantalaghūpadhasya ca)
+pub(crate) fn apply_guna(root: &str) -> String {
     let chars: Vec<char> = root.chars().collect();
+
+    if let Some(&last) = chars.last() {
+        if matches!(last, 'i' | 'I' | 'u' | 'U' | 'f' | 'F' | 'x' | 'X') {
+            let base = &root[..root.len() - last.len_utf8()];
+            return format!("{}{}", base, guna(last));
+        }
+    }
+
     if chars.len() >= 2 {
         let penult = chars[chars.len() - 2];
-        let penult_str = penult.to_string();
-        if ["i", "u", "f", "x"].contains(&penult_str.as_str()) {
-            let gunated = match penult_str.as_str() {
-                "i" => "e",
-                "u" => "o",
-                "f" => "ar",
-                "x" => "al",
-                _ => &penult_str,
-            };
-            let mut res = String::new();
-            for i in 0..chars.len() - 2 {
-                res.push(chars[i]);
-            }
-            res.push_str(gunated);
+        if matches!(penult, 'i' | 'u' | 'f' | 'x') {
+            let mut res: String = chars[..chars.len() - 2].iter().collect();
+            res.push_str(guna(penult));
             res.push(chars[chars.len() - 1]);
             return res;
         }
@@ -137,7 +422,10 @@ fn apply_guna(root: &str) -> String {
 }
 
 fn apply_shap(root: &str) -> String {
-    if root.ends_with('e') {
+    if root.ends_with('a') {
+        // a-final derived roots absorb śap (6.1.97 ato guṇe)
+        root.to_string()
+    } else if root.ends_with('e') {
         format!("{}aya", &root[..root.len() - 1])
     } else if root.ends_with('o') {
         format!("{}ava", &root[..root.len() - 1])
@@ -156,6 +444,12 @@ pub enum Purusha {
     Uttama,
 }
 
+impl Purusha {
+    pub fn all() -> [Purusha; 3] {
+        [Purusha::Prathama, Purusha::Madhyama, Purusha::Uttama]
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Vacana {
     Eka,
@@ -163,6 +457,21 @@ pub enum Vacana {
     Bahu,
 }
 
+impl Vacana {
+    pub fn all() -> [Vacana; 3] {
+        [Vacana::Eka, Vacana::Dvi, Vacana::Bahu]
+    }
+
+    /// Lowercase roman name, as used in lexicon metadata
+    pub fn name(&self) -> &'static str {
+        match self {
+            Vacana::Eka => "eka",
+            Vacana::Dvi => "dvi",
+            Vacana::Bahu => "bahu",
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -190,6 +499,171 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_bhu_lin() {
+        let dhatu = Dhatu::new("भू".to_string(), Gana::Bhvadi);
+
+        assert_eq!(
+            generate_tinanta(&dhatu, Lakara::Lin, Purusha::Prathama, Vacana::Eka),
+            "भवेत्"
+        );
+        assert_eq!(
+            generate_tinanta(&dhatu, Lakara::Lin, Purusha::Prathama, Vacana::Dvi),
+            "भवेताम्"
+        );
+        assert_eq!(
+            generate_tinanta(&dhatu, Lakara::Lin, Purusha::Prathama, Vacana::Bahu),
+            "भवेयुः"
+        );
+        assert_eq!(
+            generate_tinanta(&dhatu, Lakara::Lin, Purusha::Uttama, Vacana::Eka),
+            "भवेयम्"
+        );
+    }
+
+    #[test]
+    fn test_atmanepada_root() {
+        let dhatu = Dhatu::with_pada("एध्".to_string(), Gana::Bhvadi, PadaType::Atma);
+
+        assert_eq!(
+            generate_tinanta(&dhatu, Lakara::Lat, Purusha::Prathama, Vacana::Eka),
+            "एधते"
+        );
+        assert_eq!(
+            generate_tinanta(&dhatu, Lakara::Lat, Purusha::Prathama, Vacana::Bahu),
+            "एधन्ते"
+        );
+        assert_eq!(
+            generate_tinanta(&dhatu, Lakara::Lat, Purusha::Uttama, Vacana::Eka),
+            "एधे"
+        );
+        assert_eq!(
+            generate_tinanta(&dhatu, Lakara::Lin, Purusha::Prathama, Vacana::Eka),
+            "एधेत"
+        );
+        assert_eq!(
+            generate_tinanta_all_padas(&dhatu, Lakara::Lat, Purusha::Prathama, Vacana::Eka),
+            vec!["एधते"]
+        );
+    }
+
+    #[test]
+    fn test_parasmaipada_root() {
+        let dhatu = Dhatu::with_pada("भू".to_string(), Gana::Bhvadi, PadaType::Para);
+        assert_eq!(
+            generate_tinanta_all_padas(&dhatu, Lakara::Lat, Purusha::Prathama, Vacana::Eka),
+            vec!["भवति"]
+        );
+    }
+
+    #[test]
+    fn test_ubhayapada_root() {
+        let dhatu = Dhatu::with_pada("यज्".to_string(), Gana::Bhvadi, PadaType::Ubhaya);
+
+        assert_eq!(
+            generate_tinanta(&dhatu, Lakara::Lat, Purusha::Prathama, Vacana::Eka),
+            "यजति"
+        );
+        assert_eq!(
+            generate_tinanta_all_padas(&dhatu, Lakara::Lat, Purusha::Prathama, Vacana::Eka),
+            vec!["यजति", "यजते"]
+        );
+        assert_eq!(
+            generate_tinanta_all_padas(&dhatu, Lakara::Lat, Purusha::Madhyama, Vacana::Dvi),
+            vec!["यजथः", "यजेथे"]
+        );
+    }
+
+    #[test]
+    fn test_generate_tinanta_both() {
+        let dhatu = Dhatu::with_pada("यज्".to_string(), Gana::Bhvadi, PadaType::Ubhaya);
+        let (para, atma) = generate_tinanta_both(&dhatu, Lakara::Lat);
+        let (para, atma) = (para.unwrap(), atma.unwrap());
+        assert_eq!(para[0], ["यजति", "यजतः", "यजन्ति"]);
+        assert_eq!(para[2][0], "यजामि");
+        assert_eq!(atma[0], ["यजते", "यजेते", "यजन्ते"]);
+        assert_eq!(atma[1][1], "यजेथे");
+
+        let dhatu = Dhatu::new("भू".to_string(), Gana::Bhvadi);
+        let (para, atma) = generate_tinanta_both(&dhatu, Lakara::Lat);
+        assert_eq!(para.unwrap()[0][0], "भवति");
+        assert_eq!(atma, None);
+    }
+
+    #[test]
+    fn test_upasarga() {
+        let gam = Dhatu::new("गम्".to_string(), Gana::Bhvadi);
+        assert_eq!(
+            generate_tinanta_with_upasarga(
+                &["आ"],
+                &gam,
+                Lakara::Lat,
+                Purusha::Prathama,
+                Vacana::Eka
+            ),
+            "आगच्छति"
+        );
+        assert_eq!(
+            generate_tinanta_with_upasarga(
+                &["उप"],
+                &gam,
+                Lakara::Lat,
+                Purusha::Prathama,
+                Vacana::Eka
+            ),
+            "उपगच्छति"
+        );
+        assert_eq!(
+            generate_tinanta_with_upasarga(
+                &["प्रति", "आ"],
+                &gam,
+                Lakara::Lat,
+                Purusha::Prathama,
+                Vacana::Bahu
+            ),
+            "प्रत्यागच्छन्ति"
+        );
+    }
+
+    #[test]
+    fn test_upasarga_stha() {
+        let stha = Dhatu::new("स्था".to_string(), Gana::Bhvadi);
+        assert_eq!(
+            generate_tinanta(&stha, Lakara::Lat, Purusha::Prathama, Vacana::Eka),
+            "तिष्ठति"
+        );
+        assert_eq!(
+            generate_tinanta_with_upasarga(
+                &["उद्"],
+                &stha,
+                Lakara::Lat,
+                Purusha::Prathama,
+                Vacana::Eka
+            ),
+            "उत्तिष्ठति"
+        );
+        // pra + sthA takes atmanepada (1.3.22)
+        assert_eq!(
+            generate_tinanta_with_upasarga(
+                &["प्र"],
+                &stha,
+                Lakara::Lat,
+                Purusha::Prathama,
+                Vacana::Eka
+            ),
+            "प्रतिष्ठते"
+        );
+    }
+
+    #[test]
+    fn test_pa_lat() {
+        let dhatu = Dhatu::new("पा".to_string(), Gana::Bhvadi);
+        assert_eq!(
+            generate_tinanta(&dhatu, Lakara::Lat, Purusha::Prathama, Vacana::Eka),
+            "पिबति"
+        );
+    }
+
     #[test]
     fn test_gam_lat() {
         let dhatu = Dhatu::new("गम्".to_string(), Gana::Bhvadi);
@@ -207,4 +681,97 @@ mod tests {
             "जयति"
         );
     }
+
+    #[test]
+    fn test_lit_any_gana() {
+        let kf = Dhatu::new("कृ".to_string(), Gana::Tanadi);
+        assert_eq!(
+            generate_tinanta(&kf, Lakara::Lit, Purusha::Prathama, Vacana::Eka),
+            "चकार"
+        );
+        assert_eq!(
+            generate_tinanta(&kf, Lakara::Lit, Purusha::Uttama, Vacana::Eka),
+            "चकार"
+        );
+
+        let as_ = Dhatu::new("अस्".to_string(), Gana::Adadi);
+        assert_eq!(
+            generate_tinanta(&as_, Lakara::Lit, Purusha::Prathama, Vacana::Eka),
+            "आस"
+        );
+        assert_eq!(
+            generate_tinanta(&as_, Lakara::Lit, Purusha::Prathama, Vacana::Bahu),
+            "आसुः"
+        );
+
+        let f = Dhatu::new("ऋ".to_string(), Gana::Juhotyadi);
+        assert_eq!(
+            generate_tinanta(&f, Lakara::Lit, Purusha::Prathama, Vacana::Eka),
+            "आर"
+        );
+    }
+
+    #[test]
+    fn test_lun_s_aorist() {
+        let ni = Dhatu::new("नी".to_string(), Gana::Bhvadi);
+        assert_eq!(
+            generate_tinanta(&ni, Lakara::Lun, Purusha::Prathama, Vacana::Eka),
+            "अनैषीत्"
+        );
+        assert_eq!(
+            generate_tinanta(&ni, Lakara::Lun, Purusha::Prathama, Vacana::Bahu),
+            "अनैषुः"
+        );
+
+        // BU takes the luk of sic (2.4.77), which is not supported
+        let bhu = Dhatu::new("भू".to_string(), Gana::Bhvadi);
+        assert!(
+            generate_tinanta(&bhu, Lakara::Lun, Purusha::Prathama, Vacana::Eka).starts_with('[')
+        );
+    }
+
+    #[test]
+    fn test_vikarana_tags() {
+        let guna = |gana| allows_guna(&vikarana(gana).unwrap().tags, true);
+        assert!(guna(Gana::Bhvadi));
+        assert!(!guna(Gana::Divadi));
+        assert!(!guna(Gana::Tudadi));
+        assert_eq!(vikarana(Gana::Adadi), None);
+    }
+
+    #[test]
+    fn test_present_stem() {
+        let stem = |root: &str, gana| present_stem(&Dhatu::new(root.to_string(), gana));
+
+        assert_eq!(stem("भू", Gana::Bhvadi).as_deref(), Some("Bava"));
+        assert_eq!(stem("दिव्", Gana::Divadi).as_deref(), Some("dIvya"));
+        assert_eq!(stem("तुद्", Gana::Tudadi).as_deref(), Some("tuda"));
+
+        assert_eq!(stem("नश्", Gana::Divadi).as_deref(), Some("naSya"));
+        assert_eq!(stem("शम्", Gana::Divadi).as_deref(), Some("SAmya"));
+        assert_eq!(stem("इष्", Gana::Tudadi).as_deref(), Some("icCa"));
+        assert_eq!(stem("मुच्", Gana::Tudadi).as_deref(), Some("muYca"));
+        assert_eq!(stem("मृ", Gana::Tudadi).as_deref(), Some("mriya"));
+        assert_eq!(stem("नू", Gana::Tudadi).as_deref(), Some("nuva"));
+        assert_eq!(stem("कॄ", Gana::Tudadi).as_deref(), Some("kira"));
+        assert_eq!(stem("गॄ", Gana::Tudadi).as_deref(), Some("gira"));
+        assert_eq!(stem("जॄ", Gana::Divadi).as_deref(), Some("jIrya"));
+        assert_eq!(stem("क्री", Gana::Kryadi), None);
+
+        let div = Dhatu::new("दिव्".to_string(), Gana::Divadi);
+        assert_eq!(
+            generate_tinanta(&div, Lakara::Lat, Purusha::Prathama, Vacana::Eka),
+            "दीव्यति"
+        );
+        let tud = Dhatu::new("तुद्".to_string(), Gana::Tudadi);
+        assert_eq!(
+            generate_tinanta(&tud, Lakara::Lat, Purusha::Uttama, Vacana::Bahu),
+            "तुदामः"
+        );
+        let kf = Dhatu::new("कॄ".to_string(), Gana::Tudadi);
+        assert_eq!(
+            generate_tinanta(&kf, Lakara::Lat, Purusha::Prathama, Vacana::Eka),
+            "किरति"
+        );
+    }
 }
@@ -0,0 +1,222 @@
+//! Kṛdanta (primary derivative) generation
+
+use crate::dhatu::PadaType;
+use crate::generator::present_stem;
+use crate::term::Term;
+use crate::Dhatu;
+use vedyut_lipi::{transliterate, Scheme};
+
+/// Kṛt pratyayas supported by the generator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Krt {
+    /// Past passive participle (क्त), e.g. गत, कृत
+    Kta,
+    /// Parasmaipada present participle (शतृ), e.g. भवत्
+    Satr,
+}
+
+impl Krt {
+    /// Upadeśa of the pratyaya with its it-markers (SLP1)
+    pub fn upadesha(&self) -> &'static str {
+        match self {
+            Krt::Kta => "kta",
+            Krt::Satr => "Satf",
+        }
+    }
+
+    /// The pratyaya as a term, with the tags of its it-markers
+    pub fn term(&self) -> Term {
+        Term::pratyaya(self.upadesha())
+    }
+}
+
+/// Generate the kṛdanta stem of a dhātu
+///
+/// # Returns
+/// The prātipadika (in Devanagari), e.g. गम् + क्त → गत, भू + शतृ → भवत्
+/// (the weak stem; see [`satr_stems`] for the strong one), or `None` if the
+/// dhātu cannot take the pratyaya
+pub fn generate_krdanta(dhatu: &Dhatu, krt: Krt) -> Option<String> {
+    let root_slp1 = transliterate(&dhatu.root, Scheme::Devanagari, Scheme::Slp1);
+
+    let result_slp1 = match krt {
+        Krt::Kta => derive_kta(&root_slp1),
+        Krt::Satr => satr_stems(dhatu)?.weak,
+    };
+
+    Some(transliterate(
+        &result_slp1,
+        Scheme::Slp1,
+        Scheme::Devanagari,
+    ))
+}
+
+/// Roots ending in a consonant that do not take iṭ before kta
+///
+/// Ekāca upadeśe 'nudāttāt (7.2.10); vowel-final roots are treated as aniṭ
+/// by default (7.2.11 śryukaḥ kiti covers u/ṛ-final roots).
+const ANIT_ROOTS: &[&str] = &[
+    "gam", "yam", "ram", "nam", "man", "han", "tan", "muc", "sic", "tyaj", "Buj", "yuj", "Baj",
+    "vac", "svap",
+];
+
+/// Derive the kta stem (SLP1)
+///
+/// Kta is kit, so the root vowel takes neither guṇa nor vṛddhi (1.1.5 kṅiti ca).
+fn derive_kta(root: &str) -> String {
+    let is_anit = root.ends_with(is_vowel) || ANIT_ROOTS.contains(&root);
+
+    // Anunāsika-lopa before a jhal-initial kit suffix (6.4.37)
+    if is_anit {
+        if let Some(stem) = root.strip_suffix(['m', 'n']) {
+            return format!("{}ta", stem);
+        }
+    }
+
+    // Samprasāraṇa before a kit suffix (6.1.15 vacisvapiyajādīnāṃ kiti)
+    let root = match root {
+        "vac" => "uc",
+        "svap" => "sup",
+        "vad" => "ud",
+        // Dyatisyatimāsthām it ti kiti (7.4.40)
+        "sTA" => "sTi",
+        // Ghumāsthāgāpājahātisāṃ hali (6.4.66)
+        "pA" => "pI",
+        other => other,
+    };
+
+    if is_anit {
+        // Coḥ kuḥ (8.2.30) then khari ca (8.4.55): c/j -> k before t
+        match root.strip_suffix(['c', 'j']) {
+            Some(stem) => format!("{}kta", stem),
+            None => format!("{}ta", root),
+        }
+    } else {
+        // Ārdhadhātukasyeḍ valādeḥ (7.2.35)
+        format!("{}ita", root)
+    }
+}
+
+/// Stems of a śatṛ (parasmaipada present participle), in SLP1
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SatrStems {
+    /// Stem before the weak endings, e.g. `Bavat`
+    pub weak: String,
+    /// Stem before the sarvanāmasthāna endings, with the num augment, e.g. `Bavant`
+    pub strong: String,
+}
+
+impl SatrStems {
+    /// Masculine nominative singular, e.g. `Bavan`
+    ///
+    /// The s ending is dropped after a consonant (6.1.68 halṅyābbhyo
+    /// dīrghāt sutisyapṛktaṃ hal), and then the t closing the cluster (8.2.23
+    /// saṃyogāntasya lopaḥ).
+    pub fn nominative(&self) -> String {
+        self.strong
+            .strip_suffix('t')
+            .unwrap_or(&self.strong)
+            .to_string()
+    }
+}
+
+/// Derive the stems of the śatṛ participle (लटः शतृशानचौ, 3.2.124)
+///
+/// Śatṛ is sārvadhātuka, so it follows the present stem, whose final a
+/// absorbs the a of at (6.1.97 ato guṇe): भू → `Bava` → `Bavat`. Śatṛ is
+/// ugit, so the strong stem takes num (7.1.70 ugidacāṃ sarvanāmasthāne
+/// 'dhātoḥ): `Bavant`.
+///
+/// # Returns
+/// `None` for ātmanepada-only roots, as śatṛ replaces a parasmaipada laṭ
+/// (1.4.99 laḥ parasmaipadam), and for gaṇas whose vikaraṇa is not
+/// implemented (see [`present_stem`])
+pub fn satr_stems(dhatu: &Dhatu) -> Option<SatrStems> {
+    if !dhatu.pada.padas().contains(&PadaType::Para) {
+        return None;
+    }
+    let anga = present_stem(dhatu)?;
+    let base = anga.strip_suffix('a')?;
+
+    Some(SatrStems {
+        weak: format!("{}at", base),
+        strong: format!("{}ant", base),
+    })
+}
+
+fn is_vowel(c: char) -> bool {
+    matches!(
+        c,
+        'a' | 'A' | 'i' | 'I' | 'u' | 'U' | 'f' | 'F' | 'x' | 'X' | 'e' | 'E' | 'o' | 'O'
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dhatu::Gana;
+    use crate::tag::{allows_guna, blocks_guna, Tag};
+
+    fn kta(root: &str) -> String {
+        generate_krdanta(&Dhatu::new(root.to_string(), Gana::Bhvadi), Krt::Kta).unwrap()
+    }
+
+    #[test]
+    fn test_kta_anit() {
+        assert_eq!(kta("कृ"), "कृत");
+        assert_eq!(kta("भू"), "भूत");
+        assert_eq!(kta("मुच्"), "मुक्त");
+    }
+
+    #[test]
+    fn test_kta_anunasika_lopa() {
+        assert_eq!(kta("गम्"), "गत");
+        assert_eq!(kta("हन्"), "हत");
+    }
+
+    #[test]
+    fn test_kta_is_kit() {
+        let term = Krt::Kta.term();
+        assert_eq!(term.tags, vec![Tag::Kit]);
+        assert!(blocks_guna(&term.tags));
+        assert!(!allows_guna(&term.tags, false));
+        assert_eq!(Krt::Satr.term().tags, vec![Tag::Sit]);
+    }
+
+    #[test]
+    fn test_kta_set() {
+        assert_eq!(kta("पठ्"), "पठित");
+        assert_eq!(kta("पत्"), "पतित");
+    }
+
+    #[test]
+    fn test_satr() {
+        let stems = satr_stems(&Dhatu::new("भू".to_string(), Gana::Bhvadi)).unwrap();
+        assert_eq!(stems.weak, "Bavat");
+        assert_eq!(stems.strong, "Bavant");
+        assert_eq!(stems.nominative(), "Bavan");
+
+        let stems = satr_stems(&Dhatu::new("तुद्".to_string(), Gana::Tudadi)).unwrap();
+        assert_eq!(stems.weak, "tudat");
+        let stems = satr_stems(&Dhatu::new("गम्".to_string(), Gana::Bhvadi)).unwrap();
+        assert_eq!(stems.nominative(), "gacCan");
+
+        assert_eq!(satr_stems(&Dhatu::new("अद्".to_string(), Gana::Adadi)), None);
+
+        let edh = Dhatu::with_pada("एध्".to_string(), Gana::Bhvadi, PadaType::Atma);
+        assert_eq!(satr_stems(&edh), None);
+        assert_eq!(generate_krdanta(&edh, Krt::Satr), None);
+        let yaj = Dhatu::with_pada("यज्".to_string(), Gana::Bhvadi, PadaType::Ubhaya);
+        assert_eq!(satr_stems(&yaj).unwrap().weak, "yajat");
+
+        let bhu = Dhatu::new("भू".to_string(), Gana::Bhvadi);
+        assert_eq!(generate_krdanta(&bhu, Krt::Satr).as_deref(), Some("भवत्"));
+    }
+
+    #[test]
+    fn test_kta_stem_changes() {
+        assert_eq!(kta("वच्"), "उक्त");
+        assert_eq!(kta("स्था"), "स्थित");
+        assert_eq!(kta("वद्"), "उदित");
+    }
+}
@@ -24,7 +24,42 @@ pub enum Lakara {
     Lin,
 }
 
+/// Class of the suffixes a lakāra brings to the aṅga
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SuffixType {
+    /// Sārvadhātuka (3.4.113 tiṅśit sārvadhātukam): takes śap and the other
+    /// gaṇa vikaraṇas
+    Sarvadhatuka,
+    /// Ārdhadhātuka (3.4.114 ārdhadhātukaṃ śeṣaḥ): no gaṇa vikaraṇa
+    Ardhadhatuka,
+}
+
 impl Lakara {
+    /// Whether the aṅga is formed before sārvadhātuka or ārdhadhātuka suffixes
+    ///
+    /// Liṭ is ārdhadhātuka by 3.4.115 (liṭ ca). In luṭ, lṛṭ, luṅ and lṛṅ the
+    /// tiṅ is sārvadhātuka, but the vikaraṇa it follows (tās, sya, cli) is
+    /// ārdhadhātuka, so the root itself is treated as ārdhadhātuka. Liṅ here is
+    /// vidhiliṅ; āśīrliṅ would be ārdhadhātuka (3.4.116).
+    pub fn suffix_type(&self) -> SuffixType {
+        match self {
+            Lakara::Lat | Lakara::Lot | Lakara::Lan | Lakara::Lin | Lakara::Let => {
+                SuffixType::Sarvadhatuka
+            }
+            Lakara::Lit | Lakara::Lut | Lakara::Lrt | Lakara::Lun | Lakara::Lrn => {
+                SuffixType::Ardhadhatuka
+            }
+        }
+    }
+
+    pub fn is_sarvadhatuka(&self) -> bool {
+        self.suffix_type() == SuffixType::Sarvadhatuka
+    }
+
+    pub fn is_ardhadhatuka(&self) -> bool {
+        self.suffix_type() == SuffixType::Ardhadhatuka
+    }
+
     pub fn from_str(s: &str) -> Option<Self> {
         match s.to_lowercase().as_str() {
             "lat" | "present" => Some(Self::Lat),
@@ -41,3 +76,19 @@ impl Lakara {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_suffix_type() {
+        assert_eq!(Lakara::Lat.suffix_type(), SuffixType::Sarvadhatuka);
+        assert!(Lakara::Lot.is_sarvadhatuka());
+        assert!(Lakara::Lin.is_sarvadhatuka());
+        assert_eq!(Lakara::Lit.suffix_type(), SuffixType::Ardhadhatuka);
+        assert_eq!(Lakara::Lrt.suffix_type(), SuffixType::Ardhadhatuka);
+        assert!(Lakara::Lun.is_ardhadhatuka());
+        assert!(!Lakara::Lat.is_ardhadhatuka());
+    }
+}
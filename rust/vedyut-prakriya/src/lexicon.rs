@@ -0,0 +1,62 @@
+//! Lexicons built from generated forms
+
+use crate::generator::Vacana;
+use crate::subanta::{generate_subanta, Linga, Vibhakti};
+use vedyut_kosha::{Entry, Lexicon, SubantaEntry};
+
+/// Build a lexicon holding the full declension of each (stem, linga)
+///
+/// Every supported cell of the paradigm is stored as a [`SubantaEntry`] keyed
+/// by its SLP1 form; forms shared by several cells (e.g. `rAmAByAm`) get one
+/// entry per cell.
+pub fn build_subanta_lexicon(stems: &[(&str, Linga)]) -> Lexicon {
+    let mut lexicon = Lexicon::new();
+
+    for &(stem, linga) in stems {
+        for vibhakti in Vibhakti::all() {
+            for vacana in Vacana::all() {
+                let Some(form) = generate_subanta(stem, linga, vibhakti, vacana) else {
+                    continue;
+                };
+                let entry = SubantaEntry {
+                    stem: stem.to_string(),
+                    linga: Some(linga.name().to_string()),
+                    vibhakti: Some(vibhakti.name().to_string()),
+                    vacana: Some(vacana.name().to_string()),
+                };
+                lexicon.add(form, Entry::Subanta(entry));
+            }
+        }
+    }
+
+    lexicon
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_subanta_lexicon() {
+        let lexicon = build_subanta_lexicon(&[("rAma", Linga::Pum)]);
+
+        let entries = lexicon.lookup("rAmasya").expect("rAmasya is generated");
+        assert_eq!(
+            entries,
+            &vec![Entry::Subanta(SubantaEntry {
+                stem: "rAma".to_string(),
+                linga: Some("pum".to_string()),
+                vibhakti: Some("shashthi".to_string()),
+                vacana: Some("eka".to_string()),
+            })]
+        );
+
+        // rAmAByAm fills three cells of the dual
+        assert_eq!(lexicon.lookup("rAmAByAm").map(Vec::len), Some(3));
+    }
+
+    #[test]
+    fn test_build_subanta_lexicon_skips_unsupported() {
+        assert!(build_subanta_lexicon(&[("mati", Linga::Stri)]).is_empty());
+    }
+}
@@ -4,12 +4,38 @@
 //! It supports tiṅantas (verbs), subantas (nominals), kṛdantas, and taddhitāntas.
 
 pub mod dhatu;
+mod dvitva;
 pub mod generator;
+pub mod krdanta;
 pub mod lakara;
+pub mod lexicon;
+mod lit;
+mod lun;
+pub mod numbers;
+pub mod prakriya;
+pub mod sanadi;
+pub mod subanta;
+pub mod tag;
+pub mod term;
+pub mod tripadi;
 
-pub use dhatu::Dhatu;
-pub use generator::{generate_tinanta, Purusha, Vacana};
-pub use lakara::Lakara;
+pub use dhatu::{Dhatu, PadaType};
+pub use generator::{
+    generate_tinanta, generate_tinanta_all_padas, generate_tinanta_both,
+    generate_tinanta_with_upasarga, present_stem, Paradigm, Purusha, Vacana,
+};
+pub use krdanta::{generate_krdanta, satr_stems, Krt, SatrStems};
+pub use lakara::{Lakara, SuffixType};
+pub use lexicon::build_subanta_lexicon;
+pub use numbers::{parse_cardinal, to_sanskrit_cardinal};
+pub use prakriya::{apply_rule, Prakriya, Step};
+pub use sanadi::{derive_sanadyanta, generate_sanadyanta, Sanadi};
+pub use subanta::{analyze_subanta, generate_subanta, Linga, SubantaAnalysis, Vibhakti};
+pub use tag::{is_slp1_upadesha, it_tags, Tag};
+pub use term::Term;
+pub use tripadi::final_form;
+pub use vedyut_sandhi::vowels;
+pub use vowels::{guna, vrddhi};
 
 #[cfg(test)]
 mod tests {
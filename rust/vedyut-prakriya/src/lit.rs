@@ -0,0 +1,279 @@
+//! Liṭ (perfect) parasmaipada forms
+//!
+//! The root is doubled (6.1.8 liṭi dhātor anabhyāsasya) and takes the endings
+//! of 3.4.82 parasmaipadānāṃ ṇalatususthalathusaṇalvamāḥ. Forms are in SLP1,
+//! e.g. `kf` → `cakAra`, `as` → `Asa`.
+
+use crate::dvitva::{abhyasa, abhyasa_consonant};
+use crate::generator::{apply_guna, Purusha, Vacana};
+use crate::sanadi::is_vowel;
+use crate::vowels::vrddhi;
+
+/// Roots that never take iṭ before a liṭ ending (7.2.13 kṛsṛbhṛvṛstudrusruśruvo liṭi)
+const KRADI: &[&str] = &["kf", "sf", "Bf", "vf", "stu", "dru", "sru", "Sru"];
+
+/// How a liṭ ending grades the root vowel
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Grade {
+    /// ṇit: aco ñṇiti (7.2.115), ata upadhāyāḥ (7.2.116)
+    Vrddhi,
+    /// pit, and so not kit: guṇa by 7.3.84/7.3.86
+    Guna,
+    /// Asaṃyogāl liṭ kit (1.2.5): no guṇa or vṛddhi (1.1.5)
+    Kit,
+}
+
+/// Generate a liṭ parasmaipada form (SLP1)
+///
+/// # Returns
+/// `None` for roots ending in ā or an ec vowel, whose liṭ (ādeca upadeśe
+/// 'śiti 6.1.45, āta au ṇalaḥ 7.1.34) is not implemented
+pub(crate) fn generate_lit(root: &str, purusha: Purusha, vacana: Vacana) -> Option<String> {
+    if root.is_empty() || root.ends_with(['A', 'e', 'E', 'o', 'O']) {
+        return None;
+    }
+
+    let (ending, grade) = lit_ending(purusha, vacana);
+
+    // Ārdhadhātukasyeḍ valādeḥ (7.2.35); in liṭ every root but the krādis is seṭ
+    let ending = if ending.starts_with(|c: char| !is_vowel(c) && c != 'y') && !KRADI.contains(&root)
+    {
+        format!("i{}", ending)
+    } else {
+        ending.to_string()
+    };
+
+    // Ata ekahalmadhye 'nādeśāder liṭi (6.4.120), thali ca seṭi (6.4.121):
+    // e.g. paW → peWatuH, with no abhyāsa
+    if grade == Grade::Kit || ending == "iTa" {
+        if let Some(stem) = ekahal_madhya_e(root) {
+            return Some(format!("{}{}", stem, ending));
+        }
+    }
+
+    let stem = if root == "BU" {
+        // Bhuvo vuk luṅliṭoḥ (6.4.88)
+        "BUv".to_string()
+    } else if root == "f" {
+        // Ṛcchatyṝtām (7.4.11): guṇa even before a kit ending
+        let graded = if grade == Grade::Vrddhi { "Ar" } else { "ar" };
+        graded.to_string()
+    } else {
+        match grade {
+            Grade::Vrddhi => vrddhi_root(root),
+            Grade::Guna => apply_guna(root),
+            Grade::Kit => root.to_string(),
+        }
+    };
+    let form = format!("{}{}", before_vowel(&stem, &ending), ending);
+
+    // Bhavater aḥ (7.4.73)
+    let abhyasa = abhyasa(root, |v| if root == "BU" { 'a' } else { v });
+    if root.starts_with(is_vowel) {
+        Some(vowel_initial(root, &abhyasa, &form))
+    } else {
+        Some(format!("{}{}", abhyasa, form))
+    }
+}
+
+/// Join the abhyāsa of a vowel-initial root to the graded root and ending
+///
+/// Only the initial vowel is doubled, since halādiḥ śeṣaḥ (7.4.60) drops the
+/// consonants after it, and ṛ becomes a (7.4.66 ur at); see [`abhyasa`].
+///
+/// - An a abhyāsa is lengthened (7.4.70 ata ādeḥ) and merges with the root's
+///   a (6.1.101 akaḥ savarṇe dīrghaḥ): as → Asa, f → Ara. Before a root
+///   ending in two consonants it takes nuṭ instead (7.4.71 tasmān nuḍ
+///   dvihalaḥ): arc → Anarca, fD → AnarDa. As is not replaced by bhū (2.4.52), as in the
+///   anuprayoga after ām (3.1.40).
+/// - An i or u abhyāsa merges with the same vowel (6.1.101), becomes iy/uv
+///   before another vowel (6.4.78 abhyāsasya asavarṇe), and is lengthened
+///   before the y of iṇ (7.4.69 dīrghaḥ iṇaḥ kiti): iz → IzatuH, iyeza;
+///   i → IyatuH, iyAya.
+fn vowel_initial(root: &str, abhyasa: &str, form: &str) -> String {
+    let first = form.chars().next().unwrap_or_default();
+    let rest = &form[first.len_utf8()..];
+
+    match abhyasa {
+        "a" => {
+            // The r of ur at counts: fD → arD → AnarDa
+            let consonants = root.chars().skip(1).filter(|&c| !is_vowel(c)).count()
+                + usize::from(root.starts_with(['f', 'F']));
+            if consonants >= 2 && root.chars().skip(1).all(|c| !is_vowel(c)) {
+                format!("An{}", form)
+            } else {
+                format!("A{}", rest)
+            }
+        }
+        "i" => match first {
+            'i' | 'I' => format!("I{}", rest),
+            c if is_vowel(c) => format!("iy{}", form),
+            _ => format!("I{}", form),
+        },
+        _ => match first {
+            'u' | 'U' => format!("U{}", rest),
+            c if is_vowel(c) => format!("uv{}", form),
+            _ => format!("U{}", form),
+        },
+    }
+}
+
+/// Liṭ parasmaipada ending and the grade it gives the root
+fn lit_ending(purusha: Purusha, vacana: Vacana) -> (&'static str, Grade) {
+    match (purusha, vacana) {
+        (Purusha::Prathama, Vacana::Eka) => ("a", Grade::Vrddhi),
+        (Purusha::Prathama, Vacana::Dvi) => ("atuH", Grade::Kit),
+        (Purusha::Prathama, Vacana::Bahu) => ("uH", Grade::Kit),
+
+        (Purusha::Madhyama, Vacana::Eka) => ("Ta", Grade::Guna),
+        (Purusha::Madhyama, Vacana::Dvi) => ("aTuH", Grade::Kit),
+        (Purusha::Madhyama, Vacana::Bahu) => ("a", Grade::Kit),
+
+        // ṇal is optionally ṇit here (7.1.91 ṇal uttamo vā); this gives the
+        // vṛddhi form, cakAra beside cakara
+        (Purusha::Uttama, Vacana::Eka) => ("a", Grade::Vrddhi),
+        (Purusha::Uttama, Vacana::Dvi) => ("va", Grade::Kit),
+        (Purusha::Uttama, Vacana::Bahu) => ("ma", Grade::Kit),
+    }
+}
+
+/// Vṛddhi of a final vowel or penultimate a, else guṇa of a light penultimate
+/// ik vowel (7.3.86), e.g. kf → kAr, pac → pAc, liK → leK
+fn vrddhi_root(root: &str) -> String {
+    let chars: Vec<char> = root.chars().collect();
+
+    match chars.as_slice() {
+        [.., last] if is_vowel(*last) => {
+            format!("{}{}", &root[..root.len() - 1], vrddhi(*last))
+        }
+        [.., 'a', last] => format!("{}A{}", &root[..root.len() - 2], last),
+        _ => apply_guna(root),
+    }
+}
+
+/// Join a root-final vowel to a vowel-initial ending
+///
+/// Eco 'yavāyāvaḥ (6.1.78) after guṇa/vṛddhi; otherwise i → y (6.4.82 er
+/// anekāco 'saṃyogapūrvasya), u → uv (6.4.77) and ṛ → r (6.1.77 iko yaṇ aci).
+fn before_vowel(stem: &str, ending: &str) -> String {
+    if !ending.starts_with(is_vowel) {
+        return stem.to_string();
+    }
+    let Some(last) = stem.chars().last() else {
+        return String::new();
+    };
+    let base = &stem[..stem.len() - last.len_utf8()];
+
+    let joined = match last {
+        'e' => "ay",
+        'o' => "av",
+        'E' => "Ay",
+        'O' => "Av",
+        'i' | 'I' => "y",
+        'u' | 'U' => "uv",
+        'f' | 'F' => "r",
+        _ => return stem.to_string(),
+    };
+    format!("{}{}", base, joined)
+}
+
+/// The e-stem of a root with a between single consonants whose abhyāsa keeps
+/// the initial consonant unchanged (6.4.120), e.g. paW → peW
+fn ekahal_madhya_e(root: &str) -> Option<String> {
+    match root.chars().collect::<Vec<_>>().as_slice() {
+        [first, 'a', last]
+            if !is_vowel(*first) && !is_vowel(*last) && abhyasa_consonant(*first) == *first =>
+        {
+            Some(format!("{}e{}", first, last))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paradigm(root: &str) -> Vec<String> {
+        let mut forms = Vec::new();
+        for purusha in [Purusha::Prathama, Purusha::Madhyama, Purusha::Uttama] {
+            for vacana in Vacana::all() {
+                forms.push(generate_lit(root, purusha, vacana).unwrap());
+            }
+        }
+        forms
+    }
+
+    #[test]
+    fn test_kf_lit() {
+        assert_eq!(
+            paradigm("kf"),
+            [
+                "cakAra", "cakratuH", "cakruH", "cakarTa", "cakraTuH", "cakra", "cakAra", "cakfva",
+                "cakfma"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_as_lit() {
+        assert_eq!(
+            paradigm("as"),
+            ["Asa", "AsatuH", "AsuH", "AsiTa", "AsaTuH", "Asa", "Asa", "Asiva", "Asima"]
+        );
+    }
+
+    #[test]
+    fn test_bhu_lit() {
+        assert_eq!(
+            paradigm("BU"),
+            [
+                "baBUva",
+                "baBUvatuH",
+                "baBUvuH",
+                "baBUviTa",
+                "baBUvaTuH",
+                "baBUva",
+                "baBUva",
+                "baBUviva",
+                "baBUvima"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lit_stems() {
+        let lit = |root| generate_lit(root, Purusha::Prathama, Vacana::Eka);
+        let lit_du = |root| generate_lit(root, Purusha::Prathama, Vacana::Dvi);
+
+        assert_eq!(lit("paW").as_deref(), Some("papAWa"));
+        assert_eq!(lit_du("paW").as_deref(), Some("peWatuH"));
+        assert_eq!(lit("nI").as_deref(), Some("ninAya"));
+        assert_eq!(lit_du("nI").as_deref(), Some("ninyatuH"));
+        assert_eq!(lit("liK").as_deref(), Some("lileKa"));
+        assert_eq!(lit("dA"), None);
+    }
+
+    #[test]
+    fn test_vowel_initial_lit() {
+        assert_eq!(
+            paradigm("f"),
+            ["Ara", "AratuH", "AruH", "AriTa", "AraTuH", "Ara", "Ara", "Ariva", "Arima"]
+        );
+        assert_eq!(
+            paradigm("i"),
+            ["iyAya", "IyatuH", "IyuH", "iyayiTa", "IyaTuH", "Iya", "iyAya", "Iyiva", "Iyima"]
+        );
+
+        let lit = |root| generate_lit(root, Purusha::Prathama, Vacana::Eka);
+        let lit_du = |root| generate_lit(root, Purusha::Prathama, Vacana::Dvi);
+        assert_eq!(lit("aS").as_deref(), Some("ASa"));
+        assert_eq!(lit("iz").as_deref(), Some("iyeza"));
+        assert_eq!(lit_du("iz").as_deref(), Some("IzatuH"));
+        assert_eq!(lit("uc").as_deref(), Some("uvoca"));
+        assert_eq!(lit_du("uc").as_deref(), Some("UcatuH"));
+        assert_eq!(lit("fD").as_deref(), Some("AnarDa"));
+        assert_eq!(lit("arc").as_deref(), Some("Anarca"));
+        assert_eq!(lit_du("arc").as_deref(), Some("AnarcatuH"));
+    }
+}
@@ -0,0 +1,115 @@
+//! Luṅ (aorist) parasmaipada forms
+//!
+//! Only the s-aorist is supported: cli is replaced by sic (3.1.44 cleḥ sic)
+//! with no iṭ, so the root must be aniṭ. Forms are in SLP1, e.g. `nI` →
+//! `anEzIt`, `kf` → `akArzIt`.
+
+use crate::generator::{Purusha, Vacana};
+use crate::sanadi::is_vowel;
+use crate::vowels::vrddhi;
+
+/// Roots ending in i, ī, u or ṛ that do not take the aniṭ s-aorist
+///
+/// Caṅ replaces cli after śri, dru and sru (3.1.48 ṇiśridrusrubhyaḥ kartari
+/// caṅ). The roots excepted from aniṭ by 7.2.10 (yu, ru, kṣṇu, śī, snu, nu,
+/// kṣu, śvi, ḍī, vṛ) take iṭ, as do stu and su before a parasmaipada sic
+/// (7.2.72 stusudhūñbhyaḥ parasmaipadeṣu).
+const OTHER_AORIST: &[&str] = &[
+    "Sri", "dru", "sru", "yu", "ru", "kzRu", "SI", "snu", "nu", "Ru", "kzu", "Svi", "qI", "vf",
+    "stu", "su",
+];
+
+/// Generate a luṅ parasmaipada form of the s-aorist (SLP1)
+///
+/// Luṅ takes the aṭ augment (6.4.71 luṅlaṅlṛṅkṣv aḍ udāttaḥ) and the root
+/// vowel takes vṛddhi before sic (7.2.1 sici vṛddhiḥ parasmaipadeṣu). The s
+/// becomes ṣ after the vṛddhi vowel (8.3.59 ādeśapratyayayoḥ), and a
+/// following t or th is retroflexed (8.4.41 ṣṭunā ṣṭuḥ).
+///
+/// # Returns
+/// `None` unless the root is a single syllable starting with a consonant and
+/// ending in i, ī, u or ṛ, or if it is listed as taking another aorist type.
+/// Other roots are assumed to be aniṭ (7.2.10 ekāca upadeśe 'nudāttāt).
+pub(crate) fn generate_lun(root: &str, purusha: Purusha, vacana: Vacana) -> Option<String> {
+    let first = root.chars().next()?;
+    let last = root.chars().last()?;
+    let syllables = root.chars().filter(|&c| is_vowel(c)).count();
+    if is_vowel(first)
+        || syllables != 1
+        || !matches!(last, 'i' | 'I' | 'u' | 'f')
+        || OTHER_AORIST.contains(&root)
+    {
+        return None;
+    }
+
+    let stem = format!("{}{}", &root[..root.len() - 1], vrddhi(last));
+    let ending = match sic_ending(purusha, vacana) {
+        ending if ending.starts_with('t') => ending.replacen('t', "w", 1),
+        ending => ending.to_string(),
+    };
+
+    Some(format!("a{}z{}", stem, ending))
+}
+
+/// Parasmaipada ending after sic
+///
+/// The apṛkta endings t and s take īṭ (7.3.96 astisico 'pṛkte), and jhi
+/// becomes jus (3.4.109 sijabhyastavidibhyaś ca), whose s is visarga.
+fn sic_ending(purusha: Purusha, vacana: Vacana) -> &'static str {
+    match (purusha, vacana) {
+        (Purusha::Prathama, Vacana::Eka) => "It",
+        (Purusha::Prathama, Vacana::Dvi) => "tAm",
+        (Purusha::Prathama, Vacana::Bahu) => "uH",
+        (Purusha::Madhyama, Vacana::Eka) => "IH",
+        (Purusha::Madhyama, Vacana::Dvi) => "tam",
+        (Purusha::Madhyama, Vacana::Bahu) => "ta",
+        (Purusha::Uttama, Vacana::Eka) => "am",
+        (Purusha::Uttama, Vacana::Dvi) => "va",
+        (Purusha::Uttama, Vacana::Bahu) => "ma",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paradigm(root: &str) -> Vec<String> {
+        let mut forms = Vec::new();
+        for purusha in [Purusha::Prathama, Purusha::Madhyama, Purusha::Uttama] {
+            for vacana in Vacana::all() {
+                forms.push(generate_lun(root, purusha, vacana).unwrap());
+            }
+        }
+        forms
+    }
+
+    #[test]
+    fn test_ni_lun() {
+        assert_eq!(
+            paradigm("nI"),
+            [
+                "anEzIt", "anEzwAm", "anEzuH", "anEzIH", "anEzwam", "anEzwa", "anEzam", "anEzva",
+                "anEzma"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_other_roots_lun() {
+        let third_singular = |root| generate_lun(root, Purusha::Prathama, Vacana::Eka);
+
+        assert_eq!(third_singular("kf").as_deref(), Some("akArzIt"));
+        assert_eq!(third_singular("ji").as_deref(), Some("ajEzIt"));
+        assert_eq!(third_singular("Sru").as_deref(), Some("aSrOzIt"));
+
+        // Not the s-aorist
+        assert_eq!(third_singular("BU"), None);
+        assert_eq!(third_singular("Sri"), None);
+        assert_eq!(third_singular("gam"), None);
+        assert_eq!(third_singular("i"), None);
+        // Seṭ roots (astāvīt, avārīt, ...) and polysyllabic jāgṛ
+        for root in ["stu", "su", "nu", "Svi", "vf", "yu", "SI", "jAgf"] {
+            assert_eq!(third_singular(root), None, "{}", root);
+        }
+    }
+}
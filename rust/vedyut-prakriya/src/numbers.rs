@@ -0,0 +1,173 @@
+//! Sanskrit cardinal number words (saṅkhyā)
+//!
+//! Words are SLP1 prātipadikas in their usual citation form, e.g. `paYca`
+//! rather than the an-stem `paYcan`.
+
+use vedyut_sandhi::apply_sandhi;
+
+const UNITS: [&str; 10] = [
+    "SUnya", "eka", "dvi", "tri", "catur", "paYca", "zaw", "sapta", "azwa", "nava",
+];
+
+const TEENS: [&str; 10] = [
+    "daSa",
+    "ekAdaSa",
+    "dvAdaSa",
+    "trayodaSa",
+    "caturdaSa",
+    "paYcadaSa",
+    "zoqaSa",
+    "saptadaSa",
+    "azwAdaSa",
+    "navadaSa",
+];
+
+const TENS: [&str; 10] = [
+    "",
+    "daSa",
+    "viMSati",
+    "triMSat",
+    "catvAriMSat",
+    "paYcASat",
+    "zazwi",
+    "saptati",
+    "aSIti",
+    "navati",
+];
+
+/// Cardinal number word for `n`
+///
+/// Numbers from 101 on are formed with `aDika` ("exceeded by"), e.g. 108 →
+/// `azwADikaSata`, and thousands with `sahasra`.
+pub fn to_sanskrit_cardinal(n: u32) -> String {
+    match n {
+        0..=9 => UNITS[n as usize].to_string(),
+        10..=19 => TEENS[n as usize - 10].to_string(),
+        20..=99 => {
+            let (tens, units) = ((n / 10) as usize, (n % 10) as usize);
+            if units == 0 {
+                TENS[tens].to_string()
+            } else {
+                join_decade(decade_prefix(units, tens), TENS[tens])
+            }
+        }
+        100..=999 => with_remainder(n % 100, multiple(n / 100, "Sata")),
+        _ => with_remainder(n % 1000, multiple(n / 1000, "sahasra")),
+    }
+}
+
+/// Parse a cardinal number word (0–999) produced by [`to_sanskrit_cardinal`]
+pub fn parse_cardinal(word: &str) -> Option<u32> {
+    (0..1000).find(|&n| to_sanskrit_cardinal(n) == word)
+}
+
+/// `count` × `base`, e.g. dviSata; a count of one is left out
+fn multiple(count: u32, base: &str) -> String {
+    if count == 1 {
+        base.to_string()
+    } else {
+        join_decade(&to_sanskrit_cardinal(count), base)
+    }
+}
+
+/// Prefix `remainder` to `base` with aDika, e.g. azwa + aDika + Sata
+fn with_remainder(remainder: u32, base: String) -> String {
+    if remainder == 0 {
+        return base;
+    }
+
+    let word = to_sanskrit_cardinal(remainder);
+    let word = match word.strip_suffix(['t', 'w']) {
+        // Jhalāṃ jaśo 'nte (8.2.39)
+        Some(stem) if word.ends_with('t') => format!("{}d", stem),
+        Some(stem) => format!("{}q", stem),
+        None => word,
+    };
+    format!("{}{}", apply_sandhi(&word, "aDika"), base)
+}
+
+/// Form of the unit used before a decade (20–90)
+fn decade_prefix(units: usize, tens: usize) -> &'static str {
+    match (units, tens) {
+        (2, 2 | 3) => "dvA",
+        (3, 2 | 3) => "trayas",
+        (8, _) if tens != 9 => "azwA",
+        _ => UNITS[units],
+    }
+}
+
+/// Join a numeral to a following number word with the pada-final sandhi of compounds
+fn join_decade(prefix: &str, decade: &str) -> String {
+    let Some(next) = decade.chars().next() else {
+        return prefix.to_string();
+    };
+
+    if let Some(stem) = prefix
+        .strip_suffix('s')
+        .or_else(|| prefix.strip_suffix('r'))
+    {
+        // Visarga sandhi of final s/r (catur, trayas)
+        let final_sound = match next {
+            't' | 'T' => "s",
+            'c' | 'C' => "S",
+            'k' | 'K' | 'p' | 'P' | 'S' | 'z' | 's' => "H",
+            // Haśi ca (6.1.114): as -> o before a voiced sound
+            _ if prefix.ends_with("as") => {
+                return format!("{}o{}", &stem[..stem.len() - 1], decade)
+            }
+            _ => "r",
+        };
+        return format!("{}{}{}", stem, final_sound, decade);
+    }
+
+    if let Some(stem) = prefix.strip_suffix('w') {
+        // Jaśtva before voiced sounds, and a nasal before a nasal (8.4.45)
+        return match next {
+            'n' => format!("{}R{}", stem, decade.replacen('n', "R", 1)),
+            'k' | 'K' | 'c' | 'C' | 't' | 'T' | 'p' | 'P' | 'S' | 'z' | 's' => {
+                format!("{}w{}", stem, decade)
+            }
+            _ => format!("{}q{}", stem, decade),
+        };
+    }
+
+    apply_sandhi(prefix, decade)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_sanskrit_cardinal() {
+        assert_eq!(to_sanskrit_cardinal(0), "SUnya");
+        assert_eq!(to_sanskrit_cardinal(1), "eka");
+        assert_eq!(to_sanskrit_cardinal(16), "zoqaSa");
+        assert_eq!(to_sanskrit_cardinal(23), "trayoviMSati");
+        assert_eq!(to_sanskrit_cardinal(33), "trayastriMSat");
+        assert_eq!(to_sanskrit_cardinal(34), "catustriMSat");
+        assert_eq!(to_sanskrit_cardinal(82), "dvyaSIti");
+        assert_eq!(to_sanskrit_cardinal(86), "zaqaSIti");
+        assert_eq!(to_sanskrit_cardinal(96), "zaRRavati");
+        assert_eq!(to_sanskrit_cardinal(100), "Sata");
+        assert_eq!(to_sanskrit_cardinal(108), "azwADikaSata");
+        assert_eq!(to_sanskrit_cardinal(999), "navanavatyaDikanavaSata");
+        assert_eq!(to_sanskrit_cardinal(1000), "sahasra");
+    }
+
+    #[test]
+    fn test_parse_cardinal() {
+        for n in [1, 23, 108, 999] {
+            assert_eq!(parse_cardinal(&to_sanskrit_cardinal(n)), Some(n));
+        }
+        assert_eq!(parse_cardinal("azwADikaSata"), Some(108));
+        assert_eq!(parse_cardinal("rAma"), None);
+    }
+
+    #[test]
+    fn test_cardinals_are_unique() {
+        let words: std::collections::HashSet<String> =
+            (0..1000).map(to_sanskrit_cardinal).collect();
+        assert_eq!(words.len(), 1000);
+    }
+}
@@ -0,0 +1,246 @@
+//! A derivation as the sequence of terms joined so far
+
+use crate::term::Term;
+use crate::tripadi::final_form;
+use serde::Serialize;
+use vedyut_lipi::Scheme;
+
+/// Steps of the history listed in a mismatch message
+const HISTORY_TAIL: usize = 5;
+
+/// The terms of a derivation (SLP1), e.g. `rAma` + `s`, and the rules applied
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct Prakriya {
+    terms: Vec<Term>,
+    history: Vec<Step>,
+}
+
+/// A rule applied during a derivation
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Step {
+    /// Sutra number, e.g. "7.3.84"
+    pub rule: String,
+    /// The terms joined after the rule (SLP1)
+    pub result: String,
+}
+
+impl Prakriya {
+    /// Create an empty derivation
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a derivation from its terms, in order
+    pub fn from_terms<S: Into<String>>(terms: impl IntoIterator<Item = S>) -> Self {
+        Self {
+            terms: terms
+                .into_iter()
+                .map(|text| Term::new(&text.into()))
+                .collect(),
+            history: Vec::new(),
+        }
+    }
+
+    /// Add a term with no tags at the end
+    pub fn push(&mut self, term: &str) {
+        self.terms.push(Term::new(term));
+    }
+
+    /// Add a term at the end, e.g. a pratyaya with its tags
+    pub fn push_term(&mut self, term: Term) {
+        self.terms.push(term);
+    }
+
+    /// The terms, in order
+    pub fn terms(&self) -> &[Term] {
+        &self.terms
+    }
+
+    /// The rules applied so far, in order
+    pub fn history(&self) -> &[Step] {
+        &self.history
+    }
+
+    /// Apply `rule` by changing the terms with `f`, and record it
+    pub fn step(&mut self, rule: &str, f: impl FnOnce(&mut Vec<Term>)) {
+        f(&mut self.terms);
+        self.history.push(Step {
+            rule: rule.to_string(),
+            result: self.get_text(),
+        });
+    }
+
+    /// Apply `rule` by replacing the text of the term at `index`, and record it
+    ///
+    /// Does nothing if there is no such term.
+    pub fn set(&mut self, rule: &str, index: usize, text: &str) {
+        if index < self.terms.len() {
+            self.step(rule, |terms| terms[index].text = text.to_string());
+        }
+    }
+
+    /// The terms joined without any pada-final rule, e.g. `rAmas`
+    pub fn get_text(&self) -> String {
+        self.terms.iter().map(|term| term.text.as_str()).collect()
+    }
+
+    /// The derived word as pronounced, in `script`
+    ///
+    /// The last term ends the pada, so the pada-final rules of the tripādī
+    /// apply to it (see [`final_form`]): `rAma` + `s` → `rAmaH`, रामः.
+    pub fn final_form(&self, script: Scheme) -> String {
+        final_form(&self.get_text(), script)
+    }
+
+    /// The derivation as pretty-printed JSON: its terms with their tags and
+    /// its history
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Check the derived word (SLP1) against `expected_slp1`
+    ///
+    /// # Returns
+    /// On a mismatch, a message with the expected and actual forms, the terms
+    /// they were derived from and the last steps of the history
+    pub fn assert_final(&self, expected_slp1: &str) -> Result<(), String> {
+        let actual = self.final_form(Scheme::Slp1);
+        if actual == expected_slp1 {
+            return Ok(());
+        }
+
+        let terms: Vec<&str> = self.terms.iter().map(|term| term.text.as_str()).collect();
+        let mut message = format!(
+            "expected {}, got {}\n  terms: {}",
+            expected_slp1,
+            actual,
+            terms.join(" + ")
+        );
+        let tail = self.history.len().saturating_sub(HISTORY_TAIL);
+        for step in &self.history[tail..] {
+            message.push_str(&format!("\n  {}: {}", step.rule, step.result));
+        }
+        Err(message)
+    }
+}
+
+/// Apply one sandhi rule, named by its sutra number, to a derivation
+///
+/// The rule is tried at each junction of neighbouring terms in turn (see
+/// [`vedyut_sandhi::apply_rule`]); at the first where it applies, the two
+/// terms become one with the tags of both, and the step is recorded.
+///
+/// # Returns
+/// `false` if the sutra is unknown or applies at no junction
+pub fn apply_rule(sutra: &str, prakriya: &mut Prakriya) -> bool {
+    let terms = &prakriya.terms;
+    let Some((index, joined)) = (1..terms.len()).find_map(|i| {
+        vedyut_sandhi::apply_rule(sutra, &terms[i - 1].text, &terms[i].text)
+            .map(|joined| (i - 1, joined))
+    }) else {
+        return false;
+    };
+
+    prakriya.step(sutra.trim(), |terms| {
+        let right = terms.remove(index + 1);
+        let left = &mut terms[index];
+        left.text = joined;
+        for tag in right.tags {
+            if !left.tags.contains(&tag) {
+                left.tags.push(tag);
+            }
+        }
+    });
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_final_form() {
+        let mut prakriya = Prakriya::new();
+        prakriya.push("rAma");
+        prakriya.push("s");
+
+        assert_eq!(prakriya.get_text(), "rAmas");
+        assert_eq!(prakriya.assert_final("rAmaH"), Ok(()));
+        assert_eq!(prakriya.final_form(Scheme::Devanagari), "रामः");
+        assert_eq!(
+            Prakriya::from_terms(["vAc"]).final_form(Scheme::Devanagari),
+            "वाक्"
+        );
+    }
+
+    #[test]
+    fn test_assert_final_mismatch() {
+        let prakriya = Prakriya::from_terms(["rAma", "s"]);
+        let message = prakriya.assert_final("rAmas").unwrap_err();
+
+        assert!(message.contains("expected rAmas, got rAmaH"));
+        assert!(message.contains("terms: rAma + s"));
+    }
+
+    /// भू + शप् + तिप् → भवति
+    fn bhavati() -> Prakriya {
+        let mut prakriya = Prakriya::new();
+        prakriya.push("BU");
+        prakriya.push_term(Term::pratyaya("Sap"));
+        prakriya.push_term(Term::pratyaya("tip"));
+
+        // Tasya lopaḥ (1.3.9)
+        prakriya.set("1.3.9", 1, "a");
+        prakriya.set("1.3.9", 2, "ti");
+        // Sārvadhātukārdhadhātukayoḥ (7.3.84)
+        prakriya.set("7.3.84", 0, "Bo");
+        // Eco 'yavāyāvaḥ (6.1.78)
+        prakriya.set("6.1.78", 0, "Bav");
+        prakriya
+    }
+
+    #[test]
+    fn test_history() {
+        let prakriya = bhavati();
+        assert_eq!(prakriya.assert_final("Bavati"), Ok(()));
+
+        let rules: Vec<&str> = prakriya.history().iter().map(|s| s.rule.as_str()).collect();
+        assert_eq!(rules, vec!["1.3.9", "1.3.9", "7.3.84", "6.1.78"]);
+        assert_eq!(prakriya.history()[2].result, "Boati");
+
+        let message = prakriya.assert_final("Bavet").unwrap_err();
+        assert!(message.contains("7.3.84: Boati"));
+        assert!(message.contains("6.1.78: Bavati"));
+    }
+
+    #[test]
+    fn test_to_json() {
+        let json = bhavati().to_json().unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        let rules: Vec<&str> = value["history"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|step| step["rule"].as_str().unwrap())
+            .collect();
+        assert_eq!(rules, vec!["1.3.9", "1.3.9", "7.3.84", "6.1.78"]);
+        assert_eq!(value["terms"][1]["tags"], serde_json::json!(["Sit", "Pit"]));
+        assert_eq!(value["terms"][0]["text"], "Bav");
+    }
+
+    #[test]
+    fn test_apply_rule() {
+        let mut prakriya = Prakriya::from_terms(["deva", "indra"]);
+        assert!(apply_rule("6.1.87", &mut prakriya));
+        assert_eq!(prakriya.get_text(), "devendra");
+        assert_eq!(prakriya.terms().len(), 1);
+        assert_eq!(prakriya.history()[0].rule, "6.1.87");
+
+        let mut prakriya = Prakriya::from_terms(["deva", "indra"]);
+        assert!(!apply_rule("6.1.101", &mut prakriya));
+        assert!(!apply_rule("1.1.1", &mut prakriya));
+        assert_eq!(prakriya.terms().len(), 2);
+        assert!(prakriya.history().is_empty());
+    }
+}
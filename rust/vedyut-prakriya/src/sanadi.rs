@@ -0,0 +1,146 @@
+//! Sanādyanta (derived root) generation
+//!
+//! Roots ending in a sanādi suffix are dhātus themselves (3.1.32 sanādyantā
+//! dhātavaḥ), so the generators return a new [`Dhatu`] that can be conjugated.
+
+use crate::dhatu::Gana;
+use crate::dvitva::abhyasa;
+use crate::generator::apply_guna;
+use crate::prakriya::Prakriya;
+use crate::term::Term;
+use crate::Dhatu;
+use vedyut_lipi::{transliterate, Scheme};
+
+/// Sanādi pratyayas supported by the generator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sanadi {
+    /// Desiderative (सन्), e.g. भू → बुभूष
+    San,
+}
+
+/// Derive the sanādyanta root of a dhātu
+///
+/// The result conjugates like a bhvādi root and keeps the pada of the base root
+/// (1.3.62 pūrvavat sanaḥ), e.g. भू + सन् → बुभूष → बुभूषति.
+pub fn generate_sanadyanta(dhatu: &Dhatu, sanadi: Sanadi) -> Dhatu {
+    let derived_slp1 = derive_sanadyanta(dhatu, sanadi).get_text();
+
+    Dhatu::with_pada(
+        transliterate(&derived_slp1, Scheme::Slp1, Scheme::Devanagari),
+        Gana::Bhvadi,
+        dhatu.pada,
+    )
+}
+
+/// Derive the sanādyanta root of a dhātu step by step (SLP1)
+///
+/// The terms are the abhyāsa, the root and the suffix, e.g. भू + सन् →
+/// `bu` + `BU` + `za`, and the history lists the rules applied.
+pub fn derive_sanadyanta(dhatu: &Dhatu, secondary: Sanadi) -> Prakriya {
+    let root_slp1 = transliterate(&dhatu.root, Scheme::Devanagari, Scheme::Slp1);
+
+    match secondary {
+        Sanadi::San => derive_san(&root_slp1),
+    }
+}
+
+/// Derive the desiderative stem
+///
+/// Vowel-final roots take san without iṭ; consonant-final roots are treated as
+/// seṭ and take iṭ (7.2.35).
+fn derive_san(root: &str) -> Prakriya {
+    let mut prakriya = Prakriya::new();
+    prakriya.push(root);
+    prakriya.push_term(Term::pratyaya("san"));
+    // Tasya lopaḥ (1.3.9)
+    prakriya.set("1.3.9", 1, "sa");
+
+    if let Some(base) = root.strip_suffix('i') {
+        // Ajjhanagamāṃ sani (6.4.16), san is kit after ik (1.2.9 iko jhal)
+        prakriya.set("6.4.16", 0, &format!("{}I", base));
+    } else if let Some(base) = root.strip_suffix('u') {
+        prakriya.set("6.4.16", 0, &format!("{}U", base));
+    } else if let Some(base) = root.strip_suffix(['f', 'F']) {
+        // Ṝta id dhātoḥ (7.1.100), hali ca (8.2.77)
+        prakriya.set("7.1.100", 0, &format!("{}Ir", base));
+    } else if !root.ends_with(is_vowel) {
+        // Ārdhadhātukasyeḍ valādeḥ (7.2.35)
+        prakriya.step("7.2.35", |terms| terms.insert(1, Term::pratyaya("iw")));
+        prakriya.set("1.3.9", 1, "i");
+        // Pugantalaghūpadhasya ca (7.3.86) before the iṭ
+        prakriya.set("7.3.86", 0, &apply_guna(root));
+    }
+
+    // Ādeśapratyayayoḥ (8.3.59): the s of san is ṣ after iṇ or ku
+    let text = prakriya.get_text();
+    if text[..text.len() - "sa".len()].ends_with(is_in_or_ku) {
+        let last = prakriya.terms().len() - 1;
+        prakriya.set("8.3.59", last, "za");
+    }
+
+    // Sanyaṅoḥ (6.1.9), with sany ataḥ (7.4.79) for the abhyāsa vowel
+    if !root.starts_with(is_vowel) {
+        let abhyasa = abhyasa(root, |v| if v == 'a' { 'i' } else { v });
+        prakriya.step("6.1.9", |terms| terms.insert(0, Term::new(&abhyasa)));
+    }
+
+    // Sanliṭor jeḥ (7.3.57): ji takes its velar form after the abhyāsa
+    if root == "ji" {
+        prakriya.set("7.3.57", 1, "gI");
+    }
+
+    prakriya
+}
+
+/// The sounds after which s becomes ṣ (8.3.57 iṇkoḥ): iṇ and the velars
+fn is_in_or_ku(c: char) -> bool {
+    (is_vowel(c) && !matches!(c, 'a' | 'A')) || "hyvrlkKgGN".contains(c)
+}
+
+pub(crate) fn is_vowel(c: char) -> bool {
+    matches!(
+        c,
+        'a' | 'A' | 'i' | 'I' | 'u' | 'U' | 'f' | 'F' | 'x' | 'X' | 'e' | 'E' | 'o' | 'O'
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{generate_tinanta, Lakara, Purusha, Vacana};
+
+    fn san(root: &str) -> Dhatu {
+        generate_sanadyanta(&Dhatu::new(root.to_string(), Gana::Bhvadi), Sanadi::San)
+    }
+
+    #[test]
+    fn test_bhu_san() {
+        let bubhusa = san("भू");
+        assert_eq!(bubhusa.root, "बुभूष");
+        assert_eq!(
+            generate_tinanta(&bubhusa, Lakara::Lat, Purusha::Prathama, Vacana::Eka),
+            "बुभूषति"
+        );
+    }
+
+    #[test]
+    fn test_derive_sanadyanta() {
+        let bhu = Dhatu::new("भू".to_string(), Gana::Bhvadi);
+        let prakriya = derive_sanadyanta(&bhu, Sanadi::San);
+
+        assert_eq!(prakriya.assert_final("buBUza"), Ok(()));
+        let terms: Vec<&str> = prakriya.terms().iter().map(|t| t.text.as_str()).collect();
+        assert_eq!(terms, vec!["bu", "BU", "za"]);
+        let rules: Vec<&str> = prakriya.history().iter().map(|s| s.rule.as_str()).collect();
+        assert_eq!(rules, vec!["1.3.9", "8.3.59", "6.1.9"]);
+    }
+
+    #[test]
+    fn test_san_stems() {
+        assert_eq!(san("कृ").root, "चिकीर्ष");
+        assert_eq!(san("जि").root, "जिगीष");
+        assert_eq!(san("पठ्").root, "पिपठिष");
+        assert_eq!(san("लिख्").root, "लिलेखिष");
+        assert_eq!(san("पा").root, "पिपास");
+    }
+}
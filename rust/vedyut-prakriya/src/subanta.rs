@@ -0,0 +1,433 @@
+//! Subanta (nominal) declension
+//!
+//! Stems and forms are in SLP1, e.g. `rAma` + tṛtīyā ekavacana → `rAmeRa`.
+
+use crate::generator::Vacana;
+use crate::tag::{without_final_it, Tag};
+use crate::tripadi::apply_pada_final;
+
+/// The prathamā dual sup `O` (au)
+const AU: &str = "O";
+/// The dvitīyā dual sup `Ow` (auṭ)
+const AUT: &str = "Ow";
+
+/// The a-final stems of the sarvādi list (1.1.27)
+const SARVADI: &[&str] = &[
+    "sarva", "viSva", "uBaya", "anya", "anyatara", "itara", "katara", "katama", "nema", "sima",
+    "eka",
+];
+
+/// Grammatical gender of a prātipadika
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Linga {
+    /// Masculine (पुंलिङ्ग)
+    Pum,
+    /// Feminine (स्त्रीलिङ्ग)
+    Stri,
+    /// Neuter (नपुंसकलिङ्ग)
+    Napumsaka,
+}
+
+impl Linga {
+    pub fn all() -> [Linga; 3] {
+        [Linga::Pum, Linga::Stri, Linga::Napumsaka]
+    }
+
+    /// Lowercase roman name, as used in lexicon metadata
+    pub fn name(&self) -> &'static str {
+        match self {
+            Linga::Pum => "pum",
+            Linga::Stri => "stri",
+            Linga::Napumsaka => "napumsaka",
+        }
+    }
+}
+
+/// Case (vibhakti), with the vocative as its own row of the paradigm
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Vibhakti {
+    Prathama,
+    Dvitiya,
+    Trtiya,
+    Caturthi,
+    Panchami,
+    Shashthi,
+    Saptami,
+    Sambodhana,
+}
+
+impl Vibhakti {
+    pub fn all() -> [Vibhakti; 8] {
+        [
+            Vibhakti::Prathama,
+            Vibhakti::Dvitiya,
+            Vibhakti::Trtiya,
+            Vibhakti::Caturthi,
+            Vibhakti::Panchami,
+            Vibhakti::Shashthi,
+            Vibhakti::Saptami,
+            Vibhakti::Sambodhana,
+        ]
+    }
+
+    /// Lowercase roman name, as used in lexicon metadata
+    pub fn name(&self) -> &'static str {
+        match self {
+            Vibhakti::Prathama => "prathama",
+            Vibhakti::Dvitiya => "dvitiya",
+            Vibhakti::Trtiya => "trtiya",
+            Vibhakti::Caturthi => "caturthi",
+            Vibhakti::Panchami => "panchami",
+            Vibhakti::Shashthi => "shashthi",
+            Vibhakti::Saptami => "saptami",
+            Vibhakti::Sambodhana => "sambodhana",
+        }
+    }
+}
+
+/// One way a form can be derived from a stem
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubantaAnalysis {
+    /// Prātipadika (SLP1)
+    pub stem: String,
+    pub linga: Linga,
+    pub vibhakti: Vibhakti,
+    pub vacana: Vacana,
+}
+
+/// Generate a subanta (SLP1) from a stem, gender, case and number
+///
+/// # Returns
+/// `None` if the stem type or cell of the paradigm is not supported yet
+pub fn generate_subanta(
+    stem: &str,
+    linga: Linga,
+    vibhakti: Vibhakti,
+    vacana: Vacana,
+) -> Option<String> {
+    let tags = pratipadika_tags(stem);
+    let form = match linga {
+        Linga::Pum if stem.ends_with('a') => a_stem_pum(stem, &tags, vibhakti, vacana)?,
+        Linga::Napumsaka if stem.ends_with('a') => a_stem_napumsaka(stem, &tags, vibhakti, vacana)?,
+        _ => return None,
+    };
+
+    Some(apply_natva(&apply_pada_final(&form)))
+}
+
+/// Saṃjñās of a prātipadika
+fn pratipadika_tags(stem: &str) -> Vec<Tag> {
+    if SARVADI.contains(&stem) {
+        vec![Tag::Sarvanama]
+    } else {
+        Vec::new()
+    }
+}
+
+/// Find every (stem, linga, vibhakti, vacana) among the candidates that yields `form`
+///
+/// Each candidate's paradigm is generated in full and matched against the form,
+/// so ambiguous forms (e.g. `rAmAByAm`) return several analyses.
+pub fn analyze_subanta(form: &str, candidate_stems: &[&str]) -> Vec<SubantaAnalysis> {
+    let mut results = Vec::new();
+
+    for stem in candidate_stems {
+        for linga in Linga::all() {
+            for vibhakti in Vibhakti::all() {
+                for vacana in Vacana::all() {
+                    if generate_subanta(stem, linga, vibhakti, vacana).as_deref() == Some(form) {
+                        results.push(SubantaAnalysis {
+                            stem: stem.to_string(),
+                            linga,
+                            vibhakti,
+                            vacana,
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    results
+}
+
+/// Masculine a-stems (rāma)
+fn a_stem_pum(stem: &str, tags: &[Tag], vibhakti: Vibhakti, vacana: Vacana) -> Option<String> {
+    use Vibhakti::*;
+
+    let base = stem.strip_suffix('a')?;
+
+    if tags.contains(&Tag::Sarvanama) {
+        if let Some(form) = sarvanama_ending(base, vibhakti, vacana) {
+            return Some(form);
+        }
+    }
+
+    let form = match (vibhakti, vacana) {
+        // su -> s, made visarga in apply_pada_final
+        (Prathama, Vacana::Eka) => format!("{}as", base),
+        // a + au/auṭ -> vṛddhi (6.1.88 vṛddhir eci); the ṭ of auṭ is an it
+        (Prathama | Sambodhana, Vacana::Dvi) => format!("{}{}", base, AU),
+        (Dvitiya, Vacana::Dvi) => format!("{}{}", base, without_final_it(AUT)),
+        // Prathamayoḥ pūrvasavarṇaḥ (6.1.102)
+        (Prathama | Sambodhana, Vacana::Bahu) => format!("{}As", base),
+
+        // Ami pūrvaḥ (6.1.107)
+        (Dvitiya, Vacana::Eka) => format!("{}am", base),
+        // Prathamayoḥ pūrvasavarṇaḥ (6.1.102), then tasmāc chaso naḥ puṃsi (6.1.103)
+        (Dvitiya, Vacana::Bahu) => format!("{}An", base),
+
+        // Ṭāṅasiṅasām ināt syāḥ (7.1.12), then guṇa (6.1.87)
+        (Trtiya, Vacana::Eka) => format!("{}ena", base),
+        // Supi ca (7.3.102)
+        (Trtiya | Caturthi | Panchami, Vacana::Dvi) => format!("{}AByAm", base),
+        // Ato bhisa ais (7.1.9)
+        (Trtiya, Vacana::Bahu) => format!("{}EH", base),
+
+        // Ṅer yaḥ (7.1.13), then supi ca (7.3.102)
+        (Caturthi, Vacana::Eka) => format!("{}Aya", base),
+        // Bahuvacane jhaly et (7.3.103)
+        (Caturthi | Panchami, Vacana::Bahu) => format!("{}eByaH", base),
+
+        // ṅasi -> āt (7.1.12)
+        (Panchami, Vacana::Eka) => format!("{}At", base),
+
+        // ṅas -> sya (7.1.12)
+        (Shashthi, Vacana::Eka) => format!("{}asya", base),
+        // Osi ca (7.3.104), then ayādi (6.1.78)
+        (Shashthi | Saptami, Vacana::Dvi) => format!("{}ayoH", base),
+        // Nuṭ (7.1.54) and nāmi (6.4.3)
+        (Shashthi, Vacana::Bahu) => format!("{}AnAm", base),
+
+        // a + i -> guṇa (6.1.87)
+        (Saptami, Vacana::Eka) => format!("{}e", base),
+        // Bahuvacane jhaly et (7.3.103), ṣatva (8.3.59)
+        (Saptami, Vacana::Bahu) => format!("{}ezu", base),
+
+        // Eṅhrasvāt sambuddheḥ (6.1.69)
+        (Sambodhana, Vacana::Eka) => stem.to_string(),
+    };
+
+    Some(form)
+}
+
+/// Endings that replace the regular ones for a-final sarvanāmas (sarva)
+fn sarvanama_ending(base: &str, vibhakti: Vibhakti, vacana: Vacana) -> Option<String> {
+    use Vibhakti::*;
+
+    let form = match (vibhakti, vacana) {
+        // Jasaḥ śī (7.1.17), then guṇa (6.1.87)
+        (Prathama | Sambodhana, Vacana::Bahu) => format!("{}e", base),
+        // Sarvanāmnaḥ smai (7.1.14)
+        (Caturthi, Vacana::Eka) => format!("{}asmE", base),
+        // Ṅasiṅyoḥ smātsminau (7.1.15)
+        (Panchami, Vacana::Eka) => format!("{}asmAt", base),
+        (Saptami, Vacana::Eka) => format!("{}asmin", base),
+        // Āmi sarvanāmnaḥ suṭ (7.1.52), bahuvacane jhaly et (7.3.103), ṣatva (8.3.59)
+        (Shashthi, Vacana::Bahu) => format!("{}ezAm", base),
+        _ => return None,
+    };
+
+    Some(form)
+}
+
+/// Neuter a-stems (phala)
+///
+/// Only the direct cases and the vocative differ from the masculine.
+fn a_stem_napumsaka(
+    stem: &str,
+    tags: &[Tag],
+    vibhakti: Vibhakti,
+    vacana: Vacana,
+) -> Option<String> {
+    use Vibhakti::*;
+
+    let base = stem.strip_suffix('a')?;
+
+    let form = match (vibhakti, vacana) {
+        // Ato 'm (7.1.24), then ami pūrvaḥ (6.1.107)
+        (Prathama | Dvitiya, Vacana::Eka) => format!("{}am", base),
+        // Napuṃsakāc ca (7.1.19): au -> śī, then guṇa (6.1.87)
+        (Prathama | Dvitiya | Sambodhana, Vacana::Dvi) => format!("{}e", base),
+        // Jaśśasoḥ śiḥ (7.1.20), num (7.1.72 napuṃsakasya jhalacaḥ), then
+        // sarvanāmasthāne cāsambuddhau (6.4.8) lengthens the vowel before n
+        (Prathama | Dvitiya | Sambodhana, Vacana::Bahu) => format!("{}Ani", base),
+        // Eṅhrasvāt sambuddheḥ (6.1.69) drops the am
+        (Sambodhana, Vacana::Eka) => stem.to_string(),
+        _ => return a_stem_pum(stem, tags, vibhakti, vacana),
+    };
+
+    Some(form)
+}
+
+/// Retroflex n after r/ṣ/ṛ within the pada (8.4.1 raṣābhyāṃ no ṇaḥ samānapade)
+///
+/// Vowels, h/y/v/r, ku- and pu-varga consonants and anusvāra may intervene
+/// (8.4.2 aṭkupvāṅnumvyavāye 'pi). A pada-final n is kept (8.4.37).
+fn apply_natva(word: &str) -> String {
+    let chars: Vec<char> = word.chars().collect();
+    let mut result = String::with_capacity(word.len());
+    let mut trigger = false;
+
+    for (i, &c) in chars.iter().enumerate() {
+        match c {
+            'r' | 'z' | 'f' | 'F' => {
+                trigger = true;
+                result.push(c);
+            }
+            'n' if trigger && i + 1 < chars.len() => {
+                result.push('R');
+                trigger = false;
+            }
+            'a' | 'A' | 'i' | 'I' | 'u' | 'U' | 'x' | 'X' | 'e' | 'E' | 'o' | 'O' | 'h' | 'y'
+            | 'v' | 'M' | 'k' | 'K' | 'g' | 'G' | 'N' | 'p' | 'P' | 'b' | 'B' | 'm' => {
+                result.push(c)
+            }
+            _ => {
+                trigger = false;
+                result.push(c);
+            }
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rama_paradigm() {
+        let rama = |v, n| generate_subanta("rAma", Linga::Pum, v, n).unwrap();
+
+        assert_eq!(rama(Vibhakti::Prathama, Vacana::Eka), "rAmaH");
+        assert_eq!(rama(Vibhakti::Prathama, Vacana::Bahu), "rAmAH");
+        assert_eq!(rama(Vibhakti::Trtiya, Vacana::Eka), "rAmeRa");
+        assert_eq!(rama(Vibhakti::Caturthi, Vacana::Eka), "rAmAya");
+        assert_eq!(rama(Vibhakti::Shashthi, Vacana::Eka), "rAmasya");
+        assert_eq!(rama(Vibhakti::Shashthi, Vacana::Bahu), "rAmARAm");
+        assert_eq!(rama(Vibhakti::Saptami, Vacana::Bahu), "rAmezu");
+        assert_eq!(rama(Vibhakti::Sambodhana, Vacana::Eka), "rAma");
+    }
+
+    #[test]
+    fn test_rama_accusative() {
+        let rama = |n| generate_subanta("rAma", Linga::Pum, Vibhakti::Dvitiya, n).unwrap();
+
+        assert_eq!(rama(Vacana::Eka), "rAmam");
+        assert_eq!(rama(Vacana::Dvi), "rAmO");
+        assert_eq!(rama(Vacana::Bahu), "rAmAn");
+    }
+
+    #[test]
+    fn test_rama_dual_direct_cases() {
+        let dvi = |v| generate_subanta("rAma", Linga::Pum, v, Vacana::Dvi).unwrap();
+
+        assert_eq!(dvi(Vibhakti::Prathama), "rAmO");
+        assert_eq!(dvi(Vibhakti::Dvitiya), "rAmO");
+        assert_eq!(dvi(Vibhakti::Sambodhana), "rAmO");
+    }
+
+    #[test]
+    fn test_pada_final_visarga() {
+        assert_eq!(apply_pada_final("rAmas"), "rAmaH");
+        assert_eq!(apply_pada_final("rAmO"), "rAmO");
+
+        // Vowel-final forms never pick up a visarga
+        for linga in [Linga::Pum, Linga::Napumsaka] {
+            for vibhakti in Vibhakti::all() {
+                for vacana in Vacana::all() {
+                    let form = generate_subanta("rAma", linga, vibhakti, vacana).unwrap();
+                    assert!(!form.ends_with('s'), "{}", form);
+                    assert!(!form.ends_with("OH") && !form.ends_with("eH"), "{}", form);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_neuter_plural_num() {
+        let bahu = |stem, v| generate_subanta(stem, Linga::Napumsaka, v, Vacana::Bahu).unwrap();
+
+        assert_eq!(bahu("Pala", Vibhakti::Prathama), "PalAni");
+        assert_eq!(bahu("vana", Vibhakti::Dvitiya), "vanAni");
+        assert_eq!(bahu("SarIra", Vibhakti::Prathama), "SarIrARi");
+        assert_eq!(bahu("Pala", Vibhakti::Trtiya), "PalEH");
+    }
+
+    #[test]
+    fn test_neuter_direct_cases() {
+        let phala = |v, n| generate_subanta("Pala", Linga::Napumsaka, v, n).unwrap();
+
+        assert_eq!(phala(Vibhakti::Prathama, Vacana::Eka), "Palam");
+        assert_eq!(phala(Vibhakti::Dvitiya, Vacana::Dvi), "Pale");
+        assert_eq!(phala(Vibhakti::Sambodhana, Vacana::Eka), "Pala");
+        assert_eq!(phala(Vibhakti::Saptami, Vacana::Eka), "Pale");
+    }
+
+    #[test]
+    fn test_sarvanama_endings() {
+        let sarva = |v, n| generate_subanta("sarva", Linga::Pum, v, n).unwrap();
+
+        assert_eq!(sarva(Vibhakti::Caturthi, Vacana::Eka), "sarvasmE");
+        assert_eq!(sarva(Vibhakti::Panchami, Vacana::Eka), "sarvasmAt");
+        assert_eq!(sarva(Vibhakti::Saptami, Vacana::Eka), "sarvasmin");
+        assert_eq!(sarva(Vibhakti::Prathama, Vacana::Bahu), "sarve");
+        assert_eq!(sarva(Vibhakti::Shashthi, Vacana::Bahu), "sarvezAm");
+        // Other cells follow rāma
+        assert_eq!(sarva(Vibhakti::Trtiya, Vacana::Eka), "sarveRa");
+        assert_eq!(
+            generate_subanta("sarva", Linga::Napumsaka, Vibhakti::Saptami, Vacana::Eka),
+            Some("sarvasmin".to_string())
+        );
+        assert_eq!(
+            generate_subanta("sarva", Linga::Napumsaka, Vibhakti::Prathama, Vacana::Bahu),
+            Some("sarvARi".to_string())
+        );
+    }
+
+    #[test]
+    fn test_natva_blocked() {
+        // No r/ṣ before the n, and t blocks the trigger
+        assert_eq!(
+            generate_subanta("deva", Linga::Pum, Vibhakti::Trtiya, Vacana::Eka),
+            Some("devena".to_string())
+        );
+        assert_eq!(apply_natva("rAtena"), "rAtena");
+    }
+
+    #[test]
+    fn test_analyze_subanta() {
+        let analyses = analyze_subanta("rAmeRa", &["deva", "rAma"]);
+
+        assert_eq!(
+            analyses,
+            vec![
+                SubantaAnalysis {
+                    stem: "rAma".to_string(),
+                    linga: Linga::Pum,
+                    vibhakti: Vibhakti::Trtiya,
+                    vacana: Vacana::Eka,
+                },
+                // Without gender information the stem could also be neuter
+                SubantaAnalysis {
+                    stem: "rAma".to_string(),
+                    linga: Linga::Napumsaka,
+                    vibhakti: Vibhakti::Trtiya,
+                    vacana: Vacana::Eka,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_analyze_subanta_ambiguous() {
+        let analyses = analyze_subanta("rAmAByAm", &["rAma"]);
+        assert_eq!(analyses.len(), 6);
+        let analyses = analyze_subanta("PalAni", &["Pala"]);
+        assert_eq!(analyses.len(), 3);
+        assert!(analyses.iter().all(|a| a.linga == Linga::Napumsaka));
+        assert!(analyze_subanta("rAmeRa", &["deva"]).is_empty());
+    }
+}
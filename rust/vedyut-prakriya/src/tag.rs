@@ -0,0 +1,160 @@
+//! Grammatical tags derived from upadeśa it-markers and saṃjñās
+//!
+//! Upadeśas are given in SLP1, e.g. `Sap`, `tip`, `kta`.
+
+use serde::{Deserialize, Serialize};
+
+/// It-marker tags of a pratyaya
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Tag {
+    /// Has `k` as it (kit)
+    Kit,
+    /// Has `ṅ` as it (ṅit)
+    Nit,
+    /// Has `p` as it (pit)
+    Pit,
+    /// Has `ś` as it (śit)
+    Sit,
+    /// Has `ṇ` as it (ṇit)
+    Rit,
+    /// Has `c` as it (cit)
+    Cit,
+    /// Has `ṭ` as it (ṭit)
+    Wit,
+    /// Pronominal stem (sarvanāma, 1.1.27 sarvādīni sarvanāmāni); a saṃjñā of
+    /// the prātipadika rather than an it
+    Sarvanama,
+}
+
+impl Tag {
+    fn from_it(c: char) -> Option<Tag> {
+        match c {
+            'k' => Some(Tag::Kit),
+            'N' => Some(Tag::Nit),
+            'p' => Some(Tag::Pit),
+            'S' => Some(Tag::Sit),
+            'R' => Some(Tag::Rit),
+            'c' => Some(Tag::Cit),
+            'w' => Some(Tag::Wit),
+            _ => None,
+        }
+    }
+}
+
+/// Tags set by the it-markers of a (non-taddhita) pratyaya upadeśa
+///
+/// Initial its come from laśakv ataddhite (1.3.8) and cuṭū (1.3.7), the final
+/// it from halantyam (1.3.3). Text that is not an SLP1 upadeśa has no tags.
+pub fn it_tags(upadesha: &str) -> Vec<Tag> {
+    if !is_slp1_upadesha(upadesha) {
+        return Vec::new();
+    }
+    let mut tags = Vec::new();
+
+    if let Some(first) = upadesha.chars().next() {
+        if matches!(first, 'S' | 'k' | 'N' | 'c' | 'R' | 'w') {
+            tags.extend(Tag::from_it(first));
+        }
+    }
+
+    if upadesha.chars().count() > 1 {
+        if let Some(tag) = upadesha.chars().last().and_then(Tag::from_it) {
+            if !tags.contains(&tag) {
+                tags.push(tag);
+            }
+        }
+    }
+
+    tags
+}
+
+/// Drop the final it of an upadeśa (halantyam 1.3.3, tasya lopaḥ 1.3.9)
+///
+/// Only the final letter is checked, e.g. `Ow` (auṭ) → `O`.
+pub fn without_final_it(upadesha: &str) -> &str {
+    match upadesha.char_indices().last() {
+        Some((i, c)) if i > 0 && Tag::from_it(c).is_some() => &upadesha[..i],
+        _ => upadesha,
+    }
+}
+
+/// Check that text is written only with SLP1 letters and the nasalization mark `~`
+pub fn is_slp1_upadesha(text: &str) -> bool {
+    !text.is_empty()
+        && text
+            .chars()
+            .all(|c| (c.is_ascii_alphabetic() && !matches!(c, 'V' | 'Z')) || c == '~')
+}
+
+/// Kṅiti ca (1.1.5): a kit or ṅit suffix blocks guṇa and vṛddhi
+pub fn blocks_guna(tags: &[Tag]) -> bool {
+    tags.contains(&Tag::Kit) || tags.contains(&Tag::Nit)
+}
+
+/// Check that a root may take guṇa before a suffix with the given tags
+///
+/// Guṇa is blocked before a kit or ṅit suffix (see [`blocks_guna`]), and an
+/// apit sārvadhātuka suffix such as śyan or śa counts as ṅit (1.2.4
+/// sārvadhātukam apit).
+pub fn allows_guna(tags: &[Tag], sarvadhatuka: bool) -> bool {
+    !blocks_guna(tags) && (!sarvadhatuka || tags.contains(&Tag::Pit))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_it_tags() {
+        assert_eq!(it_tags("Sap"), vec![Tag::Sit, Tag::Pit]);
+        assert_eq!(it_tags("tip"), vec![Tag::Pit]);
+        assert_eq!(it_tags("kta"), vec![Tag::Kit]);
+        assert_eq!(it_tags("Ric"), vec![Tag::Rit, Tag::Cit]);
+        assert_eq!(it_tags("SAnac"), vec![Tag::Sit, Tag::Cit]);
+        // Final s of a vibhakti is not an it (1.3.4 na vibhaktau tusmāḥ)
+        assert_eq!(it_tags("Sas"), vec![Tag::Sit]);
+        assert!(it_tags("tas").is_empty());
+        assert!(it_tags("").is_empty());
+        assert!(it_tags("शप्").is_empty());
+    }
+
+    #[test]
+    fn test_without_final_it() {
+        assert_eq!(without_final_it("Ow"), "O");
+        assert_eq!(without_final_it("O"), "O");
+        assert_eq!(without_final_it("Sap"), "Sa");
+        assert_eq!(without_final_it("jas"), "jas");
+    }
+
+    #[test]
+    fn test_is_slp1_upadesha() {
+        assert!(is_slp1_upadesha("Sap"));
+        assert!(is_slp1_upadesha("tip"));
+        assert!(is_slp1_upadesha("Ba~j"));
+        assert!(!is_slp1_upadesha("शप्"));
+        assert!(!is_slp1_upadesha("śap"));
+        assert!(!is_slp1_upadesha(""));
+    }
+
+    #[test]
+    fn test_tag_serialize() {
+        let json = serde_json::to_string(&it_tags("Sap")).unwrap();
+        assert_eq!(json, r#"["Sit","Pit"]"#);
+    }
+
+    #[test]
+    fn test_blocks_guna() {
+        assert!(blocks_guna(&it_tags("kta")));
+        assert!(!blocks_guna(&it_tags("tip")));
+    }
+
+    #[test]
+    fn test_allows_guna() {
+        assert!(allows_guna(&it_tags("Sap"), true));
+        assert!(allows_guna(&it_tags("tip"), true));
+        assert!(!allows_guna(&it_tags("Syan"), true));
+        assert!(!allows_guna(&it_tags("Sa"), true));
+        assert!(!allows_guna(&it_tags("kta"), false));
+        assert!(allows_guna(&it_tags("tfc"), false));
+    }
+}
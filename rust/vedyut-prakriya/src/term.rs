@@ -0,0 +1,63 @@
+//! Terms of a derivation: a root, vikaraṇa or pratyaya with its tags
+
+use crate::tag::{it_tags, Tag};
+use serde::{Deserialize, Serialize};
+
+/// A term (SLP1) and the tags it carries
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Term {
+    /// Current text of the term
+    pub text: String,
+    /// Tags set on the term, e.g. the it-markers of a pratyaya
+    pub tags: Vec<Tag>,
+}
+
+impl Term {
+    /// A term with no tags, e.g. a root or a prātipadika
+    pub fn new(text: &str) -> Self {
+        Self {
+            text: text.to_string(),
+            tags: Vec::new(),
+        }
+    }
+
+    /// A pratyaya with the tags of its it-markers (see [`it_tags`])
+    ///
+    /// The text is the upadeśa itself, its included; tasya lopaḥ (1.3.9)
+    /// is left to the derivation, e.g. `Sap` is śit and pit.
+    pub fn pratyaya(upadesha: &str) -> Self {
+        Self {
+            text: upadesha.to_string(),
+            tags: it_tags(upadesha),
+        }
+    }
+
+    /// Check that the term carries `tag`
+    pub fn has_tag(&self, tag: Tag) -> bool {
+        self.tags.contains(&tag)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pratyaya_tags() {
+        // Tiṅ
+        assert_eq!(Term::pratyaya("tip").tags, vec![Tag::Pit]);
+        assert!(Term::pratyaya("tas").tags.is_empty());
+        // Vikaraṇas
+        assert_eq!(Term::pratyaya("Sap").tags, vec![Tag::Sit, Tag::Pit]);
+        assert_eq!(Term::pratyaya("Sa").tags, vec![Tag::Sit]);
+        // Sup
+        assert_eq!(Term::pratyaya("Ow").tags, vec![Tag::Wit]);
+        assert_eq!(Term::pratyaya("Sas").tags, vec![Tag::Sit]);
+        // Kṛt
+        assert!(Term::pratyaya("kta").has_tag(Tag::Kit));
+        assert_eq!(Term::pratyaya("Satf").tags, vec![Tag::Sit]);
+
+        assert_eq!(Term::pratyaya("Sap").text, "Sap");
+        assert!(Term::new("BU").tags.is_empty());
+    }
+}
@@ -0,0 +1,61 @@
+//! Pada-final rules of the tripādī (8.2–8.4)
+//!
+//! Generators build raw SLP1 strings such as `rAmas`; these rules turn them into
+//! the pronounced pada before it is shown to the user.
+
+use vedyut_lipi::{transliterate, Scheme};
+
+/// Apply pada-final sandhi to a raw SLP1 pada and transliterate it
+///
+/// Final `s` becomes ru (8.2.66 sasajuṣo ruḥ) and final `r` becomes visarga
+/// (8.3.15 kharavasānayor visarjanīyaḥ). A final palatal becomes velar
+/// (8.2.30 coḥ kuḥ) and a final stop takes jaśtva (8.2.39 jhalāṃ jaśo 'nte),
+/// then cartva at the pause (8.4.56 vāvasāne): `vAc` → `vAk`, `marut` → `marut`.
+/// See [`crate::Prakriya::final_form`] for the same on a derivation.
+pub fn final_form(slp1: &str, script: Scheme) -> String {
+    let pada = apply_pada_final(slp1);
+    transliterate(&pada, Scheme::Slp1, script)
+}
+
+/// The pada-final rules of [`final_form`], on SLP1 only
+pub(crate) fn apply_pada_final(slp1: &str) -> String {
+    let Some(last) = slp1.chars().last() else {
+        return String::new();
+    };
+    let base = &slp1[..slp1.len() - last.len_utf8()];
+
+    let new_last = match last {
+        's' | 'r' => 'H',
+        // Jaśtva gives g, ḍ, d, b, which cartva makes voiceless
+        'k' | 'K' | 'g' | 'G' | 'c' | 'C' | 'j' | 'J' => 'k',
+        'w' | 'W' | 'q' | 'Q' => 'w',
+        't' | 'T' | 'd' | 'D' => 't',
+        'p' | 'P' | 'b' | 'B' => 'p',
+        _ => return slp1.to_string(),
+    };
+
+    format!("{}{}", base, new_last)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_final_visarga() {
+        assert_eq!(final_form("rAmas", Scheme::Slp1), "rAmaH");
+        assert_eq!(final_form("rAmas", Scheme::Devanagari), "रामः");
+        assert_eq!(final_form("punar", Scheme::Slp1), "punaH");
+    }
+
+    #[test]
+    fn test_final_stop_at_pause() {
+        assert_eq!(final_form("vAc", Scheme::Slp1), "vAk");
+        assert_eq!(final_form("vAc", Scheme::Devanagari), "वाक्");
+        assert_eq!(final_form("marut", Scheme::Slp1), "marut");
+        assert_eq!(final_form("suhfd", Scheme::Slp1), "suhft");
+        assert_eq!(final_form("kakuB", Scheme::Slp1), "kakup");
+        assert_eq!(final_form("Bavati", Scheme::Slp1), "Bavati");
+        assert_eq!(final_form("", Scheme::Slp1), "");
+    }
+}
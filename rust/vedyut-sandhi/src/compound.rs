@@ -0,0 +1,94 @@
+//! Sandhi inside a compound (samāsa), keeping track of its members
+
+use crate::rules::{join_slp1, Junction, SandhiConfig};
+use serde::{Deserialize, Serialize};
+use std::ops::Range;
+
+/// A compound with the position of each member in its joined form
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompoundAnalysis {
+    /// The joined compound (SLP1)
+    pub compound: String,
+    /// Byte span of each member in `compound`, in order
+    ///
+    /// A vowel fused from two members belongs to both, so neighbouring spans
+    /// overlap there: `deva` + `indra` gives `devendra` with spans `0..4`
+    /// and `3..8`.
+    pub spans: Vec<Range<usize>>,
+}
+
+/// Check that `members` join to the compound `slp1` and locate each member
+///
+/// Sandhi is applied left to right, first with all rules and then without
+/// the optional ones. Assumes SLP1 input.
+///
+/// # Returns
+/// `None` if there are no members or neither way of joining them gives `slp1`.
+pub fn analyze_compound(slp1: &str, members: &[&str]) -> Option<CompoundAnalysis> {
+    let without_optional = SandhiConfig {
+        apply_optional: false,
+        ..Default::default()
+    };
+
+    [SandhiConfig::default(), without_optional]
+        .iter()
+        .filter_map(|config| join_members(members, config))
+        .find(|analysis| analysis.compound == slp1)
+}
+
+/// Join the members, moving the end of the previous span with each junction
+///
+/// An empty member gets an empty span.
+fn join_members(members: &[&str], config: &SandhiConfig) -> Option<CompoundAnalysis> {
+    let (first, rest) = members.split_first()?;
+    let mut compound = first.to_string();
+    let mut spans = Vec::with_capacity(members.len());
+    spans.push(0..compound.len());
+
+    for member in rest {
+        let previous = spans.last_mut()?;
+        match join_slp1(&compound, member, config) {
+            Junction::Merged(word) => {
+                // The fused vowel replaced the last char of the left side and
+                // the first char of the member
+                let first_len = member.chars().next()?.len_utf8();
+                let start = compound.len() - compound.chars().last()?.len_utf8();
+                let end = word.len() - (member.len() - first_len);
+                previous.end = end;
+                spans.push(start..word.len());
+                compound = word;
+            }
+            Junction::Separate(left, right) => {
+                previous.end = left.len();
+                spans.push(left.len()..left.len() + right.len());
+                compound = format!("{}{}", left, right);
+            }
+        }
+    }
+
+    Some(CompoundAnalysis { compound, spans })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_analyze_compound() {
+        let analysis = analyze_compound("devarAja", &["deva", "rAja"]).unwrap();
+        assert_eq!(analysis.spans, vec![0..4, 4..8]);
+
+        // Fused vowels are shared
+        let analysis = analyze_compound("devendra", &["deva", "indra"]).unwrap();
+        assert_eq!(analysis.spans, vec![0..4, 3..8]);
+        let analysis = analyze_compound("mahotsava", &["mahA", "utsava"]).unwrap();
+        assert_eq!(&analysis.compound[analysis.spans[1].clone()], "otsava");
+
+        // Changed edges stay with their member
+        let analysis = analyze_compound("sadDarma", &["sat", "Darma"]).unwrap();
+        assert_eq!(analysis.spans, vec![0..3, 3..8]);
+
+        assert_eq!(analyze_compound("devAlaya", &["deva", "indra"]), None);
+        assert_eq!(analyze_compound("deva", &[]), None);
+    }
+}
@@ -1,10 +1,18 @@
 //! Sandhi rules application and splitting for Sanskrit
 
+pub mod compound;
 pub mod rules;
+pub mod sentence;
 pub mod splitter;
+pub mod vowels;
 
-pub use rules::apply_sandhi;
-pub use splitter::split_sandhi;
+pub use compound::{analyze_compound, CompoundAnalysis};
+pub use rules::{
+    apply_rule, apply_sandhi, apply_sandhi_cfg, apply_sandhi_pragrhya, join_morphemes_devanagari,
+    SandhiConfig, SandhiRule,
+};
+pub use sentence::join_sentence;
+pub use splitter::{sandhi_consistent, split_sandhi};
 
 #[cfg(test)]
 mod tests {
@@ -1,4 +1,5 @@
 /// Sandhi rules for Sanskrit phonetic combinations
+use crate::vowels;
 use vedyut_lipi::{transliterate, Scheme};
 
 #[derive(Debug, Clone)]
@@ -13,12 +14,147 @@ pub enum SandhiRule {
     Yan,
     /// Eco'yavāyāvaḥ (6.1.78)
     Ayadi,
+    /// Haśi ca (6.1.114): aḥ before a voiced consonant becomes o
+    Utva,
+    /// Visarjanīyasya saḥ (8.3.34): visarga before a voiceless stop
+    VisargaSatva,
+    /// Jhalāṃ jaś jhaśi (8.4.53)
+    Jashtva,
+    /// Khari ca (8.4.55)
+    Chartva,
+    /// Stoḥ ścunā ścuḥ (8.4.40)
+    Scutva,
+    /// Yaro 'nunāsike 'nunāsiko vā (8.4.45), optional
+    Anunasika,
+    /// Mo 'nusvāraḥ (8.3.23): m before a consonant becomes anusvāra
+    Anusvara,
+    /// Anusvārasya yayi parasavarṇaḥ (8.4.58), optional at a pada end (8.4.59)
+    Parasavarna,
+}
+
+impl SandhiRule {
+    /// Aṣṭādhyāyī number of the sutra, e.g. "6.1.101"
+    pub fn sutra(&self) -> &'static str {
+        match self {
+            SandhiRule::Dirgha => "6.1.101",
+            SandhiRule::Guna => "6.1.87",
+            SandhiRule::Vriddhi => "6.1.88",
+            SandhiRule::Yan => "6.1.77",
+            SandhiRule::Ayadi => "6.1.78",
+            SandhiRule::Utva => "6.1.114",
+            SandhiRule::VisargaSatva => "8.3.34",
+            SandhiRule::Jashtva => "8.4.53",
+            SandhiRule::Chartva => "8.4.55",
+            SandhiRule::Scutva => "8.4.40",
+            SandhiRule::Anunasika => "8.4.45",
+            SandhiRule::Anusvara => "8.3.23",
+            SandhiRule::Parasavarna => "8.4.58",
+        }
+    }
+
+    /// Sutra text in IAST
+    pub fn name_iast(&self) -> &'static str {
+        match self {
+            SandhiRule::Dirgha => "akaḥ savarṇe dīrghaḥ",
+            SandhiRule::Guna => "ād guṇaḥ",
+            SandhiRule::Vriddhi => "vṛddhir eci",
+            SandhiRule::Yan => "iko yaṇ aci",
+            SandhiRule::Ayadi => "eco 'yavāyāvaḥ",
+            SandhiRule::Utva => "haśi ca",
+            SandhiRule::VisargaSatva => "visarjanīyasya saḥ",
+            SandhiRule::Jashtva => "jhalāṃ jaś jhaśi",
+            SandhiRule::Chartva => "khari ca",
+            SandhiRule::Scutva => "stoḥ ścunā ścuḥ",
+            SandhiRule::Anunasika => "yaro 'nunāsike 'nunāsiko vā",
+            SandhiRule::Anusvara => "mo 'nusvāraḥ",
+            SandhiRule::Parasavarna => "anusvārasya yayi parasavarṇaḥ",
+        }
+    }
+
+    /// Sutra text in Devanagari
+    pub fn name_devanagari(&self) -> &'static str {
+        match self {
+            SandhiRule::Dirgha => "अकः सवर्णे दीर्घः",
+            SandhiRule::Guna => "आद् गुणः",
+            SandhiRule::Vriddhi => "वृद्धिरेचि",
+            SandhiRule::Yan => "इको यणचि",
+            SandhiRule::Ayadi => "एचोऽयवायावः",
+            SandhiRule::Utva => "हशि च",
+            SandhiRule::VisargaSatva => "विसर्जनीयस्य सः",
+            SandhiRule::Jashtva => "झलां जश् झशि",
+            SandhiRule::Chartva => "खरि च",
+            SandhiRule::Scutva => "स्तोः श्चुना श्चुः",
+            SandhiRule::Anunasika => "यरोऽनुनासिकेऽनुनासिको वा",
+            SandhiRule::Anusvara => "मोऽनुस्वारः",
+            SandhiRule::Parasavarna => "अनुस्वारस्य ययि परसवर्णः",
+        }
+    }
+}
+
+/// Outcome of sandhi at the junction of two SLP1 words
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Junction {
+    /// The words fused into one (vowel sandhi)
+    Merged(String),
+    /// The words stay distinct, possibly with changed edges
+    Separate(String, String),
+}
+
+impl Junction {
+    /// Join the two sides without any separator
+    pub(crate) fn joined(self) -> String {
+        match self {
+            Junction::Merged(word) => word,
+            Junction::Separate(left, right) => format!("{}{}", left, right),
+        }
+    }
+
+    /// Join the two sides as written, keeping a space at a vowel hiatus (rAma
+    /// eti), which would otherwise read as a sequence awaiting sandhi
+    pub(crate) fn written(self) -> String {
+        match self {
+            Junction::Separate(left, right)
+                if left.ends_with(is_vowel) && right.starts_with(is_vowel) =>
+            {
+                format!("{} {}", left, right)
+            }
+            junction => junction.joined(),
+        }
+    }
+}
+
+/// Controls which classes of sandhi rules fire
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SandhiConfig {
+    /// Apply optional (vā/vibhāṣā) rules
+    pub apply_optional: bool,
+    /// Apply visarga sandhi
+    pub apply_visarga: bool,
+    /// Apply consonant (hal) sandhi
+    pub apply_consonant: bool,
+}
+
+impl Default for SandhiConfig {
+    fn default() -> Self {
+        Self {
+            apply_optional: true,
+            apply_visarga: true,
+            apply_consonant: true,
+        }
+    }
 }
 
 /// Apply sandhi between two words
 ///
 /// Converts to SLP1, applies rules, and converts back to the script of the first word.
+/// A vowel hiatus left by the rules is kept with a space, e.g. rAmaH + eti →
+/// "rAma eti".
 pub fn apply_sandhi(left: &str, right: &str) -> String {
+    apply_sandhi_cfg(left, right, &SandhiConfig::default())
+}
+
+/// Apply sandhi between two words, firing only the rule classes enabled in `config`
+pub fn apply_sandhi_cfg(left: &str, right: &str, config: &SandhiConfig) -> String {
     if left.is_empty() {
         return right.to_string();
     }
@@ -26,120 +162,594 @@ pub fn apply_sandhi(left: &str, right: &str) -> String {
         return left.to_string();
     }
 
-    // Detect script or default to SLP1
-    let is_devanagari = left.chars().any(|c| {
+    let scheme = detect_scheme(left);
+    let l_slp1 = transliterate(left, scheme, Scheme::Slp1);
+    let r_slp1 = transliterate(right, scheme, Scheme::Slp1);
+
+    let joined = join_slp1(&l_slp1, &r_slp1, config).written();
+    transliterate(&joined, Scheme::Slp1, scheme)
+}
+
+/// Apply sandhi after a pragṛhya word, whose final vowel takes no vowel sandhi
+///
+/// Īdūded dvivacanaṃ pragṛhyam (1.1.11) makes dual endings in ī, ū and e
+/// pragṛhya (as is a vocative o before iti, 1.1.16), and plutapragṛhyā aci
+/// nityam (6.1.125) keeps them as they are before a vowel: harī + etau stays
+/// "harī etau". Such words are returned separated by a space; other junctions
+/// follow [`apply_sandhi`].
+pub fn apply_sandhi_pragrhya(left: &str, right: &str) -> String {
+    let scheme = detect_scheme(left);
+    let l_slp1 = transliterate(left, scheme, Scheme::Slp1);
+    let r_slp1 = transliterate(right, scheme, Scheme::Slp1);
+
+    if l_slp1.ends_with(is_vowel) && r_slp1.starts_with(is_vowel) {
+        format!("{} {}", left, right)
+    } else {
+        apply_sandhi(left, right)
+    }
+}
+
+/// Join SLP1 morphemes with word-internal sandhi and return Devanagari
+///
+/// Unlike [`apply_sandhi`], no pada-final rule applies at the junctions, so a
+/// consonant-final morpheme keeps its consonant before a vowel (`rAj` + `an` →
+/// राजन्). Visarga and the optional pada-final rules are off.
+pub fn join_morphemes_devanagari(parts: &[&str]) -> String {
+    let config = SandhiConfig {
+        apply_optional: false,
+        apply_visarga: false,
+        apply_consonant: true,
+    };
+
+    let joined = parts
+        .iter()
+        .filter(|part| !part.is_empty())
+        .fold(String::new(), |acc, part| {
+            let consonant_before_vowel =
+                acc.ends_with(|c: char| !is_vowel(c)) && part.starts_with(is_vowel);
+            if acc.is_empty() || consonant_before_vowel {
+                format!("{}{}", acc, part)
+            } else {
+                join_slp1(&acc, part, &config).joined()
+            }
+        });
+
+    transliterate(&joined, Scheme::Slp1, Scheme::Devanagari)
+}
+
+/// Detect script or default to SLP1
+pub(crate) fn detect_scheme(text: &str) -> Scheme {
+    let is_devanagari = text.chars().any(|c| {
         let u = c as u32;
         (0x0900..=0x097F).contains(&u)
     });
-    let scheme = if is_devanagari {
+    if is_devanagari {
         Scheme::Devanagari
     } else {
         Scheme::Slp1
-    };
-
-    let l_slp1 = transliterate(left, scheme, Scheme::Slp1);
-    let r_slp1 = transliterate(right, scheme, Scheme::Slp1);
+    }
+}
 
-    let l_chars: Vec<char> = l_slp1.chars().collect();
-    let r_chars: Vec<char> = r_slp1.chars().collect();
+/// Apply sandhi at the junction of two non-empty SLP1 words
+pub(crate) fn join_slp1(left: &str, right: &str, config: &SandhiConfig) -> Junction {
+    let l_chars: Vec<char> = left.chars().collect();
+    let r_chars: Vec<char> = right.chars().collect();
 
-    let final_c = l_chars.last().unwrap();
-    let initial_c = r_chars.first().unwrap();
+    let (Some(&final_c), Some(&initial_c)) = (l_chars.last(), r_chars.first()) else {
+        return Junction::Separate(left.to_string(), right.to_string());
+    };
 
     // Vowel Sandhi
-    if is_vowel(*final_c) && is_vowel(*initial_c) {
-        if let Some(sandhi) = apply_vowel_sandhi(*final_c, *initial_c) {
+    if is_vowel(final_c) && is_vowel(initial_c) {
+        if let Some(sandhi) = apply_vowel_sandhi(final_c, initial_c) {
             let base = l_chars[..l_chars.len() - 1].iter().collect::<String>();
             let rest = r_chars[1..].iter().collect::<String>();
-            let combined = format!("{}{}{}", base, sandhi, rest);
-            return transliterate(&combined, Scheme::Slp1, scheme);
+            return Junction::Merged(format!("{}{}{}", base, sandhi, rest));
         }
     }
 
-    // Default: concatenate
-    transliterate(&format!("{}{}", l_slp1, r_slp1), Scheme::Slp1, scheme)
-}
+    // Visarga Sandhi
+    if config.apply_visarga && final_c == 'H' {
+        if let Some((new_left, new_right)) = apply_visarga_before_vowel(&l_chars, right) {
+            return Junction::Separate(new_left, new_right);
+        }
+        if let Some(new_left) = apply_visarga_sandhi(&l_chars, initial_c) {
+            return Junction::Separate(new_left, right.to_string());
+        }
+    }
 
-fn is_vowel(c: char) -> bool {
-    matches!(
-        c,
-        'a' | 'A' | 'i' | 'I' | 'u' | 'U' | 'f' | 'F' | 'x' | 'X' | 'e' | 'E' | 'o' | 'O'
-    )
-}
+    // Nasal Sandhi
+    if config.apply_consonant && matches!(final_c, 'm' | 'n') && is_consonant(initial_c) {
+        if let Some(new_final) = apply_nasal_sandhi(left, initial_c, config) {
+            let base = l_chars[..l_chars.len() - 1].iter().collect::<String>();
+            return Junction::Separate(format!("{}{}", base, new_final), right.to_string());
+        }
+    }
 
-fn apply_vowel_sandhi(c1: char, c2: char) -> Option<String> {
-    // 1. Dirgha (Long)
-    if (c1 == 'a' || c1 == 'A') && (c2 == 'a' || c2 == 'A') {
-        return Some("A".to_string());
+    // Consonant Sandhi
+    if config.apply_consonant && is_stop(final_c) && !is_vowel(initial_c) {
+        let base = l_chars[..l_chars.len() - 1].iter().collect::<String>();
+        let new_final = if config.apply_optional && is_nasal(initial_c) {
+            // Yaro 'nunāsike 'nunāsiko vā (8.4.45)
+            nasal_of(varga_of(final_c))
+        } else {
+            apply_consonant_sandhi(final_c, initial_c)
+        };
+        return Junction::Separate(format!("{}{}", base, new_final), right.to_string());
     }
-    if (c1 == 'i' || c1 == 'I') && (c2 == 'i' || c2 == 'I') {
-        return Some("I".to_string());
+
+    // Jhalāṃ jaśo 'nte (8.2.39): a pada-final stop is voiced before a vowel,
+    // palatals first becoming velars (8.2.30 coḥ kuḥ)
+    if config.apply_consonant && is_stop(final_c) && !is_nasal(final_c) && is_vowel(initial_c) {
+        let base = l_chars[..l_chars.len() - 1].iter().collect::<String>();
+        let varga = match varga_of(final_c) {
+            'c' => 'k',
+            varga => varga,
+        };
+        return Junction::Separate(format!("{}{}", base, jash(varga)), right.to_string());
     }
-    if (c1 == 'u' || c1 == 'U') && (c2 == 'u' || c2 == 'U') {
-        return Some("U".to_string());
+
+    // Default: leave both words unchanged
+    Junction::Separate(left.to_string(), right.to_string())
+}
+
+/// Rewrite a word ending in aḥ before a vowel
+///
+/// Before a, ato ror aplutād aplute (6.1.113) gives o, which absorbs the a
+/// (6.1.109 eṅaḥ padāntād ati), written as an avagraha: rAmaH + atra →
+/// rAmo 'tra. Before another vowel the visarga becomes y (8.3.17
+/// bhobhagoaghoapūrvasya yo 'śi) and is dropped (8.3.19 lopaḥ śākalyasya),
+/// and the hiatus stays (8.2.1 pūrvatrāsiddham): rAmaH + eti → rAma eti.
+///
+/// Returns the new words, or `None` unless the left word ends in aḥ and the
+/// right one starts with a vowel.
+fn apply_visarga_before_vowel(l_chars: &[char], right: &str) -> Option<(String, String)> {
+    let initial = right.chars().next().filter(|&c| is_vowel(c))?;
+    let base = match l_chars {
+        [base @ .., 'a', 'H'] => base.iter().collect::<String>(),
+        _ => return None,
+    };
+
+    if initial == 'a' {
+        Some((format!("{}o", base), format!("'{}", &right[1..])))
+    } else {
+        Some((format!("{}a", base), right.to_string()))
     }
-    if (c1 == 'f' || c1 == 'F') && (c2 == 'f' || c2 == 'F') {
-        return Some("F".to_string());
+}
+
+/// Rewrite a visarga-final word before the given initial sound
+///
+/// Returns the new left word, or `None` if the visarga stays.
+fn apply_visarga_sandhi(l_chars: &[char], next: char) -> Option<String> {
+    let stem = l_chars[..l_chars.len() - 1].iter().collect::<String>();
+    let before = l_chars.len().checked_sub(2).map(|i| l_chars[i]);
+
+    match next {
+        // Visarjanīyasya saḥ (8.3.34) with ścutva/ṣṭutva of the s
+        'c' | 'C' => return Some(format!("{}S", stem)),
+        'w' | 'W' => return Some(format!("{}z", stem)),
+        't' | 'T' => return Some(format!("{}s", stem)),
+        _ => {}
     }
 
-    // 2. Guna
-    if c1 == 'a' || c1 == 'A' {
-        if c2 == 'i' || c2 == 'I' {
-            return Some("e".to_string());
-        }
-        if c2 == 'u' || c2 == 'U' {
-            return Some("o".to_string());
-        }
-        if c2 == 'f' || c2 == 'F' {
-            return Some("ar".to_string());
-        }
-        if c2 == 'x' || c2 == 'X' {
-            return Some("al".to_string());
-        }
+    // Vowels count as voiced here: hariH + atra -> hariratra, devAH + atra ->
+    // devA atra
+    if !is_voiced_consonant(next) && !is_vowel(next) {
+        return None;
     }
 
-    // 3. Vriddhi
-    if c1 == 'a' || c1 == 'A' {
-        if c2 == 'e' || c2 == 'E' {
-            return Some("E".to_string());
-        }
-        if c2 == 'o' || c2 == 'O' {
-            return Some("O".to_string());
+    match before {
+        // Ato ror aplutād aplute (6.1.113), haśi ca (6.1.114): aḥ -> o
+        Some('a') if is_voiced_consonant(next) => {
+            let base = l_chars[..l_chars.len() - 2].iter().collect::<String>();
+            Some(format!("{}o", base))
         }
+        // Bho-bhago-agho-apūrvasya yo'śi (8.3.17) and lopa (8.3.22): āḥ -> ā
+        Some('A') => Some(stem),
+        // Other vowels keep the underlying r (8.2.66 sasajuṣo ruḥ)
+        Some(v) if is_vowel(v) => Some(format!("{}r", stem)),
+        _ => None,
     }
+}
+
+/// Replace a word-final stop before a consonant
+///
+/// Jhalāṃ jaś jhaśi (8.4.53) voices it before a voiced consonant, khari ca (8.4.55)
+/// devoices it otherwise, and stoḥ ścunā ścuḥ (8.4.40) turns a dental into a
+/// palatal before a palatal.
+fn apply_consonant_sandhi(final_c: char, next: char) -> char {
+    let varga = if is_dental(final_c) && is_palatal(next) {
+        'c'
+    } else {
+        varga_of(final_c)
+    };
 
-    // 4. Yan
-    if (c1 == 'i' || c1 == 'I') && !(c2 == 'i' || c2 == 'I') {
-        return Some(format!("y{}", c2));
+    if is_voiced_consonant(next) {
+        jash(varga)
+    } else {
+        varga
     }
-    if (c1 == 'u' || c1 == 'U') && !(c2 == 'u' || c2 == 'U') {
-        return Some(format!("v{}", c2));
+}
+
+/// Replace a final `m`/`n` before a consonant
+///
+/// Mo 'nusvāraḥ (8.3.23) turns m into anusvāra, which takes the nasal of a
+/// following stop or nasal (8.4.58 anusvārasya yayi parasavarṇaḥ, optional at a
+/// pada end by 8.4.59). Before c, ṭ, t and their aspirates, naś chavy apraśān
+/// (8.3.7) turns n into ru with anusvāra before it (8.3.4), and the ru ends as
+/// s, ś or ṣ (8.3.34, 8.4.40, 8.4.41): tAn + ca → tAMSca. Before j and ḍ, n
+/// assimilates (8.4.40, 8.4.41). Returns `None` if the nasal stays, as n does
+/// before a sibilant.
+fn apply_nasal_sandhi(left: &str, next: char, config: &SandhiConfig) -> Option<String> {
+    let final_c = left.chars().last()?;
+    let takes_parasavarna = is_stop(next) || is_nasal(next);
+
+    let replacement = match final_c {
+        'm' if takes_parasavarna && config.apply_optional => {
+            return Some(nasal_of(varga_of(next)).to_string())
+        }
+        'm' => "M",
+        'n' if left.ends_with("praSAn") => return None,
+        'n' => match next {
+            'c' | 'C' => "MS",
+            'w' | 'W' => "Mz",
+            't' | 'T' => "Ms",
+            'j' | 'J' => "Y",
+            'q' | 'Q' => "R",
+            _ => return None,
+        },
+        _ => return None,
+    };
+    Some(replacement.to_string())
+}
+
+/// First (voiceless unaspirated) stop of the varga a stop or nasal belongs to
+fn varga_of(c: char) -> char {
+    match c {
+        'k' | 'K' | 'g' | 'G' | 'N' => 'k',
+        'c' | 'C' | 'j' | 'J' | 'Y' => 'c',
+        'w' | 'W' | 'q' | 'Q' | 'R' => 'w',
+        't' | 'T' | 'd' | 'D' | 'n' => 't',
+        _ => 'p',
     }
-    if (c1 == 'f' || c1 == 'F') && !(c2 == 'f' || c2 == 'F') {
-        return Some(format!("r{}", c2));
+}
+
+/// Voiced unaspirated stop (jaś) of a varga
+fn jash(varga: char) -> char {
+    match varga {
+        'k' => 'g',
+        'c' => 'j',
+        'w' => 'q',
+        't' => 'd',
+        _ => 'b',
     }
+}
 
-    // 5. Ayadi
-    if c1 == 'e' {
-        return Some(format!("ay{}", c2));
+/// Nasal of a varga
+fn nasal_of(varga: char) -> char {
+    match varga {
+        'k' => 'N',
+        'c' => 'Y',
+        'w' => 'R',
+        't' => 'n',
+        _ => 'm',
     }
-    if c1 == 'o' {
-        return Some(format!("av{}", c2));
+}
+
+fn is_nasal(c: char) -> bool {
+    matches!(c, 'N' | 'Y' | 'R' | 'n' | 'm')
+}
+
+fn is_stop(c: char) -> bool {
+    matches!(
+        c,
+        'k' | 'K'
+            | 'g'
+            | 'G'
+            | 'c'
+            | 'C'
+            | 'j'
+            | 'J'
+            | 'w'
+            | 'W'
+            | 'q'
+            | 'Q'
+            | 't'
+            | 'T'
+            | 'd'
+            | 'D'
+            | 'p'
+            | 'P'
+            | 'b'
+            | 'B'
+    )
+}
+
+fn is_dental(c: char) -> bool {
+    matches!(c, 't' | 'T' | 'd' | 'D')
+}
+
+fn is_palatal(c: char) -> bool {
+    matches!(c, 'c' | 'C' | 'j' | 'J' | 'S')
+}
+
+fn is_voiced_consonant(c: char) -> bool {
+    matches!(
+        c,
+        'g' | 'G'
+            | 'N'
+            | 'j'
+            | 'J'
+            | 'Y'
+            | 'q'
+            | 'Q'
+            | 'R'
+            | 'd'
+            | 'D'
+            | 'n'
+            | 'b'
+            | 'B'
+            | 'm'
+            | 'y'
+            | 'r'
+            | 'l'
+            | 'v'
+            | 'h'
+    )
+}
+
+pub(crate) fn is_consonant(c: char) -> bool {
+    is_voiced_consonant(c)
+        || matches!(
+            c,
+            'k' | 'K' | 'c' | 'C' | 'w' | 'W' | 't' | 'T' | 'p' | 'P' | 'S' | 'z' | 's'
+        )
+}
+
+pub(crate) fn is_vowel(c: char) -> bool {
+    matches!(
+        c,
+        'a' | 'A' | 'i' | 'I' | 'u' | 'U' | 'f' | 'F' | 'x' | 'X' | 'e' | 'E' | 'o' | 'O'
+    )
+}
+
+fn apply_vowel_sandhi(c1: char, c2: char) -> Option<String> {
+    dirgha(c1, c2)
+        .or_else(|| guna(c1, c2))
+        .or_else(|| vriddhi(c1, c2))
+        .or_else(|| yan(c1, c2))
+        .or_else(|| ayadi(c1, c2))
+}
+
+/// Akaḥ savarṇe dīrghaḥ (6.1.101)
+fn dirgha(c1: char, c2: char) -> Option<String> {
+    let long = match (c1, c2) {
+        ('a' | 'A', 'a' | 'A') => 'A',
+        ('i' | 'I', 'i' | 'I') => 'I',
+        ('u' | 'U', 'u' | 'U') => 'U',
+        ('f' | 'F', 'f' | 'F') => 'F',
+        ('x' | 'X', 'x' | 'X') => 'X',
+        _ => return None,
+    };
+    Some(long.to_string())
+}
+
+/// Ād guṇaḥ (6.1.87)
+fn guna(c1: char, c2: char) -> Option<String> {
+    let is_ik = matches!(c2, 'i' | 'I' | 'u' | 'U' | 'f' | 'F' | 'x' | 'X');
+    (matches!(c1, 'a' | 'A') && is_ik).then(|| vowels::guna(c2).to_string())
+}
+
+/// Vṛddhir eci (6.1.88)
+fn vriddhi(c1: char, c2: char) -> Option<String> {
+    let is_ec = matches!(c2, 'e' | 'E' | 'o' | 'O');
+    (matches!(c1, 'a' | 'A') && is_ec).then(|| vowels::vrddhi(c2).to_string())
+}
+
+/// Iko yaṇaci (6.1.77)
+fn yan(c1: char, c2: char) -> Option<String> {
+    if dirgha(c1, c2).is_some() {
+        return None;
     }
-    if c1 == 'E' {
-        return Some(format!("Ay{}", c2));
+    vowels::yan(c1).map(|semivowel| format!("{}{}", semivowel, c2))
+}
+
+/// Eco 'yavāyāvaḥ (6.1.78)
+fn ayadi(c1: char, c2: char) -> Option<String> {
+    vowels::ayadi(c1).map(|replacement| format!("{}{}", replacement, c2))
+}
+
+impl SandhiRule {
+    /// Every rule, in the order of the variants
+    pub fn all() -> [SandhiRule; 13] {
+        [
+            SandhiRule::Dirgha,
+            SandhiRule::Guna,
+            SandhiRule::Vriddhi,
+            SandhiRule::Yan,
+            SandhiRule::Ayadi,
+            SandhiRule::Utva,
+            SandhiRule::VisargaSatva,
+            SandhiRule::Jashtva,
+            SandhiRule::Chartva,
+            SandhiRule::Scutva,
+            SandhiRule::Anunasika,
+            SandhiRule::Anusvara,
+            SandhiRule::Parasavarna,
+        ]
     }
-    if c1 == 'O' {
-        return Some(format!("Av{}", c2));
+
+    /// Look up a rule by its sutra number, e.g. "6.1.87"
+    pub fn from_sutra(sutra: &str) -> Option<SandhiRule> {
+        Self::all()
+            .into_iter()
+            .find(|rule| rule.sutra() == sutra.trim())
     }
 
-    None
+    /// Apply only this rule at the junction of two non-empty SLP1 terms
+    ///
+    /// Returns the joined text, or `None` if the rule does not apply.
+    fn apply_slp1(&self, left: &str, right: &str) -> Option<String> {
+        let final_c = left.chars().last()?;
+        let initial_c = right.chars().next()?;
+        let base = &left[..left.len() - final_c.len_utf8()];
+        let rest = &right[initial_c.len_utf8()..];
+
+        let vowel_rule: Option<fn(char, char) -> Option<String>> = match self {
+            SandhiRule::Dirgha => Some(dirgha),
+            SandhiRule::Guna => Some(guna),
+            SandhiRule::Vriddhi => Some(vriddhi),
+            SandhiRule::Yan => Some(yan),
+            SandhiRule::Ayadi => Some(ayadi),
+            _ => None,
+        };
+        if let Some(rule) = vowel_rule {
+            if !is_vowel(final_c) || !is_vowel(initial_c) {
+                return None;
+            }
+            return rule(final_c, initial_c).map(|merged| format!("{}{}{}", base, merged, rest));
+        }
+
+        let new_final = match self {
+            SandhiRule::Utva if final_c == 'H' && is_voiced_consonant(initial_c) => {
+                let stem = base.strip_suffix('a')?;
+                return Some(format!("{}o{}", stem, right));
+            }
+            SandhiRule::VisargaSatva if final_c == 'H' => match initial_c {
+                'c' | 'C' => 'S',
+                'w' | 'W' => 'z',
+                't' | 'T' => 's',
+                _ => return None,
+            },
+            SandhiRule::Jashtva if is_stop(final_c) && is_voiced_consonant(initial_c) => {
+                jash(varga_of(final_c))
+            }
+            SandhiRule::Chartva if is_stop(final_c) && is_consonant(initial_c) => {
+                if is_voiced_consonant(initial_c) {
+                    return None;
+                }
+                varga_of(final_c)
+            }
+            SandhiRule::Scutva if is_palatal(initial_c) => match final_c {
+                't' => 'c',
+                'T' => 'C',
+                'd' => 'j',
+                'D' => 'J',
+                'n' => 'Y',
+                's' => 'S',
+                _ => return None,
+            },
+            SandhiRule::Anunasika if is_stop(final_c) && is_nasal(initial_c) => {
+                nasal_of(varga_of(final_c))
+            }
+            SandhiRule::Anusvara if final_c == 'm' && is_consonant(initial_c) => 'M',
+            SandhiRule::Parasavarna
+                if final_c == 'M' && (is_stop(initial_c) || is_nasal(initial_c)) =>
+            {
+                nasal_of(varga_of(initial_c))
+            }
+            _ => return None,
+        };
+
+        Some(format!("{}{}{}", base, new_final, right))
+    }
+}
+
+/// Apply exactly one sandhi rule, named by its sutra number, to two terms
+///
+/// Terms may be SLP1 or Devanagari; the result is in the script of the first.
+/// Returns `None` if the sutra is unknown or the rule does not apply at the
+/// junction, e.g. `apply_rule("6.1.87", "deva", "indra")` → `devendra`.
+pub fn apply_rule(sutra: &str, left: &str, right: &str) -> Option<String> {
+    let rule = SandhiRule::from_sutra(sutra)?;
+
+    let scheme = detect_scheme(left);
+    let l_slp1 = transliterate(left, scheme, Scheme::Slp1);
+    let r_slp1 = transliterate(right, scheme, Scheme::Slp1);
+
+    let joined = rule.apply_slp1(&l_slp1, &r_slp1)?;
+    Some(transliterate(&joined, Scheme::Slp1, scheme))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_final_stop_before_vowel() {
+        assert_eq!(apply_sandhi("vAk", "asti"), "vAgasti");
+        assert_eq!(apply_sandhi("vAc", "asti"), "vAgasti");
+        assert_eq!(apply_sandhi("tat", "eva"), "tadeva");
+        assert_eq!(apply_sandhi("ap", "eva"), "abeva");
+        assert_eq!(apply_sandhi("वाक्", "अस्ति"), "वागस्ति");
+    }
+
+    #[test]
+    fn test_join_morphemes_devanagari() {
+        assert_eq!(join_morphemes_devanagari(&["rAj", "an"]), "राजन्");
+        assert_eq!(join_morphemes_devanagari(&["vAc", "as"]), "वाचस्");
+        assert_eq!(join_morphemes_devanagari(&["rAj", "YI"]), "राज्ञी");
+        assert_eq!(join_morphemes_devanagari(&["pitf", "e"]), "पित्रे");
+        assert_eq!(join_morphemes_devanagari(&["Bo", "ana"]), "भवन");
+        assert_eq!(join_morphemes_devanagari(&["sam", "kalpa"]), "संकल्प");
+        assert_eq!(join_morphemes_devanagari(&[]), "");
+    }
+
+    #[test]
+    fn test_apply_rule() {
+        assert_eq!(
+            apply_rule("6.1.87", "deva", "indra").as_deref(),
+            Some("devendra")
+        );
+        assert_eq!(apply_rule("6.1.87", "देव", "इन्द्र").as_deref(), Some("देवेन्द्र"));
+        assert_eq!(apply_rule("6.1.101", "deva", "indra"), None);
+        assert_eq!(
+            apply_rule("6.1.101", "deva", "Alaya").as_deref(),
+            Some("devAlaya")
+        );
+        assert_eq!(
+            apply_rule("6.1.77", "iti", "api").as_deref(),
+            Some("ityapi")
+        );
+        assert_eq!(
+            apply_rule("6.1.114", "rAmaH", "gacCati").as_deref(),
+            Some("rAmogacCati")
+        );
+        assert_eq!(apply_rule("8.4.40", "tat", "ca").as_deref(), Some("tacca"));
+        assert_eq!(
+            apply_rule("8.4.53", "tat", "gacCati").as_deref(),
+            Some("tadgacCati")
+        );
+        assert_eq!(
+            apply_rule("8.4.55", "tad", "karoti").as_deref(),
+            Some("tatkaroti")
+        );
+        assert_eq!(apply_rule("8.3.23", "ham", "sa").as_deref(), Some("haMsa"));
+        assert_eq!(
+            apply_rule("8.4.58", "saM", "kalpa").as_deref(),
+            Some("saNkalpa")
+        );
+        assert_eq!(apply_rule("1.1.1", "deva", "indra"), None);
+    }
+
+    #[test]
+    fn test_rule_from_sutra() {
+        for rule in SandhiRule::all() {
+            assert_eq!(
+                SandhiRule::from_sutra(rule.sutra()).map(|r| r.sutra()),
+                Some(rule.sutra())
+            );
+        }
+    }
+
+    #[test]
+    fn test_rule_describe() {
+        assert_eq!(SandhiRule::Dirgha.sutra(), "6.1.101");
+        assert_eq!(SandhiRule::Dirgha.name_iast(), "akaḥ savarṇe dīrghaḥ");
+        assert_eq!(SandhiRule::Dirgha.name_devanagari(), "अकः सवर्णे दीर्घः");
+        assert_eq!(SandhiRule::Guna.sutra(), "6.1.87");
+    }
+
     #[test]
     fn test_dirgha() {
         assert_eq!(apply_sandhi("deva", "Alaya"), "devAlaya");
@@ -174,6 +784,124 @@ mod tests {
         assert_eq!(apply_sandhi("pO", "aka"), "pAvaka");
     }
 
+    #[test]
+    fn test_pragrhya() {
+        assert_eq!(apply_sandhi_pragrhya("harI", "etO"), "harI etO");
+        assert_eq!(apply_sandhi_pragrhya("vizRU", "imO"), "vizRU imO");
+        assert_eq!(apply_sandhi_pragrhya("late", "ime"), "late ime");
+        assert_eq!(apply_sandhi_pragrhya("vizRo", "iti"), "vizRo iti");
+        assert_eq!(apply_sandhi_pragrhya("हरी", "एतौ"), "हरी एतौ");
+
+        // The same vowels combine when not pragṛhya
+        assert_eq!(apply_sandhi("harI", "etO"), "haryetO");
+        assert_eq!(apply_sandhi("late", "ime"), "latayime");
+
+        // Pragṛhya only blocks vowel sandhi
+        assert_eq!(apply_sandhi_pragrhya("harI", "gacCataH"), "harIgacCataH");
+    }
+
+    #[test]
+    fn test_visarga() {
+        assert_eq!(apply_sandhi("rAmaH", "gacCati"), "rAmogacCati");
+        assert_eq!(apply_sandhi("rAmaH", "ca"), "rAmaSca");
+        assert_eq!(apply_sandhi("hariH", "gacCati"), "harirgacCati");
+        assert_eq!(apply_sandhi("devAH", "gacCanti"), "devAgacCanti");
+        assert_eq!(apply_sandhi("rAmaH", "patati"), "rAmaHpatati");
+    }
+
+    #[test]
+    fn test_visarga_before_vowel() {
+        assert_eq!(apply_sandhi("rAmaH", "atra"), "rAmo'tra");
+        assert_eq!(apply_sandhi("रामः", "अत्र"), "रामोऽत्र");
+        // The hiatus left by the lopa takes no further vowel sandhi
+        assert_eq!(apply_sandhi("rAmaH", "eti"), "rAma eti");
+        assert_eq!(apply_sandhi("रामः", "एति"), "राम एति");
+        assert_eq!(apply_sandhi("rAmaH", "Agacchati"), "rAma Agacchati");
+
+        // Other visargas: r after i and u, lopa after ā
+        assert_eq!(apply_sandhi("hariH", "atra"), "hariratra");
+        assert_eq!(apply_sandhi("guruH", "api"), "gururapi");
+        assert_eq!(apply_sandhi("devAH", "atra"), "devA atra");
+        assert_eq!(apply_sandhi("हरिः", "अत्र"), "हरिरत्र");
+    }
+
+    #[test]
+    fn test_consonant() {
+        assert_eq!(apply_sandhi("vAk", "devI"), "vAgdevI");
+        assert_eq!(apply_sandhi("tad", "pAti"), "tatpAti");
+        assert_eq!(apply_sandhi("tat", "ca"), "tacca");
+    }
+
+    #[test]
+    fn test_nasal_before_sibilant() {
+        assert_eq!(apply_sandhi("sam", "skfta"), "saMskfta");
+        assert_eq!(apply_sandhi("sam", "hAra"), "saMhAra");
+        // A pada-final n stays before a sibilant
+        assert_eq!(apply_sandhi("devAn", "smarati"), "devAnsmarati");
+        assert_eq!(apply_sandhi("सम्", "स्कृत"), "संस्कृत");
+    }
+
+    #[test]
+    fn test_nasal_before_stop() {
+        assert_eq!(apply_sandhi("sam", "kalpa"), "saNkalpa");
+        assert_eq!(apply_sandhi("sam", "tozaH"), "santozaH");
+        assert_eq!(apply_sandhi("sam", "Bava"), "samBava");
+        assert_eq!(apply_sandhi("tAn", "jayati"), "tAYjayati");
+        assert_eq!(apply_sandhi("tAn", "qayate"), "tARqayate");
+        assert_eq!(apply_sandhi("tAn", "karoti"), "tAnkaroti");
+        assert_eq!(apply_sandhi("aham", "yAmi"), "ahaMyAmi");
+
+        // Parasavarṇa is optional at a pada end
+        let no_optional = SandhiConfig {
+            apply_optional: false,
+            ..Default::default()
+        };
+        assert_eq!(apply_sandhi_cfg("sam", "kalpa", &no_optional), "saMkalpa");
+    }
+
+    #[test]
+    fn test_nasal_before_chav() {
+        assert_eq!(apply_sandhi("tAn", "ca"), "tAMSca");
+        assert_eq!(apply_sandhi("tAn", "wIkate"), "tAMzwIkate");
+        assert_eq!(apply_sandhi("tAn", "tarati"), "tAMstarati");
+        assert_eq!(apply_sandhi("तान्", "च"), "तांश्च");
+        // Except praśān (8.3.7 apraśān)
+        assert_eq!(apply_sandhi("praSAn", "tanoti"), "praSAntanoti");
+    }
+
+    #[test]
+    fn test_config_visarga_toggle() {
+        let all = SandhiConfig::default();
+        let no_visarga = SandhiConfig {
+            apply_visarga: false,
+            ..Default::default()
+        };
+
+        assert_eq!(apply_sandhi_cfg("rAmaH", "gacCati", &all), "rAmogacCati");
+        assert_eq!(
+            apply_sandhi_cfg("rAmaH", "gacCati", &no_visarga),
+            "rAmaHgacCati"
+        );
+        // Vowel sandhi is unaffected
+        assert_eq!(apply_sandhi_cfg("deva", "indra", &no_visarga), "devendra");
+    }
+
+    #[test]
+    fn test_config_optional_and_consonant() {
+        let no_optional = SandhiConfig {
+            apply_optional: false,
+            ..Default::default()
+        };
+        let no_consonant = SandhiConfig {
+            apply_consonant: false,
+            ..Default::default()
+        };
+
+        assert_eq!(apply_sandhi("vAk", "mayam"), "vANmayam");
+        assert_eq!(apply_sandhi_cfg("vAk", "mayam", &no_optional), "vAgmayam");
+        assert_eq!(apply_sandhi_cfg("vAk", "devI", &no_consonant), "vAkdevI");
+    }
+
     #[test]
     fn test_devanagari_support() {
         assert_eq!(apply_sandhi("धर्म", "आलय"), "धर्मालय");
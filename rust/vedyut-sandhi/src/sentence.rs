@@ -0,0 +1,83 @@
+//! Sentence-level sandhi: padapāṭha → saṃhitā
+
+use crate::rules::{detect_scheme, is_consonant, is_vowel, join_slp1, Junction, SandhiConfig};
+use vedyut_lipi::{transliterate, Scheme};
+
+/// Join a padapāṭha (word-by-word text) into its saṃhitā form
+///
+/// Sandhi is applied at every word junction. Words fused by vowel sandhi, and
+/// a consonant-final word before a vowel, are written together; elsewhere the
+/// (possibly changed) words stay separated by a space, following the
+/// classical convention.
+///
+/// The script is detected from the first word (Devanagari or SLP1).
+pub fn join_sentence(padas: &[&str]) -> String {
+    let Some(first) = padas.iter().find(|p| !p.is_empty()) else {
+        return String::new();
+    };
+    let scheme = detect_scheme(first);
+    let config = SandhiConfig::default();
+
+    let mut words: Vec<String> = Vec::new();
+    for pada in padas.iter().filter(|p| !p.is_empty()) {
+        let right = transliterate(pada, scheme, Scheme::Slp1);
+
+        let Some(left) = words.pop() else {
+            words.push(right);
+            continue;
+        };
+
+        match join_slp1(&left, &right, &config) {
+            Junction::Merged(word) => words.push(word),
+            Junction::Separate(new_left, new_right)
+                if new_left.ends_with(is_consonant) && new_right.starts_with(is_vowel) =>
+            {
+                words.push(format!("{}{}", new_left, new_right));
+            }
+            Junction::Separate(new_left, new_right) => {
+                words.push(new_left);
+                words.push(new_right);
+            }
+        }
+    }
+
+    transliterate(&words.join(" "), Scheme::Slp1, scheme)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_join_visarga_keeps_space() {
+        assert_eq!(join_sentence(&["rAmaH", "gacCati"]), "rAmo gacCati");
+    }
+
+    #[test]
+    fn test_join_vowel_merges() {
+        assert_eq!(join_sentence(&["deva", "indra"]), "devendra");
+        assert_eq!(
+            join_sentence(&["rAmaH", "deva", "indra", "gacCati"]),
+            "rAmo devendra gacCati"
+        );
+    }
+
+    #[test]
+    fn test_join_visarga_before_vowel() {
+        assert_eq!(join_sentence(&["rAmaH", "eti"]), "rAma eti");
+        assert_eq!(join_sentence(&["rAmaH", "atra"]), "rAmo 'tra");
+        assert_eq!(join_sentence(&["hariH", "atra", "asti"]), "hariratrAsti");
+        assert_eq!(join_sentence(&["devAH", "atra"]), "devA atra");
+    }
+
+    #[test]
+    fn test_join_devanagari() {
+        assert_eq!(join_sentence(&["रामः", "गच्छति"]), "रामो गच्छति");
+    }
+
+    #[test]
+    fn test_join_empty() {
+        assert_eq!(join_sentence(&[]), "");
+        assert_eq!(join_sentence(&["deva"]), "deva");
+    }
+}
@@ -1,3 +1,5 @@
+use crate::rules::{join_slp1, SandhiConfig};
+
 /// Sandhi splitting - reverse sandhi to find original words
 
 /// Split a sandhi-combined word into possible original components
@@ -181,6 +183,22 @@ pub fn split_sandhi(text: &str) -> Vec<(String, String)> {
     results
 }
 
+/// Check that sandhi between `left` and `right` actually gives `joined`
+///
+/// Used to drop candidates of [`split_sandhi`] that do not round-trip, such
+/// as `deva` + `endra` for `devendra`. A junction with an optional rule
+/// matches either variant. Assumes SLP1 input.
+pub fn sandhi_consistent(left: &str, right: &str, joined: &str) -> bool {
+    let without_optional = SandhiConfig {
+        apply_optional: false,
+        ..Default::default()
+    };
+
+    [SandhiConfig::default(), without_optional]
+        .iter()
+        .any(|config| join_slp1(left, right, config).joined() == joined)
+}
+
 fn add_splits(
     results: &mut Vec<(String, String)>,
     chars: &[char],
@@ -231,6 +249,21 @@ mod tests {
         assert!(splits.contains(&("iti".to_string(), "Adi".to_string())));
     }
 
+    #[test]
+    fn test_sandhi_consistent() {
+        assert!(sandhi_consistent("deva", "indra", "devendra"));
+        assert!(sandhi_consistent("iti", "Adi", "ityAdi"));
+        assert!(sandhi_consistent("deva", "Alaya", "devAlaya"));
+        assert!(sandhi_consistent("dev", "endra", "devendra"));
+
+        // Proposed by split_sandhi, but i + A gives yA, not yAA
+        assert!(split_sandhi("ityAdi").contains(&("iti".to_string(), "AAdi".to_string())));
+        assert!(!sandhi_consistent("iti", "AAdi", "ityAdi"));
+        // A plain cut between two vowels skips their sandhi
+        assert!(!sandhi_consistent("ca", "iti", "caiti"));
+        assert!(!sandhi_consistent("deva", "endra", "devendra"));
+    }
+
     #[test]
     fn test_split_ayadi() {
         let splits = split_sandhi("nayanam");
@@ -0,0 +1,122 @@
+//! Vowel substitutes shared by sandhi and the word generator
+//!
+//! Vowels are SLP1 chars. Guṇa is a, e, o (1.1.2 adeṅ guṇaḥ) and vṛddhi is
+//! ā, ai, au (1.1.1 vṛddhir ādaic); ṛ and ḷ take r and l with them (1.1.51
+//! uraṇ raparaḥ).
+
+/// Guṇa substitute of a vowel, e.g. `i` → `e`, `f` → `ar`
+///
+/// Vowels that are already guṇa or vṛddhi are returned unchanged; non-vowels
+/// give the empty string.
+pub fn guna(vowel: char) -> &'static str {
+    match vowel {
+        'a' | 'A' => "a",
+        'i' | 'I' | 'e' => "e",
+        'u' | 'U' | 'o' => "o",
+        'f' | 'F' => "ar",
+        'x' | 'X' => "al",
+        'E' => "E",
+        'O' => "O",
+        _ => "",
+    }
+}
+
+/// Vṛddhi substitute of a vowel, e.g. `i` → `E`, `f` → `Ar`
+///
+/// Non-vowels give the empty string.
+pub fn vrddhi(vowel: char) -> &'static str {
+    match vowel {
+        'a' | 'A' => "A",
+        'i' | 'I' | 'e' | 'E' => "E",
+        'u' | 'U' | 'o' | 'O' => "O",
+        'f' | 'F' => "Ar",
+        'x' | 'X' => "Al",
+        _ => "",
+    }
+}
+
+/// Short vowel whose guṇa is `gunated`, e.g. `ar` → `f`
+pub fn from_guna(gunated: &str) -> Option<char> {
+    match gunated {
+        "a" => Some('a'),
+        "e" => Some('i'),
+        "o" => Some('u'),
+        "ar" => Some('f'),
+        "al" => Some('x'),
+        _ => None,
+    }
+}
+
+/// Short vowel whose vṛddhi is `vrddhied`, e.g. `E` → `i`
+pub fn from_vrddhi(vrddhied: &str) -> Option<char> {
+    match vrddhied {
+        "A" => Some('a'),
+        "E" => Some('i'),
+        "O" => Some('u'),
+        "Ar" => Some('f'),
+        "Al" => Some('x'),
+        _ => None,
+    }
+}
+
+/// Semivowel of an ik vowel, e.g. `i` → `y` (6.1.77 iko yaṇ aci)
+pub fn yan(vowel: char) -> Option<char> {
+    match vowel {
+        'i' | 'I' => Some('y'),
+        'u' | 'U' => Some('v'),
+        'f' | 'F' => Some('r'),
+        'x' | 'X' => Some('l'),
+        _ => None,
+    }
+}
+
+/// Substitute of an ec vowel before a vowel, e.g. `e` → `ay` (6.1.78 eco
+/// 'yavāyāvaḥ)
+pub fn ayadi(vowel: char) -> Option<&'static str> {
+    match vowel {
+        'e' => Some("ay"),
+        'o' => Some("av"),
+        'E' => Some("Ay"),
+        'O' => Some("Av"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_guna() {
+        assert_eq!(guna('i'), "e");
+        assert_eq!(guna('U'), "o");
+        assert_eq!(guna('f'), "ar");
+        assert_eq!(guna('a'), "a");
+        assert_eq!(guna('k'), "");
+    }
+
+    #[test]
+    fn test_vrddhi() {
+        assert_eq!(vrddhi('i'), "E");
+        assert_eq!(vrddhi('a'), "A");
+        assert_eq!(vrddhi('u'), "O");
+        assert_eq!(vrddhi('F'), "Ar");
+    }
+
+    #[test]
+    fn test_inverses() {
+        for v in ['a', 'i', 'u', 'f', 'x'] {
+            assert_eq!(from_guna(guna(v)), Some(v));
+            assert_eq!(from_vrddhi(vrddhi(v)), Some(v));
+        }
+        assert_eq!(from_guna("E"), None);
+    }
+
+    #[test]
+    fn test_yan_ayadi() {
+        assert_eq!(yan('I'), Some('y'));
+        assert_eq!(yan('a'), None);
+        assert_eq!(ayadi('O'), Some("Av"));
+        assert_eq!(ayadi('i'), None);
+    }
+}
@@ -0,0 +1,133 @@
+//! Word-level diff between original and sanskritified text
+
+use serde::{Deserialize, Serialize};
+
+/// A span of the token-aligned diff
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DiffSpan {
+    /// Token present in both texts
+    Unchanged(String),
+    /// Token in the original replaced by a token in the refined text
+    Replaced { from: String, to: String },
+    /// Token only present in the refined text
+    Inserted(String),
+    /// Token only present in the original text
+    Deleted(String),
+}
+
+/// Compute a word-level diff between the original and refined text
+///
+/// Both texts are tokenized on whitespace and aligned by their longest common
+/// subsequence. A deletion immediately followed by an insertion is reported
+/// as a replacement.
+pub fn diff(original: &str, refined: &str) -> Vec<DiffSpan> {
+    let old: Vec<&str> = original.split_whitespace().collect();
+    let new: Vec<&str> = refined.split_whitespace().collect();
+
+    // lcs[i][j] = LCS length of old[i..] and new[j..]
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut spans = Vec::new();
+    let mut deleted: Vec<&str> = Vec::new();
+    let mut inserted: Vec<&str> = Vec::new();
+    let (mut i, mut j) = (0, 0);
+
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            flush_changes(&mut spans, &mut deleted, &mut inserted);
+            spans.push(DiffSpan::Unchanged(old[i].to_string()));
+            i += 1;
+            j += 1;
+        } else if j < new.len() && (i == old.len() || lcs[i][j + 1] >= lcs[i + 1][j]) {
+            inserted.push(new[j]);
+            j += 1;
+        } else {
+            deleted.push(old[i]);
+            i += 1;
+        }
+    }
+    flush_changes(&mut spans, &mut deleted, &mut inserted);
+
+    spans
+}
+
+/// Emit a pending run of changes, pairing deletions with insertions as replacements
+fn flush_changes(spans: &mut Vec<DiffSpan>, deleted: &mut Vec<&str>, inserted: &mut Vec<&str>) {
+    let paired = deleted.len().min(inserted.len());
+
+    for (from, to) in deleted.iter().zip(inserted.iter()) {
+        spans.push(DiffSpan::Replaced {
+            from: from.to_string(),
+            to: to.to_string(),
+        });
+    }
+    for word in &deleted[paired..] {
+        spans.push(DiffSpan::Deleted(word.to_string()));
+    }
+    for word in &inserted[paired..] {
+        spans.push(DiffSpan::Inserted(word.to_string()));
+    }
+
+    deleted.clear();
+    inserted.clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_two_replacements() {
+        let spans = diff(
+            "my good friend drinks water daily",
+            "my उत्तम friend drinks जल daily",
+        );
+
+        assert_eq!(
+            spans,
+            vec![
+                DiffSpan::Unchanged("my".to_string()),
+                DiffSpan::Replaced {
+                    from: "good".to_string(),
+                    to: "उत्तम".to_string()
+                },
+                DiffSpan::Unchanged("friend".to_string()),
+                DiffSpan::Unchanged("drinks".to_string()),
+                DiffSpan::Replaced {
+                    from: "water".to_string(),
+                    to: "जल".to_string()
+                },
+                DiffSpan::Unchanged("daily".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_insert_and_delete() {
+        assert_eq!(
+            diff("a b", "a b c"),
+            vec![
+                DiffSpan::Unchanged("a".to_string()),
+                DiffSpan::Unchanged("b".to_string()),
+                DiffSpan::Inserted("c".to_string()),
+            ]
+        );
+        assert_eq!(
+            diff("a b c", "a c"),
+            vec![
+                DiffSpan::Unchanged("a".to_string()),
+                DiffSpan::Deleted("b".to_string()),
+                DiffSpan::Unchanged("c".to_string()),
+            ]
+        );
+    }
+}
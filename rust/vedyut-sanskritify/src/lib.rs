@@ -6,14 +6,16 @@
 
 use vedyut_lipi::Scheme;
 
+pub mod diff;
 pub mod llm_fallback;
 pub mod options;
 pub mod refiner;
 pub mod vocabulary;
 
+pub use diff::{diff, DiffSpan};
 pub use llm_fallback::{LlmFallbackConfig, LlmProvider, OriginDetector};
 pub use options::{RefinementLevel, SanskritifyOptions};
-pub use refiner::sanskritify;
+pub use refiner::{sanskritify, sanskritify_best_effort, sanskritify_convert};
 
 /// Sanskritify text to make it more refined and Sanskrit-like
 ///
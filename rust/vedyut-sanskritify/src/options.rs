@@ -1,5 +1,6 @@
 //! Configuration options for Sanskritification
 
+use rustc_hash::FxHashMap;
 use serde::{Deserialize, Serialize};
 
 /// Level of Sanskrit refinement to apply
@@ -59,6 +60,12 @@ pub struct SanskritifyOptions {
 
     /// Replace Urdu/Arabic/Persian words with Sanskrit equivalents
     pub replace_foreign_words: bool,
+
+    /// Fixed phrases (mantras, quotations) passed through unchanged
+    pub protected_phrases: Vec<String>,
+
+    /// Extra abbreviation expansions, overriding the built-in ones
+    pub abbreviations: FxHashMap<String, String>,
 }
 
 impl Default for SanskritifyOptions {
@@ -76,6 +83,8 @@ impl Default for SanskritifyOptions {
             context: None,
             enable_llm_fallback: true, // Enable by default for better coverage
             replace_foreign_words: true, // Replace Urdu/Arabic/Persian by default
+            protected_phrases: Vec::new(),
+            abbreviations: FxHashMap::default(),
         }
     }
 }
@@ -95,6 +104,14 @@ impl SanskritifyOptions {
         }
     }
 
+    /// Create options for medium refinement (the defaults)
+    pub fn medium() -> Self {
+        Self {
+            level: RefinementLevel::Medium,
+            ..Default::default()
+        }
+    }
+
     /// Create options for high refinement
     pub fn high() -> Self {
         Self {
@@ -143,6 +160,14 @@ mod tests {
         assert!(!opts.apply_sandhi);
     }
 
+    #[test]
+    fn test_medium_options() {
+        let opts = SanskritifyOptions::medium();
+        assert_eq!(opts.level, RefinementLevel::Medium);
+        assert!(opts.apply_grammar_patterns);
+        assert!(!opts.apply_sandhi);
+    }
+
     #[test]
     fn test_classical_options() {
         let opts = SanskritifyOptions::classical();
@@ -2,7 +2,11 @@
 
 use crate::vocabulary::VocabularyTransformer;
 use crate::{SanskritifyError, SanskritifyOptions};
-use vedyut_lipi::Scheme;
+use vedyut_lipi::{is_supported, split_by_script, transliterate, transliterate_brahmic_runs};
+use vedyut_lipi::{Scheme, ScriptRun};
+
+/// A refinement stage; stages run in order, each on the previous output
+type Stage = fn(&str, &SanskritifyOptions) -> Result<String, SanskritifyError>;
 
 /// Main refinement function
 pub fn sanskritify(
@@ -10,6 +14,133 @@ pub fn sanskritify(
     script: Scheme,
     options: SanskritifyOptions,
 ) -> Result<String, SanskritifyError> {
+    validate(text, script)?;
+
+    let (mut refined, masked) = mask_phrases(text, &options.protected_phrases);
+    for stage in stages(&options) {
+        refined = stage(&refined, &options)?;
+    }
+
+    Ok(restore_phrases(&refined, &masked))
+}
+
+/// Refine text like [`sanskritify`] and write the result in another script
+///
+/// Vocabulary replacements come out in Devanagari, so each Brahmic run is
+/// transliterated from its own script and each Latin run from `in_script`
+/// when that is a romanization. Other Latin text (untouched English words in
+/// Devanagari input) is kept as is.
+pub fn sanskritify_convert(
+    text: &str,
+    in_script: Scheme,
+    out_script: Scheme,
+    options: SanskritifyOptions,
+) -> Result<String, SanskritifyError> {
+    if !is_supported(Scheme::Devanagari, out_script) {
+        return Err(SanskritifyError::UnsupportedScript(format!(
+            "Cannot write output in {:?}",
+            out_script
+        )));
+    }
+
+    let refined = sanskritify(text, in_script, options)?;
+
+    Ok(split_by_script(&refined)
+        .into_iter()
+        .map(|(script, run)| match script {
+            ScriptRun::Brahmic => transliterate_brahmic_runs(run, out_script),
+            ScriptRun::Latin if !in_script.is_brahmic() => {
+                transliterate(run, in_script, out_script)
+            }
+            _ => run.to_string(),
+        })
+        .collect())
+}
+
+/// Refine text like [`sanskritify`], but keep going when a stage fails
+///
+/// A failing stage is skipped (its input passes on unchanged) and its error
+/// collected, so the output keeps every successful transformation. Invalid
+/// input returns the text unchanged with the validation error.
+pub fn sanskritify_best_effort(
+    text: &str,
+    script: Scheme,
+    options: SanskritifyOptions,
+) -> (String, Vec<SanskritifyError>) {
+    if let Err(err) = validate(text, script) {
+        return (text.to_string(), vec![err]);
+    }
+
+    let (masked_text, masked) = mask_phrases(text, &options.protected_phrases);
+    let (refined, errors) = run_best_effort(&masked_text, &stages(&options), &options);
+    (restore_phrases(&refined, &masked), errors)
+}
+
+fn run_best_effort(
+    text: &str,
+    stages: &[Stage],
+    options: &SanskritifyOptions,
+) -> (String, Vec<SanskritifyError>) {
+    let mut refined = text.to_string();
+    let mut errors = Vec::new();
+
+    for stage in stages {
+        match stage(&refined, options) {
+            Ok(output) => refined = output,
+            Err(err) => errors.push(err),
+        }
+    }
+
+    (refined, errors)
+}
+
+/// Replace each protected phrase with a placeholder token
+///
+/// The placeholder is private-use characters around an index, with no
+/// whitespace or letters, so it stays one token that no stage matches. Where
+/// phrases overlap, the longest one starting first wins.
+fn mask_phrases<'a>(text: &str, phrases: &'a [String]) -> (String, Vec<&'a str>) {
+    let mut masked_text = String::with_capacity(text.len());
+    let mut masked = Vec::new();
+    let mut rest = text;
+
+    while let Some(c) = rest.chars().next() {
+        let phrase = phrases
+            .iter()
+            .filter(|phrase| !phrase.is_empty() && rest.starts_with(phrase.as_str()))
+            .max_by_key(|phrase| phrase.len());
+
+        match phrase {
+            Some(phrase) => {
+                masked_text.push_str(&placeholder(masked.len()));
+                masked.push(phrase.as_str());
+                rest = &rest[phrase.len()..];
+            }
+            None => {
+                masked_text.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+    }
+
+    (masked_text, masked)
+}
+
+/// Put the masked phrases back in place of their placeholders
+fn restore_phrases(text: &str, masked: &[&str]) -> String {
+    masked
+        .iter()
+        .enumerate()
+        .fold(text.to_string(), |text, (i, phrase)| {
+            text.replacen(&placeholder(i), phrase, 1)
+        })
+}
+
+fn placeholder(index: usize) -> String {
+    format!("\u{E000}{}\u{E001}", index)
+}
+
+fn validate(text: &str, script: Scheme) -> Result<(), SanskritifyError> {
     if text.is_empty() {
         return Err(SanskritifyError::InvalidInput(
             "Empty input text".to_string(),
@@ -24,30 +155,45 @@ pub fn sanskritify(
         )));
     }
 
-    let mut refined = text.to_string();
+    Ok(())
+}
+
+/// Stages enabled by the options, in order
+fn stages(options: &SanskritifyOptions) -> Vec<Stage> {
+    let mut stages: Vec<Stage> = Vec::new();
 
     // Step 1: Vocabulary transformation (colloquial → formal/tatsama)
     if options.use_tatsama || options.replace_colloquial {
-        let vocab_transformer = VocabularyTransformer::new();
-        refined = vocab_transformer.transform(&refined, &options)?;
+        stages.push(transform_vocabulary);
     }
 
     // Step 2: Grammar pattern application
     if options.apply_grammar_patterns {
-        refined = apply_grammar_patterns(&refined, &options)?;
+        stages.push(apply_grammar_patterns);
     }
 
     // Step 3: Formal register adjustment
     if options.formal_register {
-        refined = adjust_register(&refined, &options)?;
+        stages.push(adjust_register);
     }
 
     // Step 4: Sandhi application (if requested)
     if options.apply_sandhi {
-        refined = apply_sandhi_rules(&refined)?;
+        stages.push(apply_sandhi_rules);
     }
 
-    Ok(refined)
+    stages
+}
+
+fn transform_vocabulary(
+    text: &str,
+    options: &SanskritifyOptions,
+) -> Result<String, SanskritifyError> {
+    let mut transformer = VocabularyTransformer::new();
+    for (abbreviation, expansion) in &options.abbreviations {
+        transformer.add_abbreviation(abbreviation, expansion);
+    }
+    transformer.transform(text, options)
 }
 
 /// Check if script is suitable for Indian languages
@@ -97,7 +243,10 @@ fn adjust_register(text: &str, _options: &SanskritifyOptions) -> Result<String,
 }
 
 /// Apply sandhi rules for euphonic combination
-fn apply_sandhi_rules(text: &str) -> Result<String, SanskritifyError> {
+fn apply_sandhi_rules(
+    text: &str,
+    _options: &SanskritifyOptions,
+) -> Result<String, SanskritifyError> {
     // TODO: Implement sandhi application
     // This would use vedyut-sandhi module
 
@@ -141,4 +290,99 @@ mod tests {
         );
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_convert_iast_to_devanagari() {
+        let options = SanskritifyOptions::default();
+
+        let result = sanskritify_convert(
+            "rāmaḥ friend",
+            Scheme::Iast,
+            Scheme::Devanagari,
+            options.clone(),
+        );
+        assert_eq!(result.unwrap(), "रामः सखा");
+
+        // Devanagari replacements are transliterated back to a romanization
+        let result =
+            sanskritify_convert("rāmaḥ friend", Scheme::Iast, Scheme::Iast, options.clone());
+        assert_eq!(result.unwrap(), "rāmaḥ sakhā");
+
+        assert!(sanskritify_convert("rāmaḥ", Scheme::Iast, Scheme::Tibetan, options).is_err());
+    }
+
+    #[test]
+    fn test_best_effort_keeps_successful_stages() {
+        fn failing_sandhi(
+            _text: &str,
+            _options: &SanskritifyOptions,
+        ) -> Result<String, SanskritifyError> {
+            Err(SanskritifyError::RefinementFailed(
+                "sandhi unavailable".to_string(),
+            ))
+        }
+
+        let options = SanskritifyOptions::default();
+        let (refined, errors) =
+            run_best_effort("डॉ. राम", &[transform_vocabulary, failing_sandhi], &options);
+
+        assert_eq!(refined, "वैद्य राम");
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(errors[0], SanskritifyError::RefinementFailed(_)));
+    }
+
+    #[test]
+    fn test_protected_phrase() {
+        let mantra = "ॐ नमः शिवाय";
+        let options = SanskritifyOptions {
+            protected_phrases: vec![mantra.to_string(), "नमः".to_string()],
+            ..Default::default()
+        };
+
+        let text = format!("hello friend {}। good water", mantra);
+        let refined = sanskritify(&text, Scheme::Devanagari, options.clone()).unwrap();
+        assert_eq!(refined, format!("प्रणाम सखा {}। श्रेष्ठ नीर", mantra));
+
+        let (refined, errors) = sanskritify_best_effort(&text, Scheme::Devanagari, options);
+        assert!(errors.is_empty());
+        assert!(refined.contains(mantra));
+    }
+
+    #[test]
+    fn test_abbreviations_option() {
+        let mut options = SanskritifyOptions::default();
+        let text = "dept. water";
+        assert_eq!(
+            sanskritify(text, Scheme::Devanagari, options.clone()).unwrap(),
+            "dept. नीर"
+        );
+
+        options
+            .abbreviations
+            .insert("Dept.".to_string(), "विभाग".to_string());
+        assert_eq!(
+            sanskritify(text, Scheme::Devanagari, options.clone()).unwrap(),
+            "विभाग नीर"
+        );
+        let (refined, errors) = sanskritify_best_effort(text, Scheme::Devanagari, options);
+        assert!(errors.is_empty());
+        assert_eq!(refined, "विभाग नीर");
+    }
+
+    #[test]
+    fn test_mask_phrases() {
+        let phrases = vec!["ab".to_string(), "abc".to_string(), String::new()];
+        let (masked_text, masked) = mask_phrases("xabcab", &phrases);
+        assert_eq!(masked_text, "x\u{E000}0\u{E001}\u{E000}1\u{E001}");
+        assert_eq!(masked, vec!["abc", "ab"]);
+        assert_eq!(restore_phrases(&masked_text, &masked), "xabcab");
+    }
+
+    #[test]
+    fn test_best_effort_invalid_input() {
+        let (refined, errors) =
+            sanskritify_best_effort("", Scheme::Devanagari, SanskritifyOptions::default());
+        assert_eq!(refined, "");
+        assert!(matches!(errors[..], [SanskritifyError::InvalidInput(_)]));
+    }
 }
@@ -3,6 +3,32 @@
 use crate::llm_fallback::OriginDetector;
 use crate::{RefinementLevel, SanskritifyError, SanskritifyOptions};
 use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Why a word is flagged for replacement
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VocabCategory {
+    /// Colloquial word with a tatsama/formal equivalent
+    Colloquial,
+    /// Urdu/Arabic/Persian word
+    Foreign,
+    /// Abbreviation with a Sanskrit expansion
+    Abbreviation,
+}
+
+/// A word that [`VocabularyTransformer::transform`] would replace
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VocabSuggestion {
+    /// The word as written in the text
+    pub original: String,
+    /// Byte offset of the word in the text
+    pub offset: usize,
+    /// Replacements, from least to most Sanskritized; empty for a foreign
+    /// word left to the LLM fallback
+    pub candidates: Vec<String>,
+    pub category: VocabCategory,
+}
 
 /// Transforms vocabulary to use more Sanskrit-like words
 pub struct VocabularyTransformer {
@@ -10,6 +36,8 @@ pub struct VocabularyTransformer {
     mappings: FxHashMap<String, Vec<String>>,
     /// Urdu/Arabic/Persian → Sanskrit mappings
     foreign_mappings: FxHashMap<String, Vec<String>>,
+    /// Abbreviation → Sanskrit expansion, applied before the vocabulary pass
+    abbreviations: FxHashMap<String, String>,
     /// Origin detector for foreign words
     origin_detector: OriginDetector,
 }
@@ -19,6 +47,7 @@ impl VocabularyTransformer {
     pub fn new() -> Self {
         let mut mappings = FxHashMap::default();
         let mut foreign_mappings = FxHashMap::default();
+        let mut abbreviations = FxHashMap::default();
 
         // Sample mappings (would be expanded with comprehensive dictionary)
         // Hindi/Hindustani → Sanskrit/Tatsama equivalents
@@ -163,34 +192,80 @@ impl VocabularyTransformer {
             vec!["प्रयोग".to_string(), "उपयोग".to_string()],
         );
 
+        // Abbreviations (matched as whole tokens, case-insensitively)
+        abbreviations.insert("डॉ.".to_string(), "वैद्य".to_string());
+        abbreviations.insert("dr.".to_string(), "वैद्य".to_string());
+        abbreviations.insert("प्रो.".to_string(), "प्राध्यापक".to_string());
+        abbreviations.insert("prof.".to_string(), "प्राध्यापक".to_string());
+
         Self {
             mappings,
             foreign_mappings,
+            abbreviations,
             origin_detector: OriginDetector::new(),
         }
     }
 
+    /// Add or replace an abbreviation expansion
+    pub fn add_abbreviation(&mut self, abbreviation: &str, expansion: &str) {
+        self.abbreviations
+            .insert(abbreviation.to_lowercase(), expansion.to_string());
+    }
+
+    /// Load abbreviations from a JSON object of `{"abbreviation": "expansion"}`
+    ///
+    /// Entries are merged into the built-in table, overriding existing keys.
+    /// Returns the number of entries loaded.
+    pub fn load_abbreviations(&mut self, json: &str) -> Result<usize, SanskritifyError> {
+        let entries: FxHashMap<String, String> = serde_json::from_str(json)
+            .map_err(|e| SanskritifyError::InvalidInput(format!("Abbreviations: {}", e)))?;
+
+        let count = entries.len();
+        for (abbreviation, expansion) in entries {
+            self.add_abbreviation(&abbreviation, &expansion);
+        }
+        Ok(count)
+    }
+
+    /// Load abbreviations from a JSON file, as in [`Self::load_abbreviations`]
+    pub fn load_abbreviations_file(
+        &mut self,
+        path: impl AsRef<Path>,
+    ) -> Result<usize, SanskritifyError> {
+        let path = path.as_ref();
+        let json = std::fs::read_to_string(path).map_err(|e| {
+            SanskritifyError::InvalidInput(format!("Abbreviations {}: {}", path.display(), e))
+        })?;
+        self.load_abbreviations(&json)
+    }
+
+    /// Expand whole-token abbreviations, keeping the original whitespace
+    fn expand_abbreviations(&self, text: &str) -> String {
+        text.split_inclusive(char::is_whitespace)
+            .map(|chunk| {
+                let token = chunk.trim_end();
+                match self.abbreviations.get(&token.to_lowercase()) {
+                    Some(expansion) => format!("{}{}", expansion, &chunk[token.len()..]),
+                    None => chunk.to_string(),
+                }
+            })
+            .collect()
+    }
+
     /// Transform text vocabulary
     pub fn transform(
         &self,
         text: &str,
         options: &SanskritifyOptions,
     ) -> Result<String, SanskritifyError> {
-        let mut result = text.to_string();
-        let words: Vec<&str> = text.split_whitespace().collect();
+        let expanded = self.expand_abbreviations(text);
+        let mut result = expanded.clone();
+        let words: Vec<&str> = expanded.split_whitespace().collect();
 
         // Process each word
         for word in words {
-            let word_lower = word.to_lowercase();
-
-            // First, check if it's a known foreign word and should be replaced
-            if options.replace_foreign_words && self.origin_detector.is_foreign_origin(&word_lower)
-            {
-                if let Some(sanskrit_options) = self.foreign_mappings.get(&word_lower) {
-                    let replacement = self.select_replacement(sanskrit_options, options.level);
-                    result = result.replace(word, replacement);
-                    continue;
-                } else if options.enable_llm_fallback {
+            match self.lookup(&word.to_lowercase(), options) {
+                Some((_, [])) => {
                     // Word is foreign but not in vocabulary - would use LLM fallback
                     // For now, mark it for LLM processing
                     // In production, this would call the LLM API
@@ -198,45 +273,81 @@ impl VocabularyTransformer {
                         word,
                         &format!("[LLM_NEEDED: {}]", word), // Placeholder
                     );
-                    continue;
                 }
+                Some((_, candidates)) => {
+                    let replacement = self.select_replacement(candidates, options.level);
+                    result = result.replace(word, replacement);
+                }
+                None => {}
             }
+        }
 
-            // Regular vocabulary transformation
-            if let Some(tatsama_options) = self.mappings.get(&word_lower) {
-                let replacement = self.select_replacement(tatsama_options, options.level);
-                result = result.replace(word, replacement);
+        Ok(result)
+    }
+
+    /// List the words `transform` would replace, without changing the text
+    pub fn analyze(&self, text: &str, options: &SanskritifyOptions) -> Vec<VocabSuggestion> {
+        text.split_whitespace()
+            .filter_map(|word| {
+                let offset = word.as_ptr() as usize - text.as_ptr() as usize;
+                let word_lower = word.to_lowercase();
+
+                let (category, candidates) = match self.abbreviations.get(&word_lower) {
+                    Some(expansion) => (VocabCategory::Abbreviation, vec![expansion.clone()]),
+                    None => {
+                        let (category, candidates) = self.lookup(&word_lower, options)?;
+                        (category, candidates.to_vec())
+                    }
+                };
+
+                Some(VocabSuggestion {
+                    original: word.to_string(),
+                    offset,
+                    candidates,
+                    category,
+                })
+            })
+            .collect()
+    }
+
+    /// Find the replacements for a lowercased word
+    ///
+    /// Known foreign words take precedence over the colloquial table. A foreign
+    /// word without a mapping has no candidates when the LLM fallback is on.
+    fn lookup(
+        &self,
+        word_lower: &str,
+        options: &SanskritifyOptions,
+    ) -> Option<(VocabCategory, &[String])> {
+        if options.replace_foreign_words && self.origin_detector.is_foreign_origin(word_lower) {
+            if let Some(sanskrit_options) = self.foreign_mappings.get(word_lower) {
+                return Some((VocabCategory::Foreign, sanskrit_options));
+            } else if options.enable_llm_fallback {
+                return Some((VocabCategory::Foreign, &[]));
             }
         }
 
-        Ok(result)
+        self.mappings
+            .get(word_lower)
+            .map(|tatsama_options| (VocabCategory::Colloquial, tatsama_options.as_slice()))
     }
 
     /// Select appropriate replacement based on refinement level
+    ///
+    /// Options run from least to most Sanskritized. Light takes the first,
+    /// Medium the second and High/Classical the third; a level asking for more
+    /// options than there are takes the last one.
     fn select_replacement<'a>(&self, options: &'a [String], level: RefinementLevel) -> &'a str {
-        if options.is_empty() {
-            return "";
-        }
+        let wanted = match level {
+            RefinementLevel::Light => 0,
+            RefinementLevel::Medium => 1,
+            RefinementLevel::High | RefinementLevel::Classical => 2,
+        };
 
-        match level {
-            RefinementLevel::Light => &options[0],
-            RefinementLevel::Medium => {
-                if options.len() > 1 {
-                    &options[1]
-                } else {
-                    &options[0]
-                }
-            }
-            RefinementLevel::High | RefinementLevel::Classical => {
-                if options.len() > 2 {
-                    &options[2]
-                } else if options.len() > 1 {
-                    &options[1]
-                } else {
-                    &options[0]
-                }
-            }
-        }
+        options
+            .get(wanted)
+            .or_else(|| options.last())
+            .map_or("", String::as_str)
     }
 }
 
@@ -250,6 +361,36 @@ impl Default for VocabularyTransformer {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_select_replacement() {
+        use RefinementLevel::*;
+
+        let transformer = VocabularyTransformer::new();
+        let options = |n: usize| -> Vec<String> {
+            ["a", "b", "c"][..n].iter().map(|s| s.to_string()).collect()
+        };
+        let select = |n, level| {
+            transformer
+                .select_replacement(&options(n), level)
+                .to_string()
+        };
+
+        for level in [Light, Medium, High, Classical] {
+            assert_eq!(select(0, level), "");
+            assert_eq!(select(1, level), "a");
+        }
+
+        assert_eq!(select(2, Light), "a");
+        assert_eq!(select(2, Medium), "b");
+        assert_eq!(select(2, High), "b");
+        assert_eq!(select(2, Classical), "b");
+
+        assert_eq!(select(3, Light), "a");
+        assert_eq!(select(3, Medium), "b");
+        assert_eq!(select(3, High), "c");
+        assert_eq!(select(3, Classical), "c");
+    }
+
     #[test]
     fn test_vocabulary_transformer_creation() {
         let transformer = VocabularyTransformer::new();
@@ -268,6 +409,89 @@ mod tests {
         assert!(transformed != "hello friend");
     }
 
+    #[test]
+    fn test_abbreviation_expansion() {
+        let transformer = VocabularyTransformer::new();
+        let options = SanskritifyOptions::light();
+
+        let result = transformer.transform("डॉ. friend", &options).unwrap();
+        assert_eq!(result, "वैद्य मित्र");
+
+        // Only whole tokens are expanded
+        let result = transformer.transform("डॉ.x friend", &options).unwrap();
+        assert_eq!(result, "डॉ.x मित्र");
+    }
+
+    #[test]
+    fn test_load_abbreviations() {
+        let mut transformer = VocabularyTransformer::new();
+        let options = SanskritifyOptions::light();
+
+        let loaded = transformer
+            .load_abbreviations(r#"{"Govt.": "शासन"}"#)
+            .unwrap();
+        assert_eq!(loaded, 1);
+        assert_eq!(
+            transformer.transform("govt.  water", &options).unwrap(),
+            "शासन  जल"
+        );
+        assert!(transformer.load_abbreviations("not json").is_err());
+    }
+
+    #[test]
+    fn test_load_abbreviations_file() {
+        let path =
+            std::env::temp_dir().join(format!("vedyut-abbreviations-{}.json", std::process::id()));
+        std::fs::write(&path, r#"{"Dept.": "विभाग"}"#).unwrap();
+
+        let mut transformer = VocabularyTransformer::new();
+        let loaded = transformer.load_abbreviations_file(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded.unwrap(), 1);
+        assert_eq!(
+            transformer
+                .transform("dept.", &SanskritifyOptions::light())
+                .unwrap(),
+            "विभाग"
+        );
+        assert!(transformer.load_abbreviations_file(&path).is_err());
+    }
+
+    #[test]
+    fn test_analyze() {
+        let transformer = VocabularyTransformer::new();
+        let options = SanskritifyOptions::default();
+        let text = "hello friend duniya";
+
+        let suggestions = transformer.analyze(text, &options);
+        let flagged: Vec<_> = suggestions
+            .iter()
+            .map(|s| (s.original.as_str(), s.offset, s.category))
+            .collect();
+        assert_eq!(
+            flagged,
+            vec![
+                ("hello", 0, VocabCategory::Colloquial),
+                ("friend", 6, VocabCategory::Colloquial),
+                ("duniya", 13, VocabCategory::Foreign),
+            ]
+        );
+        assert_eq!(suggestions[1].candidates, vec!["मित्र", "सखा"]);
+        assert_eq!(suggestions[2].candidates, vec!["जगत्", "विश्व", "लोक"]);
+
+        // Foreign words are left alone when not replaced
+        let options = SanskritifyOptions {
+            replace_foreign_words: false,
+            ..Default::default()
+        };
+        assert_eq!(transformer.analyze(text, &options).len(), 2);
+
+        let suggestions = transformer.analyze("Dr. xyz", &options);
+        assert_eq!(suggestions.len(), 1);
+        assert_eq!(suggestions[0].category, VocabCategory::Abbreviation);
+    }
+
     #[test]
     fn test_refinement_levels() {
         let transformer = VocabularyTransformer::new();